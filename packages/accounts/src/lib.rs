@@ -7,4 +7,5 @@
 
 pub mod policies;
 pub mod smart_account;
+pub mod token_bound;
 pub mod verifiers;
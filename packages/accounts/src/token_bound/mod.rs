@@ -0,0 +1,208 @@
+//! # Token-Bound Accounts
+//!
+//! This module implements ERC-6551 style token-bound accounts: smart accounts
+//! owned by a non-fungible token, so that everything the account holds moves
+//! together with the token.
+//!
+//! ## Account
+//!
+//! A token-bound account is a regular smart account whose default context rule
+//! contains a single [`Signer::Delegated`] signer: the owner of the bound
+//! token. The account is bound once, with [`bind_token`], which is meant to be
+//! invoked from the constructor.
+//!
+//! Control of the account follows every transfer of the token. The account
+//! uses [`do_check_auth`] in `__check_auth`, which first syncs the account with
+//! the current `owner_of(token_id)` of the bound token: when the token changed
+//! hands, the default context rule of the previous owner is replaced by one
+//! for the new owner. All context rules created before that rule, i.e. the
+//! rules, signers and policies added by previous owners, are ignored from
+//! then on, so a seller cannot keep control of the account after a sale. The
+//! new owner can remove them to free up their slots.
+//!
+//! ## Registry
+//!
+//! A registry contract deterministically deploys one account per
+//! `(wasm_hash, collection, token_id, salt)` tuple with [`create_account`],
+//! and any party can compute the address of an account beforehand with
+//! [`account_address`], e.g. to send assets to a token's account before it is
+//! deployed. The account WASM must accept `(collection: Address, token_id:
+//! u32)` as constructor arguments.
+//!
+//! ## Caveats
+//!
+//! - If a token is owned, directly or indirectly, by its own token-bound
+//!   account, the account can no longer be authorized: its assets and the token
+//!   are locked forever.
+//! - The context rules of previous owners still count towards
+//!   `MAX_CONTEXT_RULES` until they are removed. If the default context rule
+//!   was removed and the account is full, the new owner cannot be synced.
+//! - The assets held by the account can still be moved by the seller before the
+//!   sale is settled.
+mod storage;
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contractclient, contracterror, contractevent, Address, BytesN, Env};
+pub use storage::{
+    account_address, account_salt, bind_token, bound_owner, bound_token, create_account,
+    do_check_auth, get_validated_context, sync_owner, token_owner, BoundOwner, BoundToken,
+    TokenBoundStorageKey,
+};
+
+#[cfg(doc)]
+use crate::smart_account::Signer;
+use crate::smart_account::SmartAccount;
+
+/// Minimal interface of a non-fungible token contract required to resolve the
+/// owner of a token.
+#[contractclient(name = "NonFungibleOwnerClient")]
+pub trait NonFungibleOwner {
+    /// Returns the owner of `token_id`.
+    fn owner_of(e: &Env, token_id: u32) -> Address;
+}
+
+/// Trait for smart accounts bound to a non-fungible token.
+///
+/// The account is controlled by the current owner of the bound token through
+/// a [`Signer::Delegated`] signer, and must authorize with [`do_check_auth`]
+/// to recognize the owner after every transfer of the token.
+pub trait TokenBoundAccount: SmartAccount {
+    /// Returns the token the account is bound to.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`TokenBoundError::TokenNotBound`] - When the account is not bound to
+    ///   a token.
+    fn token(e: &Env) -> BoundToken;
+
+    /// Returns the current owner of the bound token, i.e. the owner of the
+    /// account.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`TokenBoundError::TokenNotBound`] - When the account is not bound to
+    ///   a token.
+    fn owner(e: &Env) -> Address;
+}
+
+/// Trait for registries deploying token-bound accounts.
+#[contractclient(name = "TokenBoundRegistryClient")]
+pub trait TokenBoundRegistry {
+    /// Deploys the token-bound account for the given parameters and returns
+    /// its address. If the account was already created, returns its address
+    /// without deploying it again.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `wasm_hash` - The hash of the account WASM.
+    /// * `collection` - The address of the non-fungible token contract.
+    /// * `token_id` - The identifier of the token.
+    /// * `salt` - An arbitrary salt, allowing multiple accounts per token.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["account_created", collection: Address, token_id: u32]`
+    /// * data - `[account: Address, wasm_hash: BytesN<32>, salt: BytesN<32>]`
+    fn create_account(
+        e: &Env,
+        wasm_hash: BytesN<32>,
+        collection: Address,
+        token_id: u32,
+        salt: BytesN<32>,
+    ) -> Address;
+
+    /// Returns the address of the token-bound account for the given
+    /// parameters, whether it is deployed or not.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `wasm_hash` - The hash of the account WASM.
+    /// * `collection` - The address of the non-fungible token contract.
+    /// * `token_id` - The identifier of the token.
+    /// * `salt` - An arbitrary salt, allowing multiple accounts per token.
+    fn account(
+        e: &Env,
+        wasm_hash: BytesN<32>,
+        collection: Address,
+        token_id: u32,
+        salt: BytesN<32>,
+    ) -> Address;
+}
+
+// ################## ERRORS ##################
+
+/// Error codes for token-bound account operations.
+#[contracterror]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(u32)]
+pub enum TokenBoundError {
+    /// The account is not bound to a token.
+    TokenNotBound = 3300,
+    /// The account is already bound to a token.
+    TokenAlreadyBound = 3301,
+}
+
+// ################## CONSTANTS ##################
+
+const DAY_IN_LEDGERS: u32 = 17280;
+pub const TOKEN_BOUND_EXTEND_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const TOKEN_BOUND_TTL_THRESHOLD: u32 = TOKEN_BOUND_EXTEND_AMOUNT - DAY_IN_LEDGERS;
+
+// ################## EVENTS ##################
+
+/// Event emitted when a token-bound account is created by a registry.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountCreated {
+    #[topic]
+    pub collection: Address,
+    #[topic]
+    pub token_id: u32,
+    pub account: Address,
+    pub wasm_hash: BytesN<32>,
+    pub salt: BytesN<32>,
+}
+
+/// Emits an event indicating a token-bound account has been created.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `collection` - The address of the non-fungible token contract.
+/// * `token_id` - The identifier of the token.
+/// * `account` - The address of the created account.
+/// * `wasm_hash` - The hash of the account WASM.
+/// * `salt` - The salt provided for the account.
+///
+/// # Events
+///
+/// * topics - `["account_created", collection: Address, token_id: u32]`
+/// * data - `[account: Address, wasm_hash: BytesN<32>, salt: BytesN<32>]`
+pub fn emit_account_created(
+    e: &Env,
+    collection: &Address,
+    token_id: u32,
+    account: &Address,
+    wasm_hash: &BytesN<32>,
+    salt: &BytesN<32>,
+) {
+    AccountCreated {
+        collection: collection.clone(),
+        token_id,
+        account: account.clone(),
+        wasm_hash: wasm_hash.clone(),
+        salt: salt.clone(),
+    }
+    .publish(e);
+}
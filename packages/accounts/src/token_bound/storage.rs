@@ -0,0 +1,416 @@
+use soroban_sdk::{
+    auth::{
+        Context, ContractContext, ContractExecutable, CreateContractHostFnContext,
+        CreateContractWithConstructorHostFnContext,
+    },
+    contracttype,
+    crypto::Hash,
+    panic_with_error, vec,
+    xdr::ToXdr,
+    Address, Bytes, BytesN, Env, Map, String, Vec,
+};
+
+use crate::{
+    policies::PolicyClient,
+    smart_account::{
+        add_context_rule, authenticate, can_enforce_all_policies, get_authenticated_signers,
+        get_valid_context_rules, remove_context_rule, ContextRule, ContextRuleType, Signatures,
+        Signer, SmartAccountError, SmartAccountStorageKey,
+    },
+    token_bound::{
+        emit_account_created, NonFungibleOwnerClient, TokenBoundError, TOKEN_BOUND_EXTEND_AMOUNT,
+        TOKEN_BOUND_TTL_THRESHOLD,
+    },
+};
+
+/// Storage keys for token-bound account data.
+#[contracttype]
+pub enum TokenBoundStorageKey {
+    /// Storage key for the token an account is bound to.
+    Token,
+    /// Storage key for the accounts created by a registry.
+    /// Maps the account salt to the account `Address`.
+    Account(BytesN<32>),
+    /// Storage key for the owner recognized by an account and its context
+    /// rule.
+    Owner,
+}
+
+/// The non-fungible token a token-bound account is bound to.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BoundToken {
+    /// The address of the non-fungible token contract.
+    pub collection: Address,
+    /// The identifier of the token.
+    pub token_id: u32,
+}
+
+/// The owner recognized by a token-bound account.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BoundOwner {
+    /// The owner of the bound token when the account was last synced.
+    pub owner: Address,
+    /// The identifier of the default context rule controlled by `owner`. Any
+    /// context rule with a lower identifier was created before `owner`
+    /// acquired the token and is ignored during authorization.
+    pub rule_id: u32,
+}
+
+// ################## ACCOUNT ##################
+
+/// Retrieves the token the account is bound to.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+///
+/// # Errors
+///
+/// * [`TokenBoundError::TokenNotBound`] - When the account is not bound to a
+///   token.
+pub fn bound_token(e: &Env) -> BoundToken {
+    e.storage()
+        .instance()
+        .get(&TokenBoundStorageKey::Token)
+        .unwrap_or_else(|| panic_with_error!(e, TokenBoundError::TokenNotBound))
+}
+
+/// Retrieves the owner recognized by the account, which might differ from
+/// the current owner of the bound token until the account is synced with
+/// [`sync_owner`].
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+///
+/// # Errors
+///
+/// * [`TokenBoundError::TokenNotBound`] - When the account is not bound to a
+///   token.
+pub fn bound_owner(e: &Env) -> BoundOwner {
+    e.storage()
+        .instance()
+        .get(&TokenBoundStorageKey::Owner)
+        .unwrap_or_else(|| panic_with_error!(e, TokenBoundError::TokenNotBound))
+}
+
+/// Retrieves the current owner of the bound token, by invoking `owner_of` on
+/// the token contract.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+///
+/// # Errors
+///
+/// * [`TokenBoundError::TokenNotBound`] - When the account is not bound to a
+///   token.
+pub fn token_owner(e: &Env) -> Address {
+    let BoundToken { collection, token_id } = bound_token(e);
+    NonFungibleOwnerClient::new(e, &collection).owner_of(&token_id)
+}
+
+/// Binds the account to a non-fungible token and creates a default context
+/// rule with the current owner of that token as its single
+/// [`Signer::Delegated`] signer. Returns the created context rule.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `collection` - The address of the non-fungible token contract.
+/// * `token_id` - The identifier of the token.
+///
+/// # Errors
+///
+/// * [`TokenBoundError::TokenAlreadyBound`] - When the account is already bound
+///   to a token.
+/// * refer to [`add_context_rule`] errors.
+///
+/// # Events
+///
+/// * topics - `["context_rule_added", id: u32]`
+/// * data - `[name: String, context_type: ContextRuleType, valid_until:
+///   Option<u32>, signers: Vec<Signer>, policies: Vec<Address>]`
+///
+/// # Security Warning
+///
+/// This function lacks authorization checks and should only be used in the
+/// constructor of the account. Context rules created before it are ignored
+/// during authorization.
+pub fn bind_token(e: &Env, collection: &Address, token_id: u32) -> ContextRule {
+    let key = TokenBoundStorageKey::Token;
+    if e.storage().instance().has(&key) {
+        panic_with_error!(e, TokenBoundError::TokenAlreadyBound);
+    }
+    e.storage().instance().set(&key, &BoundToken { collection: collection.clone(), token_id });
+
+    add_owner_rule(e, &token_owner(e))
+}
+
+/// Syncs the account with the current owner of the bound token and returns
+/// the recognized owner.
+///
+/// When the token changed hands, the default context rule of the previous
+/// owner is removed and a new one is created for the current owner. Since
+/// context rule identifiers only increase, all context rules created by
+/// previous owners then have a lower identifier than the one of the new rule,
+/// and are ignored by [`do_check_auth`].
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+///
+/// # Errors
+///
+/// * [`TokenBoundError::TokenNotBound`] - When the account is not bound to a
+///   token.
+/// * refer to [`add_context_rule`] errors.
+///
+/// # Events
+///
+/// If the token changed hands:
+/// * topics - `["context_rule_removed", id: u32]`
+/// * data - `[]`
+///
+/// * topics - `["context_rule_added", id: u32]`
+/// * data - `[name: String, context_type: ContextRuleType, valid_until:
+///   Option<u32>, signers: Vec<Signer>, policies: Vec<Address>]`
+pub fn sync_owner(e: &Env) -> BoundOwner {
+    let bound = bound_owner(e);
+    let owner = token_owner(e);
+    if bound.owner == owner {
+        return bound;
+    }
+
+    // the previous owner might have removed the rule already
+    if e.storage().persistent().has(&SmartAccountStorageKey::Meta(bound.rule_id)) {
+        remove_context_rule(e, bound.rule_id);
+    }
+    add_owner_rule(e, &owner);
+
+    bound_owner(e)
+}
+
+/// Validates a context against the context rules created by the recognized
+/// owner of the account, and returns the matching rule with authenticated
+/// signers. Mirrors [`crate::smart_account::get_validated_context`], while
+/// skipping the context rules created before `first_rule_id`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `context` - The authorization context to validate.
+/// * `all_signers` - The signers provided for authentication.
+/// * `first_rule_id` - The identifier of the first context rule to consider.
+///
+/// # Errors
+///
+/// * [`SmartAccountError::UnvalidatedContext`] - When no context rule can
+///   validate the provided context and signers.
+pub fn get_validated_context(
+    e: &Env,
+    context: &Context,
+    all_signers: &Vec<Signer>,
+    first_rule_id: u32,
+) -> (ContextRule, Context, Vec<Signer>) {
+    let context_rules = match context.clone() {
+        #[rustfmt::skip]
+        Context::Contract(ContractContext { contract, .. }) => {
+            get_valid_context_rules(e, &ContextRuleType::CallContract(contract))
+        },
+        Context::CreateContractHostFn(CreateContractHostFnContext {
+            executable: ContractExecutable::Wasm(wasm),
+            ..
+        }) => get_valid_context_rules(e, &ContextRuleType::CreateContract(wasm)),
+        Context::CreateContractWithCtorHostFn(CreateContractWithConstructorHostFnContext {
+            executable: ContractExecutable::Wasm(wasm),
+            ..
+        }) => get_valid_context_rules(e, &ContextRuleType::CreateContract(wasm)),
+    };
+
+    for context_rule in context_rules.iter() {
+        // skip the rules of previous owners
+        if context_rule.id < first_rule_id {
+            continue;
+        }
+        let ContextRule { signers: rule_signers, policies, .. } = context_rule.clone();
+
+        let authenticated_signers = get_authenticated_signers(e, &rule_signers, all_signers);
+        if policies.is_empty() {
+            if rule_signers.len() == authenticated_signers.len() {
+                return (context_rule, context.clone(), authenticated_signers);
+            }
+        } else if can_enforce_all_policies(e, context, &context_rule, &authenticated_signers) {
+            return (context_rule, context.clone(), authenticated_signers);
+        }
+    }
+
+    panic_with_error!(e, SmartAccountError::UnvalidatedContext)
+}
+
+/// Performs complete authorization check for a token-bound account. Syncs
+/// the account with the current owner of the bound token, then proceeds as
+/// [`crate::smart_account::do_check_auth`], considering only the context rules
+/// created by the current owner. Meant to be used in `__check_auth` instead of
+/// the latter.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `signature_payload` - The hash of the data that was signed.
+/// * `signatures` - The signatures mapped to their signers.
+/// * `auth_contexts` - The authorization contexts to validate.
+///
+/// # Errors
+///
+/// * refer to [`sync_owner`] errors.
+/// * refer to [`authenticate`] errors.
+/// * refer to [`get_validated_context`] errors.
+pub fn do_check_auth(
+    e: &Env,
+    signature_payload: &Hash<32>,
+    signatures: &Signatures,
+    auth_contexts: &Vec<Context>,
+) -> Result<(), SmartAccountError> {
+    let BoundOwner { rule_id, .. } = sync_owner(e);
+
+    authenticate(e, signature_payload, &signatures.0);
+
+    let validated_contexts = Vec::from_iter(
+        e,
+        auth_contexts
+            .iter()
+            .map(|context| get_validated_context(e, &context, &signatures.0.keys(), rule_id)),
+    );
+
+    for (rule, context, authenticated_signers) in validated_contexts.iter() {
+        for policy in rule.policies.iter() {
+            PolicyClient::new(e, &policy).enforce(
+                &context,
+                &authenticated_signers,
+                &rule,
+                &e.current_contract_address(),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Creates the default context rule controlled by `owner` and records it as
+/// the rule of the recognized owner.
+fn add_owner_rule(e: &Env, owner: &Address) -> ContextRule {
+    let rule = add_context_rule(
+        e,
+        &ContextRuleType::Default,
+        &String::from_str(e, "token owner"),
+        None,
+        &vec![e, Signer::Delegated(owner.clone())],
+        &Map::new(e),
+    );
+    e.storage()
+        .instance()
+        .set(&TokenBoundStorageKey::Owner, &BoundOwner { owner: owner.clone(), rule_id: rule.id });
+    rule
+}
+
+// ################## REGISTRY ##################
+
+/// Computes the deployment salt of a token-bound account, as the SHA-256
+/// hash of the XDR-encoded parameters.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `wasm_hash` - The hash of the account WASM.
+/// * `collection` - The address of the non-fungible token contract.
+/// * `token_id` - The identifier of the token.
+/// * `salt` - An arbitrary salt, allowing multiple accounts per token.
+pub fn account_salt(
+    e: &Env,
+    wasm_hash: &BytesN<32>,
+    collection: &Address,
+    token_id: u32,
+    salt: &BytesN<32>,
+) -> BytesN<32> {
+    let mut data = Bytes::new(e);
+    data.append(&wasm_hash.to_xdr(e));
+    data.append(&collection.to_xdr(e));
+    data.append(&token_id.to_xdr(e));
+    data.append(&salt.to_xdr(e));
+    e.crypto().sha256(&data).to_bytes()
+}
+
+/// Computes the address of a token-bound account deployed by the current
+/// contract, whether it is deployed or not.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `wasm_hash` - The hash of the account WASM.
+/// * `collection` - The address of the non-fungible token contract.
+/// * `token_id` - The identifier of the token.
+/// * `salt` - An arbitrary salt, allowing multiple accounts per token.
+pub fn account_address(
+    e: &Env,
+    wasm_hash: &BytesN<32>,
+    collection: &Address,
+    token_id: u32,
+    salt: &BytesN<32>,
+) -> Address {
+    let account_salt = account_salt(e, wasm_hash, collection, token_id, salt);
+    e.deployer().with_current_contract(account_salt).deployed_address()
+}
+
+/// Deploys a token-bound account from the current contract, passing
+/// `(collection, token_id)` to its constructor, and returns its address. If the
+/// account was already created, returns its address without deploying it
+/// again.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `wasm_hash` - The hash of the account WASM.
+/// * `collection` - The address of the non-fungible token contract.
+/// * `token_id` - The identifier of the token.
+/// * `salt` - An arbitrary salt, allowing multiple accounts per token.
+///
+/// # Events
+///
+/// * topics - `["account_created", collection: Address, token_id: u32]`
+/// * data - `[account: Address, wasm_hash: BytesN<32>, salt: BytesN<32>]`
+///
+/// # Notes
+///
+/// Anyone can create the account of any token, as the account is controlled by
+/// the owner of the token regardless of who deployed it.
+pub fn create_account(
+    e: &Env,
+    wasm_hash: &BytesN<32>,
+    collection: &Address,
+    token_id: u32,
+    salt: &BytesN<32>,
+) -> Address {
+    let account_salt = account_salt(e, wasm_hash, collection, token_id, salt);
+    let key = TokenBoundStorageKey::Account(account_salt.clone());
+    if let Some(account) = e.storage().persistent().get::<_, Address>(&key) {
+        e.storage().persistent().extend_ttl(
+            &key,
+            TOKEN_BOUND_TTL_THRESHOLD,
+            TOKEN_BOUND_EXTEND_AMOUNT,
+        );
+        return account;
+    }
+
+    let account = e
+        .deployer()
+        .with_current_contract(account_salt)
+        .deploy_v2(wasm_hash.clone(), (collection.clone(), token_id));
+    e.storage().persistent().set(&key, &account);
+
+    emit_account_created(e, collection, token_id, &account, wasm_hash, salt);
+
+    account
+}
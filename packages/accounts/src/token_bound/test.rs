@@ -0,0 +1,246 @@
+extern crate std;
+
+use soroban_sdk::{
+    auth::{Context, ContractContext},
+    contract, contractimpl,
+    testutils::Address as _,
+    vec, Address, Bytes, BytesN, Env, Map, String, Symbol,
+};
+
+use crate::{
+    smart_account::{
+        add_context_rule, get_context_rule, ContextRuleType, Signatures, Signer,
+        SmartAccountStorageKey,
+    },
+    token_bound::{
+        account_address, bind_token, bound_owner, bound_token, do_check_auth, sync_owner,
+        token_owner, BoundToken,
+    },
+};
+
+#[contract]
+struct MockContract;
+
+#[contract]
+struct MockNftContract;
+
+#[contractimpl]
+impl MockNftContract {
+    pub fn owner_of(e: &Env, token_id: u32) -> Address {
+        e.storage().persistent().get(&token_id).unwrap()
+    }
+
+    pub fn set_owner(e: &Env, token_id: u32, owner: Address) {
+        e.storage().persistent().set(&token_id, &owner);
+    }
+}
+
+fn setup(e: &Env) -> (Address, Address, Address) {
+    let account = e.register(MockContract, ());
+    let collection = e.register(MockNftContract, ());
+    let owner = Address::generate(e);
+    MockNftContractClient::new(e, &collection).set_owner(&7, &owner);
+    (account, collection, owner)
+}
+
+fn signatures(e: &Env, signer: &Address) -> Signatures {
+    let mut signatures = Map::new(e);
+    signatures.set(Signer::Delegated(signer.clone()), Bytes::new(e));
+    Signatures(signatures)
+}
+
+fn check_auth(e: &Env, signer: &Address) {
+    let context = Context::Contract(ContractContext {
+        contract: Address::generate(e),
+        fn_name: Symbol::new(e, "transfer"),
+        args: vec![e],
+    });
+    let payload = Bytes::from_array(e, &[1u8; 32]);
+    do_check_auth(e, &e.crypto().sha256(&payload), &signatures(e, signer), &vec![e, context])
+        .unwrap();
+}
+
+#[test]
+fn bind_token_creates_owner_rule() {
+    let e = Env::default();
+    let (account, collection, owner) = setup(&e);
+
+    e.as_contract(&account, || {
+        let rule = bind_token(&e, &collection, 7);
+        assert_eq!(rule.context_type, ContextRuleType::Default);
+        assert_eq!(rule.signers, vec![&e, Signer::Delegated(owner.clone())]);
+        assert_eq!(get_context_rule(&e, rule.id), rule);
+        assert_eq!(bound_token(&e), BoundToken { collection: collection.clone(), token_id: 7 });
+        assert_eq!(bound_owner(&e).owner, owner);
+        assert_eq!(bound_owner(&e).rule_id, rule.id);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3301)")]
+fn bind_token_twice_fails() {
+    let e = Env::default();
+    let (account, collection, _) = setup(&e);
+
+    e.as_contract(&account, || {
+        bind_token(&e, &collection, 7);
+        bind_token(&e, &collection, 8);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3300)")]
+fn bound_token_not_bound_fails() {
+    let e = Env::default();
+    let (account, ..) = setup(&e);
+
+    e.as_contract(&account, || bound_token(&e));
+}
+
+#[test]
+fn token_owner_follows_transfers() {
+    let e = Env::default();
+    let (account, collection, owner) = setup(&e);
+
+    e.as_contract(&account, || {
+        bind_token(&e, &collection, 7);
+        assert_eq!(token_owner(&e), owner);
+    });
+
+    let new_owner = Address::generate(&e);
+    MockNftContractClient::new(&e, &collection).set_owner(&7, &new_owner);
+
+    e.as_contract(&account, || assert_eq!(token_owner(&e), new_owner));
+}
+
+#[test]
+fn sync_owner_replaces_owner_rule() {
+    let e = Env::default();
+    let (account, collection, _) = setup(&e);
+
+    let rule = e.as_contract(&account, || bind_token(&e, &collection, 7));
+    // nothing changes until the token is transferred
+    e.as_contract(&account, || assert_eq!(sync_owner(&e).rule_id, rule.id));
+
+    let new_owner = Address::generate(&e);
+    MockNftContractClient::new(&e, &collection).set_owner(&7, &new_owner);
+
+    e.as_contract(&account, || {
+        let bound = sync_owner(&e);
+        assert_eq!(bound.owner, new_owner);
+        assert!(bound.rule_id > rule.id);
+        assert_eq!(
+            get_context_rule(&e, bound.rule_id).signers,
+            vec![&e, Signer::Delegated(new_owner.clone())]
+        );
+        assert!(!e.storage().persistent().has(&SmartAccountStorageKey::Meta(rule.id)));
+    });
+}
+
+#[test]
+fn do_check_auth_owner_success() {
+    let e = Env::default();
+    let (account, collection, owner) = setup(&e);
+    e.mock_all_auths();
+
+    e.as_contract(&account, || {
+        bind_token(&e, &collection, 7);
+        check_auth(&e, &owner);
+    });
+}
+
+#[test]
+fn do_check_auth_new_owner_success() {
+    let e = Env::default();
+    let (account, collection, _) = setup(&e);
+    e.mock_all_auths();
+
+    e.as_contract(&account, || {
+        bind_token(&e, &collection, 7);
+    });
+
+    let new_owner = Address::generate(&e);
+    MockNftContractClient::new(&e, &collection).set_owner(&7, &new_owner);
+
+    e.as_contract(&account, || check_auth(&e, &new_owner));
+
+    // rules added by the new owner are honored
+    let delegate = Address::generate(&e);
+    e.as_contract(&account, || {
+        add_context_rule(
+            &e,
+            &ContextRuleType::Default,
+            &String::from_str(&e, "delegate"),
+            None,
+            &vec![&e, Signer::Delegated(delegate.clone())],
+            &Map::new(&e),
+        );
+    });
+    e.as_contract(&account, || check_auth(&e, &delegate));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3002)")]
+fn do_check_auth_previous_owner_fails() {
+    let e = Env::default();
+    let (account, collection, owner) = setup(&e);
+    e.mock_all_auths();
+
+    e.as_contract(&account, || {
+        bind_token(&e, &collection, 7);
+    });
+
+    MockNftContractClient::new(&e, &collection).set_owner(&7, &Address::generate(&e));
+
+    e.as_contract(&account, || check_auth(&e, &owner));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3002)")]
+fn do_check_auth_previous_owner_rule_fails() {
+    let e = Env::default();
+    let (account, collection, _) = setup(&e);
+    e.mock_all_auths();
+
+    // the seller adds a backdoor signer before the sale
+    let backdoor = Address::generate(&e);
+    e.as_contract(&account, || {
+        bind_token(&e, &collection, 7);
+        add_context_rule(
+            &e,
+            &ContextRuleType::Default,
+            &String::from_str(&e, "backdoor"),
+            None,
+            &vec![&e, Signer::Delegated(backdoor.clone())],
+            &Map::new(&e),
+        );
+    });
+    e.as_contract(&account, || check_auth(&e, &backdoor));
+
+    MockNftContractClient::new(&e, &collection).set_owner(&7, &Address::generate(&e));
+
+    e.as_contract(&account, || check_auth(&e, &backdoor));
+}
+
+#[test]
+fn account_address_is_deterministic() {
+    let e = Env::default();
+    let registry = e.register(MockContract, ());
+    let collection = Address::generate(&e);
+    let wasm_hash = BytesN::from_array(&e, &[1u8; 32]);
+    let salt = BytesN::from_array(&e, &[0u8; 32]);
+
+    e.as_contract(&registry, || {
+        let account = account_address(&e, &wasm_hash, &collection, 7, &salt);
+        assert_eq!(account, account_address(&e, &wasm_hash, &collection, 7, &salt));
+        assert_ne!(account, account_address(&e, &wasm_hash, &collection, 8, &salt));
+        assert_ne!(
+            account,
+            account_address(&e, &wasm_hash, &collection, 7, &BytesN::from_array(&e, &[1u8; 32]))
+        );
+        assert_ne!(
+            account,
+            account_address(&e, &BytesN::from_array(&e, &[2u8; 32]), &collection, 7, &salt)
+        );
+    });
+}
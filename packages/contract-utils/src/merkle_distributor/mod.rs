@@ -38,6 +38,23 @@
 //! }
 //! ```
 //!
+//! ### Capped Claims
+//!
+//! Besides single claims, a leaf can be claimed multiple times up to a cap
+//! encoded in the leaf itself, e.g. "address X may mint up to N tokens". Such
+//! leaves **MUST** also implement `CappedLeaf`, and are claimed with
+//! `verify_and_add_claims` (or `verify_with_index_and_add_claims`), which track
+//! the number of claims per index. The two claiming mechanisms should not be
+//! mixed within the same tree.
+//!
+//! ```ignore,rust
+//! impl CappedLeaf for LeafData {
+//!     fn cap(&self) -> u32 {
+//!         self.cap
+//!     }
+//! }
+//! ```
+//!
 //! This structure supports a wide variety of distribution mechanisms such as:
 //!
 //! - Token airdrops
@@ -62,6 +79,10 @@ pub trait IndexableLeaf {
     fn index(&self) -> u32;
 }
 
+pub trait CappedLeaf: IndexableLeaf {
+    fn cap(&self) -> u32;
+}
+
 pub struct MerkleDistributor<H: Hasher>(PhantomData<H>);
 
 // ################## ERRORS ##################
@@ -76,6 +97,10 @@ pub enum MerkleDistributorError {
     IndexAlreadyClaimed = 1301,
     /// The proof is invalid.
     InvalidProof = 1302,
+    /// The claim would exceed the cap of the leaf.
+    ClaimCapExceeded = 1303,
+    /// The claimed amount is zero.
+    InvalidClaimAmount = 1304,
 }
 
 // ################## CONSTANTS ##################
@@ -100,6 +125,14 @@ pub struct SetClaimed {
     pub index: Val,
 }
 
+/// Event emitted when the claim count of an index is updated.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SetClaimCount {
+    pub index: u32,
+    pub count: u32,
+}
+
 /// Emits an event when the merkle root is set.
 ///
 /// # Arguments
@@ -119,3 +152,14 @@ pub fn emit_set_root(e: &Env, root: Bytes) {
 pub fn emit_set_claimed(e: &Env, index: Val) {
     SetClaimed { index }.publish(e);
 }
+
+/// Emits an event when the claim count of an index is updated.
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+/// * `index` - The index whose claim count was updated.
+/// * `count` - The new claim count.
+pub fn emit_set_claim_count(e: &Env, index: u32, count: u32) {
+    SetClaimCount { index, count }.publish(e);
+}
@@ -3,8 +3,9 @@ use soroban_sdk::{contracttype, panic_with_error, xdr::ToXdr, BytesN, Env, Vec};
 use crate::{
     crypto::{hasher::Hasher, merkle::Verifier},
     merkle_distributor::{
-        emit_set_claimed, emit_set_root, IndexableLeaf, MerkleDistributor, MerkleDistributorError,
-        MERKLE_CLAIMED_EXTEND_AMOUNT, MERKLE_CLAIMED_TTL_THRESHOLD,
+        emit_set_claim_count, emit_set_claimed, emit_set_root, CappedLeaf, IndexableLeaf,
+        MerkleDistributor, MerkleDistributorError, MERKLE_CLAIMED_EXTEND_AMOUNT,
+        MERKLE_CLAIMED_TTL_THRESHOLD,
     },
 };
 
@@ -15,6 +16,8 @@ pub enum MerkleDistributorStorageKey {
     Root,
     /// Maps an index to its claimed status
     Claimed(u32),
+    /// Maps an index to its number of claims
+    ClaimCount(u32),
 }
 
 impl<H> MerkleDistributor<H>
@@ -58,6 +61,27 @@ where
         }
     }
 
+    /// Returns the number of claims made for an index and extends its TTL if
+    /// any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `index` - The index to check.
+    pub fn get_claim_count(e: &Env, index: u32) -> u32 {
+        let key = MerkleDistributorStorageKey::ClaimCount(index);
+        if let Some(count) = e.storage().persistent().get(&key) {
+            e.storage().persistent().extend_ttl(
+                &key,
+                MERKLE_CLAIMED_TTL_THRESHOLD,
+                MERKLE_CLAIMED_EXTEND_AMOUNT,
+            );
+            count
+        } else {
+            0
+        }
+    }
+
     /// Sets the Merkle root for the distribution.
     ///
     /// # Arguments
@@ -104,6 +128,29 @@ where
         emit_set_claimed(e, index.into());
     }
 
+    /// Sets the number of claims made for an index.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `index` - The index to update.
+    /// * `count` - The new claim count.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["set_claim_count"]`
+    /// * data - `[index: u32, count: u32]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function lacks authorization checks and should only
+    /// be used in admin functions that implement their own authorization logic.
+    pub fn set_claim_count(e: &Env, index: u32, count: u32) {
+        let key = MerkleDistributorStorageKey::ClaimCount(index);
+        e.storage().persistent().set(&key, &count);
+        emit_set_claim_count(e, index, count);
+    }
+
     /// Verifies a Merkle proof for a leaf and marks its index as claimed if the
     /// proof is valid. Internally using [`Verifier::verify`] which assumes that
     /// when the tree gets constructed, **commutative** hashing was used,
@@ -182,6 +229,113 @@ where
         };
     }
 
+    /// Verifies a Merkle proof for a leaf and adds `amount` claims to its
+    /// index if the proof is valid, as long as the total does not exceed the
+    /// cap of the leaf. Returns the new claim count. Internally using
+    /// [`Verifier::verify`] which assumes that when the tree gets constructed,
+    /// **commutative** hashing was used, i.e. the leaves are **sorted**.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `leaf` - The leaf data containing an index and a cap field.
+    /// * `proof` - The Merkle proof for the leaf.
+    /// * `amount` - The number of claims to add.
+    ///
+    /// # Errors
+    ///
+    /// * [`MerkleDistributorError::InvalidClaimAmount`] - When `amount` is
+    ///   zero.
+    /// * [`MerkleDistributorError::ClaimCapExceeded`] - When the claims would
+    ///   exceed the cap of the leaf.
+    /// * [`MerkleDistributorError::InvalidProof`] - When the provided Merkle
+    ///   proof is invalid.
+    /// * [`MerkleDistributorError::RootNotSet`] - When the root is not set.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["set_claim_count"]`
+    /// * data - `[index: u32, count: u32]`
+    pub fn verify_and_add_claims<N: ToXdr + CappedLeaf>(
+        e: &Env,
+        leaf: N,
+        proof: Vec<H::Output>,
+        amount: u32,
+    ) -> u32 {
+        let (index, count) = Self::get_new_claim_count(e, &leaf, amount);
+        let (root, leaf_hash, _) = Self::get_verification_args(e, leaf);
+
+        match Verifier::<H>::verify(e, proof, root, leaf_hash) {
+            true => Self::set_claim_count(e, index, count),
+            false => panic_with_error!(e, MerkleDistributorError::InvalidProof),
+        };
+        count
+    }
+
+    /// Verifies a Merkle proof for a leaf and adds `amount` claims to its
+    /// index if the proof is valid, as long as the total does not exceed the
+    /// cap of the leaf. Returns the new claim count. Internally using
+    /// [`Verifier::verify_with_index`] which assumes that when the tree gets
+    /// constructed, **non-commutative** hashing was used, i.e. the leaves and
+    /// the nodes are **unsorted**.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `leaf` - The leaf data containing an index and a cap field.
+    /// * `proof` - The Merkle proof for the leaf.
+    /// * `amount` - The number of claims to add.
+    ///
+    /// # Errors
+    ///
+    /// * [`MerkleDistributorError::InvalidClaimAmount`] - When `amount` is
+    ///   zero.
+    /// * [`MerkleDistributorError::ClaimCapExceeded`] - When the claims would
+    ///   exceed the cap of the leaf.
+    /// * [`MerkleDistributorError::InvalidProof`] - When the provided Merkle
+    ///   proof is invalid.
+    /// * [`MerkleDistributorError::RootNotSet`] - When the root is not set.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["set_claim_count"]`
+    /// * data - `[index: u32, count: u32]`
+    pub fn verify_with_index_and_add_claims<N: ToXdr + CappedLeaf>(
+        e: &Env,
+        leaf: N,
+        proof: Vec<H::Output>,
+        amount: u32,
+    ) -> u32 {
+        let (index, count) = Self::get_new_claim_count(e, &leaf, amount);
+        let (root, leaf_hash, _) = Self::get_verification_args(e, leaf);
+
+        match Verifier::<H>::verify_with_index(e, proof, root, leaf_hash, index) {
+            true => Self::set_claim_count(e, index, count),
+            false => panic_with_error!(e, MerkleDistributorError::InvalidProof),
+        };
+        count
+    }
+
+    /// Internal helper function that returns the index of the leaf and its
+    /// claim count after adding `amount` claims, checking it against the cap.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `leaf` - The leaf data containing an index and a cap field.
+    /// * `amount` - The number of claims to add.
+    fn get_new_claim_count<N: CappedLeaf>(e: &Env, leaf: &N, amount: u32) -> (u32, u32) {
+        if amount == 0 {
+            panic_with_error!(e, MerkleDistributorError::InvalidClaimAmount);
+        }
+        let index = leaf.index();
+        let count = Self::get_claim_count(e, index)
+            .checked_add(amount)
+            .filter(|count| *count <= leaf.cap())
+            .unwrap_or_else(|| panic_with_error!(e, MerkleDistributorError::ClaimCapExceeded));
+        (index, count)
+    }
+
     /// Internal helper function that returns a tuple of the root, the hashed
     /// leaf and the leaf index.
     ///
@@ -2,13 +2,17 @@ extern crate std;
 
 use hex_literal::hex;
 use soroban_sdk::{
-    contract, contracttype, testutils::Address as _, vec, Address, BytesN, Env, Vec,
+    contract, contracttype, testutils::Address as _, vec, xdr::ToXdr, Address, BytesN, Env, Vec,
 };
 use stellar_event_assertion::EventAssertion;
 
 use crate::{
-    crypto::sha256::Sha256,
-    merkle_distributor::{IndexableLeaf, MerkleDistributor},
+    crypto::{
+        hashable::{commutative_hash_pair, hash_pair},
+        hasher::Hasher,
+        sha256::Sha256,
+    },
+    merkle_distributor::{CappedLeaf, IndexableLeaf, MerkleDistributor},
 };
 
 type Bytes32 = BytesN<32>;
@@ -211,3 +215,114 @@ fn test_unsorted_verify_with_invalid_proof_fails() {
         Distributor::verify_with_index_and_set_claimed(&e, data, proof);
     });
 }
+
+#[contracttype]
+#[derive(Debug, Clone)]
+struct CappedLeafData {
+    pub index: u32,
+    pub address: Address,
+    pub cap: u32,
+}
+
+impl IndexableLeaf for CappedLeafData {
+    fn index(&self) -> u32 {
+        self.index
+    }
+}
+
+impl CappedLeaf for CappedLeafData {
+    fn cap(&self) -> u32 {
+        self.cap
+    }
+}
+
+fn hash_leaf(e: &Env, leaf: &CappedLeafData) -> Bytes32 {
+    let mut hasher = Sha256::new(e);
+    hasher.update(leaf.clone().to_xdr(e));
+    hasher.finalize()
+}
+
+fn get_capped_args(e: &Env) -> (Bytes32, CappedLeafData, Vec<Bytes32>) {
+    let data = CappedLeafData { index: 0, address: Address::generate(e), cap: 3 };
+    let other = CappedLeafData { index: 1, address: Address::generate(e), cap: 1 };
+    let (leaf_hash, other_hash) = (hash_leaf(e, &data), hash_leaf(e, &other));
+    let root = commutative_hash_pair(&leaf_hash, &other_hash, Sha256::new(e));
+    (root, data, vec![e, other_hash])
+}
+
+#[test]
+fn test_capped_claims_up_to_cap_succeed() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    let (root, data, proof) = get_capped_args(&e);
+    e.as_contract(&address, || {
+        Distributor::set_root(&e, root);
+        assert_eq!(Distributor::get_claim_count(&e, 0), 0);
+
+        assert_eq!(Distributor::verify_and_add_claims(&e, data.clone(), proof.clone(), 2), 2);
+        assert_eq!(Distributor::verify_and_add_claims(&e, data, proof, 1), 3);
+        assert_eq!(Distributor::get_claim_count(&e, 0), 3);
+
+        let assert = EventAssertion::new(&e, address.clone());
+        assert.assert_event_count(3);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1303)")]
+fn test_capped_claims_exceeding_cap_fail() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    let (root, data, proof) = get_capped_args(&e);
+    e.as_contract(&address, || {
+        Distributor::set_root(&e, root);
+        Distributor::verify_and_add_claims(&e, data.clone(), proof.clone(), 2);
+        Distributor::verify_and_add_claims(&e, data, proof, 2);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1304)")]
+fn test_capped_claims_zero_amount_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    let (root, data, proof) = get_capped_args(&e);
+    e.as_contract(&address, || {
+        Distributor::set_root(&e, root);
+        Distributor::verify_and_add_claims(&e, data, proof, 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1302)")]
+fn test_capped_claims_with_raised_cap_fail() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    let (root, data, proof) = get_capped_args(&e);
+    let data = CappedLeafData { cap: 10, ..data };
+    e.as_contract(&address, || {
+        Distributor::set_root(&e, root);
+        Distributor::verify_and_add_claims(&e, data, proof, 5);
+    });
+}
+
+#[test]
+fn test_unsorted_capped_claims_succeed() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    let data = CappedLeafData { index: 0, address: Address::generate(&e), cap: 2 };
+    let other = CappedLeafData { index: 1, address: Address::generate(&e), cap: 2 };
+    let other_hash = hash_leaf(&e, &other);
+    let root = hash_pair(&hash_leaf(&e, &data), &other_hash, Sha256::new(&e));
+    e.as_contract(&address, || {
+        Distributor::set_root(&e, root);
+        Distributor::verify_with_index_and_add_claims(&e, data.clone(), vec![&e, other_hash], 2);
+        assert_eq!(Distributor::get_claim_count(&e, 0), 2);
+        assert_eq!(Distributor::get_claim_count(&e, 1), 0);
+    });
+}
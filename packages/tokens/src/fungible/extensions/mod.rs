@@ -2,4 +2,8 @@ pub mod allowlist;
 pub mod blocklist;
 pub mod burnable;
 pub mod capped;
+pub mod rebasing;
+pub mod snapshot;
+pub mod transfer_fee;
 pub mod votes;
+pub mod wrapper;
@@ -0,0 +1,127 @@
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contractevent, Address, Env};
+pub use storage::{Rebasing, RebasingStorageKey};
+
+use crate::fungible::FungibleToken;
+
+/// Rebasing Trait for Fungible Token
+///
+/// The `FungibleRebasing` trait extends the `FungibleToken` trait to support
+/// elastic-supply and yield-bearing tokens. Instead of token amounts, the
+/// contract stores shares for every account, and balances are derived from a
+/// global rebase index:
+///
+/// ```text
+/// balance = shares * index / REBASE_INDEX_SCALE
+/// ```
+///
+/// Updating the index changes every balance, as well as the total supply, at
+/// once and at constant cost, without touching any account storage. The index
+/// starts at [`REBASE_INDEX_SCALE`], i.e. 1 share = 1 token.
+///
+/// The `FungibleToken` interface is kept intact: `balance`, `total_supply`,
+/// `transfer`, `transfer_from`, allowances and all emitted events are
+/// expressed in token units. Conversions between token units and shares use
+/// the rounding helpers from `stellar_contract_utils::math` and always round
+/// in favor of the token:
+///
+/// - transferring or burning `amount` tokens debits the shares rounded up,
+/// - minting `amount` tokens credits the shares rounded down,
+/// - balances and the total supply are rounded down.
+///
+/// As a consequence, the recipient of a transfer may observe a balance
+/// increase that differs from `amount` by a rounding unit.
+///
+/// This trait is designed to be used in conjunction with the `FungibleToken`
+/// trait, with [`Rebasing`] as its `ContractType`. When combined with
+/// [`crate::fungible::burnable::FungibleBurnable`], `burn` and `burn_from` must
+/// be overridden to call [`Rebasing::burn`] and [`Rebasing::burn_from`], and
+/// minting must go through [`Rebasing::mint`]. Calling `Base` functions that
+/// modify balances directly operates on shares instead of token units.
+///
+/// **NOTE**
+///
+/// [`FungibleRebasing::set_rebase_index`] includes an additional parameter
+/// `operator: Address`. This account is the one authorizing the invocation.
+/// Having it as a parameter grants the flexibility to introduce simple or
+/// complex role-based access controls.
+///
+/// There is no default implementation for this trait on purpose.
+///
+/// Because, there are no default implementation to enforce how the
+/// authorization should be configured. Not providing a default implementation
+/// for this trait is a reminder for the implementor to provide the
+/// authorization logic for this trait.
+pub trait FungibleRebasing: FungibleToken<ContractType = Rebasing> {
+    /// Returns the current rebase index, scaled by [`REBASE_INDEX_SCALE`].
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn rebase_index(e: &Env) -> i128;
+
+    /// Returns the amount of shares held by `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the shares are being queried.
+    fn shares_of(e: &Env, account: Address) -> i128;
+
+    /// Returns the total amount of shares in circulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn total_shares(e: &Env) -> i128;
+
+    /// Sets the rebase index, scaled by [`REBASE_INDEX_SCALE`], which changes
+    /// every balance and the total supply proportionally.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `index` - The new rebase index.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::fungible::FungibleTokenError::InvalidRebaseIndex`] - When
+    ///   `index <= 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["rebase"]`
+    /// * data - `[old_index: i128, new_index: i128]`
+    fn set_rebase_index(e: &Env, index: i128, operator: Address);
+}
+
+// ################## CONSTANTS ##################
+
+/// Fixed-point scale of the rebase index (18 decimals).
+pub const REBASE_INDEX_SCALE: i128 = 1_000_000_000_000_000_000;
+
+// ################## EVENTS ##################
+
+/// Event emitted when the rebase index is updated.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rebase {
+    pub old_index: i128,
+    pub new_index: i128,
+}
+
+/// Emits an event indicating the rebase index was updated.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `old_index` - The previous rebase index.
+/// * `new_index` - The new rebase index.
+pub fn emit_rebase(e: &Env, old_index: i128, new_index: i128) {
+    Rebase { old_index, new_index }.publish(e);
+}
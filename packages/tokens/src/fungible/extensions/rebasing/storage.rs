@@ -0,0 +1,338 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, MuxedAddress};
+use stellar_contract_utils::math::{mul_div_i128, Rounding};
+
+use crate::fungible::{
+    emit_mint, emit_transfer,
+    extensions::{
+        burnable::emit_burn,
+        rebasing::{emit_rebase, REBASE_INDEX_SCALE},
+    },
+    Base, ContractOverrides, FungibleTokenError,
+};
+
+pub struct Rebasing;
+
+impl ContractOverrides for Rebasing {
+    fn total_supply(e: &Env) -> i128 {
+        Rebasing::total_supply(e)
+    }
+
+    fn balance(e: &Env, account: &Address) -> i128 {
+        Rebasing::balance(e, account)
+    }
+
+    fn transfer(e: &Env, from: &Address, to: &MuxedAddress, amount: i128) {
+        Rebasing::transfer(e, from, to, amount);
+    }
+
+    fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, amount: i128) {
+        Rebasing::transfer_from(e, spender, from, to, amount);
+    }
+}
+
+/// Storage keys for the data associated with the rebasing extension
+#[contracttype]
+pub enum RebasingStorageKey {
+    /// Current rebase index
+    Index,
+}
+
+impl Rebasing {
+    // ################## QUERY STATE ##################
+
+    /// Returns the current rebase index, scaled by [`REBASE_INDEX_SCALE`].
+    /// Defaults to [`REBASE_INDEX_SCALE`] if the index was never set.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn index(e: &Env) -> i128 {
+        e.storage().instance().get(&RebasingStorageKey::Index).unwrap_or(REBASE_INDEX_SCALE)
+    }
+
+    /// Returns the amount of shares held by `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the shares are being queried.
+    pub fn shares_of(e: &Env, account: &Address) -> i128 {
+        Base::balance(e, account)
+    }
+
+    /// Returns the total amount of shares in circulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn total_shares(e: &Env) -> i128 {
+        Base::total_supply(e)
+    }
+
+    /// Returns the balance of `account` in token units, rounded down.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the balance is being queried.
+    pub fn balance(e: &Env, account: &Address) -> i128 {
+        Rebasing::to_amount(e, Rebasing::shares_of(e, account), Rounding::Floor)
+    }
+
+    /// Returns the total supply in token units, rounded down.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn total_supply(e: &Env) -> i128 {
+        Rebasing::to_amount(e, Rebasing::total_shares(e), Rounding::Floor)
+    }
+
+    /// Converts an amount of tokens to shares at the current index.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `amount` - The amount of tokens to convert.
+    /// * `rounding` - The rounding direction.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`mul_div_i128`] errors.
+    pub fn to_shares(e: &Env, amount: i128, rounding: Rounding) -> i128 {
+        mul_div_i128(e, amount, REBASE_INDEX_SCALE, Rebasing::index(e), rounding)
+    }
+
+    /// Converts an amount of shares to tokens at the current index.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `shares` - The amount of shares to convert.
+    /// * `rounding` - The rounding direction.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`mul_div_i128`] errors.
+    pub fn to_amount(e: &Env, shares: i128, rounding: Rounding) -> i128 {
+        mul_div_i128(e, shares, Rebasing::index(e), REBASE_INDEX_SCALE, rounding)
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Sets the rebase index, scaled by [`REBASE_INDEX_SCALE`].
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `index` - The new rebase index.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::InvalidRebaseIndex`] - When `index <= 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["rebase"]`
+    /// * data - `[old_index: i128, new_index: i128]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used in admin functions that implement their own authorization
+    /// logic.
+    pub fn set_index(e: &Env, index: i128) {
+        if index <= 0 {
+            panic_with_error!(e, FungibleTokenError::InvalidRebaseIndex);
+        }
+
+        let old_index = Rebasing::index(e);
+        e.storage().instance().set(&RebasingStorageKey::Index, &index);
+        emit_rebase(e, old_index, index);
+    }
+
+    // ################## OVERRIDDEN FUNCTIONS ##################
+
+    /// Transfers `amount` of tokens from `from` to `to` by moving the
+    /// equivalent shares, rounded up.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `amount` - The amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::LessThanZero`] - When `amount < 0`.
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[to_muxed_id: Option<u64>, amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn transfer(e: &Env, from: &Address, to: &MuxedAddress, amount: i128) {
+        from.require_auth();
+        Rebasing::update(e, Some(from), Some(&to.address()), amount);
+        emit_transfer(e, from, &to.address(), to.id(), amount);
+    }
+
+    /// Transfers `amount` of tokens from `from` to `to` using the
+    /// allowance mechanism, by moving the equivalent shares, rounded up.
+    /// Allowances are expressed in token units.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `spender` - The address authorizing the transfer, and having its
+    ///   allowance consumed during the transfer.
+    /// * `from` - The address holding the tokens which will be transferred.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `amount` - The amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::LessThanZero`] - When `amount < 0`.
+    /// * refer to [`Base::spend_allowance`] errors.
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[to_muxed_id: Option<u64>, amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `spender` is required.
+    pub fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, amount: i128) {
+        spender.require_auth();
+        Base::spend_allowance(e, from, spender, amount);
+        Rebasing::update(e, Some(from), Some(to), amount);
+        emit_transfer(e, from, to, None, amount);
+    }
+
+    /// Creates `amount` of tokens and assigns them to `to`, by crediting the
+    /// equivalent shares, rounded down.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The address receiving the new tokens.
+    /// * `amount` - The amount of tokens to mint.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::LessThanZero`] - When `amount < 0`.
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can execute
+    /// minting operations.
+    pub fn mint(e: &Env, to: &Address, amount: i128) {
+        Rebasing::update(e, None, Some(to), amount);
+        emit_mint(e, to, amount);
+    }
+
+    /// Destroys `amount` of tokens from `from`, by debiting the equivalent
+    /// shares, rounded up.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The account whose tokens are destroyed.
+    /// * `amount` - The amount of tokens to burn.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::LessThanZero`] - When `amount < 0`.
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn burn(e: &Env, from: &Address, amount: i128) {
+        from.require_auth();
+        Rebasing::update(e, Some(from), None, amount);
+        emit_burn(e, from, amount);
+    }
+
+    /// Destroys `amount` of tokens from `from` using the allowance mechanism,
+    /// by debiting the equivalent shares, rounded up.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorized to burn the tokens.
+    /// * `from` - The account whose tokens are destroyed.
+    /// * `amount` - The amount of tokens to burn.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::LessThanZero`] - When `amount < 0`.
+    /// * refer to [`Base::spend_allowance`] errors.
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `spender` is required.
+    pub fn burn_from(e: &Env, spender: &Address, from: &Address, amount: i128) {
+        spender.require_auth();
+        Base::spend_allowance(e, from, spender, amount);
+        Rebasing::update(e, Some(from), None, amount);
+        emit_burn(e, from, amount);
+    }
+
+    /// Moves the shares equivalent to `amount` of tokens from `from` to `to`.
+    /// Shares are rounded down when minting (`from` is `None`) and rounded up
+    /// otherwise, so that rounding never favors the caller.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `from` - The address holding the tokens. `None` when minting.
+    /// * `to` - The address receiving the tokens. `None` when burning.
+    /// * `amount` - The amount of tokens to be moved.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::LessThanZero`] - When `amount < 0`.
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Notes
+    ///
+    /// This function does not enforce authorization. Ensure that authorization
+    /// is handled at a higher level.
+    pub fn update(e: &Env, from: Option<&Address>, to: Option<&Address>, amount: i128) {
+        if amount < 0 {
+            panic_with_error!(e, FungibleTokenError::LessThanZero);
+        }
+
+        let rounding = if from.is_none() { Rounding::Floor } else { Rounding::Ceil };
+        let shares = Rebasing::to_shares(e, amount, rounding);
+        Base::update(e, from, to, shares);
+    }
+}
@@ -0,0 +1,255 @@
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, Address, Env, MuxedAddress};
+use stellar_contract_utils::math::Rounding;
+use stellar_event_assertion::EventAssertion;
+
+use crate::fungible::{
+    extensions::rebasing::{Rebasing, REBASE_INDEX_SCALE},
+    Base, ContractOverrides,
+};
+
+#[contract]
+struct MockContract;
+
+fn setup_env() -> (Env, Address) {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_address = e.register(MockContract, ());
+    (e, contract_address)
+}
+
+#[test]
+fn default_index_is_one_to_one() {
+    let (e, contract_address) = setup_env();
+    let alice = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        assert_eq!(Rebasing::index(&e), REBASE_INDEX_SCALE);
+
+        Rebasing::mint(&e, &alice, 100);
+        assert_eq!(Rebasing::balance(&e, &alice), 100);
+        assert_eq!(Rebasing::shares_of(&e, &alice), 100);
+        assert_eq!(Rebasing::total_supply(&e), 100);
+        assert_eq!(Rebasing::total_shares(&e), 100);
+
+        let mut event_assert = EventAssertion::new(&e, contract_address.clone());
+        event_assert.assert_fungible_mint(&alice, 100);
+    });
+}
+
+#[test]
+fn rebase_scales_balances_and_supply() {
+    let (e, contract_address) = setup_env();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        Rebasing::mint(&e, &alice, 100);
+        Rebasing::mint(&e, &bob, 300);
+
+        // +10% yield
+        Rebasing::set_index(&e, REBASE_INDEX_SCALE * 11 / 10);
+
+        assert_eq!(Rebasing::balance(&e, &alice), 110);
+        assert_eq!(Rebasing::balance(&e, &bob), 330);
+        assert_eq!(Rebasing::total_supply(&e), 440);
+        assert_eq!(Rebasing::shares_of(&e, &alice), 100);
+        assert_eq!(Rebasing::total_shares(&e), 400);
+
+        // Negative rebase
+        Rebasing::set_index(&e, REBASE_INDEX_SCALE / 2);
+
+        assert_eq!(Rebasing::balance(&e, &alice), 50);
+        assert_eq!(Rebasing::total_supply(&e), 200);
+    });
+}
+
+#[test]
+fn set_index_emits_event() {
+    let (e, contract_address) = setup_env();
+
+    e.as_contract(&contract_address, || {
+        Rebasing::set_index(&e, 2 * REBASE_INDEX_SCALE);
+        assert_eq!(Rebasing::index(&e), 2 * REBASE_INDEX_SCALE);
+
+        let event_assert = EventAssertion::new(&e, contract_address.clone());
+        event_assert.assert_event_count(1);
+    });
+}
+
+#[test]
+fn transfer_moves_shares_in_token_units() {
+    let (e, contract_address) = setup_env();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        Rebasing::mint(&e, &alice, 100);
+        Rebasing::set_index(&e, 2 * REBASE_INDEX_SCALE);
+    });
+    e.as_contract(&contract_address, || {
+        Rebasing::transfer(&e, &alice, &MuxedAddress::from(bob.clone()), 50);
+
+        assert_eq!(Rebasing::balance(&e, &alice), 150);
+        assert_eq!(Rebasing::balance(&e, &bob), 50);
+        assert_eq!(Rebasing::shares_of(&e, &alice), 75);
+        assert_eq!(Rebasing::shares_of(&e, &bob), 25);
+
+        let mut event_assert = EventAssertion::new(&e, contract_address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_fungible_transfer(&alice, &bob, None, 50);
+    });
+}
+
+#[test]
+fn transfer_rounds_shares_up() {
+    let (e, contract_address) = setup_env();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        Rebasing::mint(&e, &alice, 100);
+        Rebasing::set_index(&e, 3 * REBASE_INDEX_SCALE);
+    });
+    e.as_contract(&contract_address, || {
+        // 10 tokens = 3.33.. shares, rounded up to 4
+        Rebasing::transfer(&e, &alice, &MuxedAddress::from(bob.clone()), 10);
+
+        assert_eq!(Rebasing::shares_of(&e, &alice), 96);
+        assert_eq!(Rebasing::shares_of(&e, &bob), 4);
+        assert_eq!(Rebasing::balance(&e, &bob), 12);
+    });
+}
+
+#[test]
+fn transfer_full_balance() {
+    let (e, contract_address) = setup_env();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        Rebasing::mint(&e, &alice, 7);
+        Rebasing::set_index(&e, REBASE_INDEX_SCALE * 3 / 2);
+    });
+    e.as_contract(&contract_address, || {
+        let balance = Rebasing::balance(&e, &alice);
+        assert_eq!(balance, 10);
+
+        Rebasing::transfer(&e, &alice, &MuxedAddress::from(bob.clone()), balance);
+        assert_eq!(Rebasing::shares_of(&e, &alice), 0);
+        assert_eq!(Rebasing::shares_of(&e, &bob), 7);
+    });
+}
+
+#[test]
+fn transfer_from_spends_allowance_in_token_units() {
+    let (e, contract_address) = setup_env();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        Rebasing::mint(&e, &alice, 100);
+        Rebasing::set_index(&e, 2 * REBASE_INDEX_SCALE);
+        Base::approve(&e, &alice, &spender, 120, 1000);
+    });
+    e.as_contract(&contract_address, || {
+        Rebasing::transfer_from(&e, &spender, &alice, &bob, 100);
+
+        assert_eq!(Rebasing::balance(&e, &alice), 100);
+        assert_eq!(Rebasing::balance(&e, &bob), 100);
+        assert_eq!(Base::allowance(&e, &alice, &spender), 20);
+    });
+}
+
+#[test]
+fn mint_and_burn_round_against_caller() {
+    let (e, contract_address) = setup_env();
+    let alice = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        Rebasing::set_index(&e, 3 * REBASE_INDEX_SCALE);
+
+        // 10 tokens = 3.33.. shares, rounded down to 3
+        Rebasing::mint(&e, &alice, 10);
+        assert_eq!(Rebasing::shares_of(&e, &alice), 3);
+        assert_eq!(Rebasing::balance(&e, &alice), 9);
+    });
+    e.as_contract(&contract_address, || {
+        // 4 tokens = 1.33.. shares, rounded up to 2
+        Rebasing::burn(&e, &alice, 4);
+        assert_eq!(Rebasing::shares_of(&e, &alice), 1);
+        assert_eq!(Rebasing::total_shares(&e), 1);
+
+        let mut event_assert = EventAssertion::new(&e, contract_address.clone());
+        event_assert.assert_fungible_burn(&alice, 4);
+    });
+}
+
+#[test]
+fn conversions() {
+    let (e, contract_address) = setup_env();
+
+    e.as_contract(&contract_address, || {
+        Rebasing::set_index(&e, REBASE_INDEX_SCALE * 5 / 4);
+
+        assert_eq!(Rebasing::to_amount(&e, 4, Rounding::Floor), 5);
+        assert_eq!(Rebasing::to_shares(&e, 5, Rounding::Floor), 4);
+        assert_eq!(Rebasing::to_shares(&e, 6, Rounding::Floor), 4);
+        assert_eq!(Rebasing::to_shares(&e, 6, Rounding::Ceil), 5);
+    });
+}
+
+#[test]
+fn contract_overrides_use_token_units() {
+    let (e, contract_address) = setup_env();
+    let alice = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        Rebasing::mint(&e, &alice, 100);
+        Rebasing::set_index(&e, 2 * REBASE_INDEX_SCALE);
+
+        assert_eq!(<Rebasing as ContractOverrides>::balance(&e, &alice), 200);
+        assert_eq!(<Rebasing as ContractOverrides>::total_supply(&e), 200);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #119)")]
+fn set_zero_index_panics() {
+    let (e, contract_address) = setup_env();
+
+    e.as_contract(&contract_address, || {
+        Rebasing::set_index(&e, 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #103)")]
+fn transfer_negative_amount_panics() {
+    let (e, contract_address) = setup_env();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        Rebasing::set_index(&e, 2 * REBASE_INDEX_SCALE);
+        Rebasing::transfer(&e, &alice, &MuxedAddress::from(bob.clone()), -1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #100)")]
+fn transfer_more_than_balance_panics() {
+    let (e, contract_address) = setup_env();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        Rebasing::mint(&e, &alice, 10);
+        Rebasing::set_index(&e, 2 * REBASE_INDEX_SCALE);
+    });
+    e.as_contract(&contract_address, || {
+        Rebasing::transfer(&e, &alice, &MuxedAddress::from(bob.clone()), 21);
+    });
+}
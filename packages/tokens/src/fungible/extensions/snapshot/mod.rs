@@ -0,0 +1,122 @@
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contractevent, Address, Env};
+pub use storage::{Snapshot, SnapshotCheckpoint, SnapshotStorageKey};
+
+use crate::fungible::FungibleToken;
+
+/// Snapshot Trait for Fungible Token
+///
+/// The `FungibleSnapshot` trait extends the `FungibleToken` trait to record
+/// balances and the total supply at specific points in time, identified by
+/// snapshot ids. Historical values can be queried later with
+/// [`FungibleSnapshot::balance_of_at`] and
+/// [`FungibleSnapshot::total_supply_at`], which makes this extension suitable
+/// for dividends, airdrops or any other distribution based on raw holdings.
+///
+/// Unlike the `votes` extension, no delegation is required: every holder's
+/// balance is tracked as-is.
+///
+/// Snapshots are recorded lazily. Taking a snapshot only increments the
+/// current snapshot id, and the previous value of an account balance (or of
+/// the total supply) is written to storage the first time it changes after
+/// that. Accounts that are not touched between two snapshots incur no storage
+/// cost.
+///
+/// This trait is designed to be used in conjunction with the `FungibleToken`
+/// trait, with [`Snapshot`] as its `ContractType`. When combined with
+/// [`crate::fungible::burnable::FungibleBurnable`], `burn` and `burn_from` must
+/// be overridden to call [`Snapshot::burn`] and [`Snapshot::burn_from`], and
+/// minting must go through [`Snapshot::mint`]. Balance changes bypassing the
+/// [`Snapshot`] functions are not recorded.
+///
+/// **NOTE**
+///
+/// [`FungibleSnapshot::snapshot`] includes an additional parameter `operator:
+/// Address`. This account is the one authorizing the invocation. Having it as
+/// a parameter grants the flexibility to introduce simple or complex
+/// role-based access controls.
+///
+/// There is no default implementation for this trait on purpose.
+///
+/// Because, there are no default implementation to enforce how the
+/// authorization should be configured. Not providing a default implementation
+/// for this trait is a reminder for the implementor to provide the
+/// authorization logic for this trait.
+pub trait FungibleSnapshot: FungibleToken<ContractType = Snapshot> {
+    /// Creates a new snapshot and returns its id.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::fungible::FungibleTokenError::MathOverflow`] - When the
+    ///   snapshot id overflows.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["snapshot_created"]`
+    /// * data - `[snapshot_id: u32]`
+    fn snapshot(e: &Env, operator: Address) -> u32;
+
+    /// Returns the id of the most recent snapshot, or `0` if no snapshot was
+    /// taken yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn current_snapshot_id(e: &Env) -> u32;
+
+    /// Returns the balance of `account` at the time `snapshot_id` was
+    /// created.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the balance is being queried.
+    /// * `snapshot_id` - The id of the snapshot.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::fungible::FungibleTokenError::InvalidSnapshotId`] - When
+    ///   `snapshot_id` is `0` or greater than the current snapshot id.
+    fn balance_of_at(e: &Env, account: Address, snapshot_id: u32) -> i128;
+
+    /// Returns the total supply at the time `snapshot_id` was created.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `snapshot_id` - The id of the snapshot.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::fungible::FungibleTokenError::InvalidSnapshotId`] - When
+    ///   `snapshot_id` is `0` or greater than the current snapshot id.
+    fn total_supply_at(e: &Env, snapshot_id: u32) -> i128;
+}
+
+// ################## EVENTS ##################
+
+/// Event emitted when a new snapshot is created.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SnapshotCreated {
+    pub snapshot_id: u32,
+}
+
+/// Emits an event when a new snapshot is created.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `snapshot_id` - The id of the created snapshot.
+pub fn emit_snapshot_created(e: &Env, snapshot_id: u32) {
+    SnapshotCreated { snapshot_id }.publish(e);
+}
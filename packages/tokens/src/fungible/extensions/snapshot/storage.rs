@@ -0,0 +1,430 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, MuxedAddress};
+
+use crate::fungible::{
+    emit_mint, emit_transfer,
+    extensions::{burnable::emit_burn, snapshot::emit_snapshot_created},
+    overrides::WrapperOverrides,
+    Base, ContractOverrides, FungibleTokenError, BALANCE_EXTEND_AMOUNT, BALANCE_TTL_THRESHOLD,
+};
+
+pub struct Snapshot;
+
+impl ContractOverrides for Snapshot {
+    fn transfer(e: &Env, from: &Address, to: &MuxedAddress, amount: i128) {
+        Snapshot::transfer(e, from, to, amount);
+    }
+
+    fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, amount: i128) {
+        Snapshot::transfer_from(e, spender, from, to, amount);
+    }
+}
+
+impl WrapperOverrides for Snapshot {
+    fn mint(e: &Env, to: &Address, amount: i128) {
+        Snapshot::mint(e, to, amount);
+    }
+
+    fn burn(e: &Env, from: &Address, amount: i128) {
+        Snapshot::burn(e, from, amount);
+    }
+}
+
+/// A value recorded for a given snapshot id.
+///
+/// The value is the one that was in effect when `snapshot_id` was created,
+/// and it remained unchanged until the checkpoint was written.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SnapshotCheckpoint {
+    pub snapshot_id: u32,
+    pub value: i128,
+}
+
+/// Storage keys for the data associated with the snapshot extension
+#[contracttype]
+pub enum SnapshotStorageKey {
+    /// Id of the most recent snapshot
+    CurrentSnapshotId,
+    /// Number of checkpoints recorded for an account
+    NumAccountCheckpoints(Address),
+    /// Individual account checkpoint at index
+    AccountCheckpoint(Address, u32),
+    /// Number of checkpoints recorded for the total supply
+    NumTotalSupplyCheckpoints,
+    /// Individual total supply checkpoint at index
+    TotalSupplyCheckpoint(u32),
+}
+
+/// Selects the checkpoint history to operate on.
+enum History {
+    TotalSupply,
+    Account(Address),
+}
+
+impl Snapshot {
+    // ################## QUERY STATE ##################
+
+    /// Returns the id of the most recent snapshot, or `0` if no snapshot was
+    /// taken yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn current_snapshot_id(e: &Env) -> u32 {
+        e.storage().instance().get(&SnapshotStorageKey::CurrentSnapshotId).unwrap_or(0)
+    }
+
+    /// Returns the balance of `account` at the time `snapshot_id` was
+    /// created.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the balance is being queried.
+    /// * `snapshot_id` - The id of the snapshot.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::InvalidSnapshotId`] - When `snapshot_id` is `0`
+    ///   or greater than the current snapshot id.
+    pub fn balance_of_at(e: &Env, account: &Address, snapshot_id: u32) -> i128 {
+        match lookup(e, &History::Account(account.clone()), snapshot_id) {
+            Some(value) => value,
+            None => Base::balance(e, account),
+        }
+    }
+
+    /// Returns the total supply at the time `snapshot_id` was created.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `snapshot_id` - The id of the snapshot.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::InvalidSnapshotId`] - When `snapshot_id` is `0`
+    ///   or greater than the current snapshot id.
+    pub fn total_supply_at(e: &Env, snapshot_id: u32) -> i128 {
+        match lookup(e, &History::TotalSupply, snapshot_id) {
+            Some(value) => value,
+            None => Base::total_supply(e),
+        }
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Creates a new snapshot and returns its id. Snapshot ids start at `1`
+    /// and increase monotonically.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::MathOverflow`] - When the snapshot id overflows.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["snapshot_created"]`
+    /// * data - `[snapshot_id: u32]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used in admin functions that implement their own authorization
+    /// logic.
+    pub fn snapshot(e: &Env) -> u32 {
+        let Some(snapshot_id) = Snapshot::current_snapshot_id(e).checked_add(1) else {
+            panic_with_error!(e, FungibleTokenError::MathOverflow);
+        };
+        e.storage().instance().set(&SnapshotStorageKey::CurrentSnapshotId, &snapshot_id);
+        emit_snapshot_created(e, snapshot_id);
+        snapshot_id
+    }
+
+    /// Records the pre-change values for the affected accounts (and the total
+    /// supply when minting or burning), then moves `amount` of tokens from
+    /// `from` to `to` through [`Base::update`].
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `from` - The address holding the tokens, `None` when minting.
+    /// * `to` - The address receiving the tokens, `None` when burning.
+    /// * `amount` - The amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Notes
+    ///
+    /// This function does not enforce authorization. Ensure that authorization
+    /// is handled at a higher level.
+    pub fn update(e: &Env, from: Option<&Address>, to: Option<&Address>, amount: i128) {
+        if let Some(account) = from {
+            update_history(e, &History::Account(account.clone()), Base::balance(e, account));
+        }
+        if let Some(account) = to {
+            update_history(e, &History::Account(account.clone()), Base::balance(e, account));
+        }
+        if from.is_none() || to.is_none() {
+            update_history(e, &History::TotalSupply, Base::total_supply(e));
+        }
+
+        Base::update(e, from, to, amount);
+    }
+
+    // ################## OVERRIDDEN FUNCTIONS ##################
+
+    /// Transfers `amount` of tokens from `from` to `to`, recording the
+    /// balances of both accounts for the current snapshot.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `amount` - The amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[to_muxed_id: Option<u64>, amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn transfer(e: &Env, from: &Address, to: &MuxedAddress, amount: i128) {
+        from.require_auth();
+        Snapshot::update(e, Some(from), Some(&to.address()), amount);
+        emit_transfer(e, from, &to.address(), to.id(), amount);
+    }
+
+    /// Transfers `amount` of tokens from `from` to `to` using the
+    /// allowance mechanism, recording the balances of both accounts for the
+    /// current snapshot.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `spender` - The address authorizing the transfer, and having its
+    ///   allowance consumed during the transfer.
+    /// * `from` - The address holding the tokens which will be transferred.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `amount` - The amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::spend_allowance`] errors.
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `spender` is required.
+    pub fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, amount: i128) {
+        spender.require_auth();
+        Base::spend_allowance(e, from, spender, amount);
+        Snapshot::update(e, Some(from), Some(to), amount);
+        emit_transfer(e, from, to, None, amount);
+    }
+
+    /// Creates `amount` of tokens and assigns them to `to`, recording the
+    /// balance of `to` and the total supply for the current snapshot.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The address receiving the new tokens.
+    /// * `amount` - The amount of tokens to mint.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can execute
+    /// minting operations.
+    pub fn mint(e: &Env, to: &Address, amount: i128) {
+        Snapshot::update(e, None, Some(to), amount);
+        emit_mint(e, to, amount);
+    }
+
+    /// Destroys `amount` of tokens from `from`, recording the balance of
+    /// `from` and the total supply for the current snapshot.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The account whose tokens are destroyed.
+    /// * `amount` - The amount of tokens to burn.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn burn(e: &Env, from: &Address, amount: i128) {
+        from.require_auth();
+        Snapshot::update(e, Some(from), None, amount);
+        emit_burn(e, from, amount);
+    }
+
+    /// Destroys `amount` of tokens from `from` using the allowance mechanism,
+    /// recording the balance of `from` and the total supply for the current
+    /// snapshot.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorized to burn the tokens.
+    /// * `from` - The account whose tokens are destroyed.
+    /// * `amount` - The amount of tokens to burn.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::spend_allowance`] errors.
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `spender` is required.
+    pub fn burn_from(e: &Env, spender: &Address, from: &Address, amount: i128) {
+        spender.require_auth();
+        Base::spend_allowance(e, from, spender, amount);
+        Snapshot::update(e, Some(from), None, amount);
+        emit_burn(e, from, amount);
+    }
+}
+
+// ################## INTERNAL HELPERS ##################
+
+/// Returns the recorded value for `snapshot_id`, or `None` if the value has
+/// not changed since that snapshot and the current value applies.
+fn lookup(e: &Env, history: &History, snapshot_id: u32) -> Option<i128> {
+    if snapshot_id == 0 || snapshot_id > Snapshot::current_snapshot_id(e) {
+        panic_with_error!(e, FungibleTokenError::InvalidSnapshotId);
+    }
+
+    let num = get_num_checkpoints(e, history);
+    if num == 0 || get_checkpoint(e, history, num - 1).snapshot_id < snapshot_id {
+        return None;
+    }
+
+    // Binary search for the first checkpoint with `snapshot_id` greater than
+    // or equal to the requested one.
+    let mut low: u32 = 0;
+    let mut high: u32 = num - 1;
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if get_checkpoint(e, history, mid).snapshot_id < snapshot_id {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    Some(get_checkpoint(e, history, low).value)
+}
+
+/// Records `current_value` for the current snapshot, unless a value was
+/// already recorded for it.
+fn update_history(e: &Env, history: &History, current_value: i128) {
+    let snapshot_id = Snapshot::current_snapshot_id(e);
+    if snapshot_id == 0 {
+        return;
+    }
+
+    let num = get_num_checkpoints(e, history);
+    if num > 0 && get_checkpoint(e, history, num - 1).snapshot_id == snapshot_id {
+        return;
+    }
+
+    let checkpoint = SnapshotCheckpoint { snapshot_id, value: current_value };
+    e.storage().persistent().set(&checkpoint_storage_key(history, num), &checkpoint);
+
+    match history {
+        History::TotalSupply => {
+            e.storage().instance().set(&SnapshotStorageKey::NumTotalSupplyCheckpoints, &(num + 1));
+        }
+        History::Account(account) => {
+            let key = SnapshotStorageKey::NumAccountCheckpoints(account.clone());
+            e.storage().persistent().set(&key, &(num + 1));
+        }
+    }
+}
+
+/// Returns the storage key for a checkpoint at the given index.
+fn checkpoint_storage_key(history: &History, index: u32) -> SnapshotStorageKey {
+    match history {
+        History::TotalSupply => SnapshotStorageKey::TotalSupplyCheckpoint(index),
+        History::Account(account) => SnapshotStorageKey::AccountCheckpoint(account.clone(), index),
+    }
+}
+
+/// Returns the checkpoint at `index`. The index must be lower than the number
+/// of checkpoints.
+fn get_checkpoint(e: &Env, history: &History, index: u32) -> SnapshotCheckpoint {
+    let key = checkpoint_storage_key(history, index);
+    let checkpoint = e
+        .storage()
+        .persistent()
+        .get::<_, SnapshotCheckpoint>(&key)
+        .expect("checkpoint to be present");
+    e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+    checkpoint
+}
+
+/// Returns the number of checkpoints for the given history.
+fn get_num_checkpoints(e: &Env, history: &History) -> u32 {
+    match history {
+        History::TotalSupply =>
+            e.storage().instance().get(&SnapshotStorageKey::NumTotalSupplyCheckpoints).unwrap_or(0),
+        History::Account(account) => {
+            let key = SnapshotStorageKey::NumAccountCheckpoints(account.clone());
+            if let Some(num) = e.storage().persistent().get::<_, u32>(&key) {
+                e.storage().persistent().extend_ttl(
+                    &key,
+                    BALANCE_TTL_THRESHOLD,
+                    BALANCE_EXTEND_AMOUNT,
+                );
+                num
+            } else {
+                0
+            }
+        }
+    }
+}
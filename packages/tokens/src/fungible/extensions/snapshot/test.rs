@@ -0,0 +1,185 @@
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, Address, Env, MuxedAddress};
+use stellar_event_assertion::EventAssertion;
+
+use crate::fungible::{extensions::snapshot::Snapshot, Base, ContractOverrides};
+
+#[contract]
+struct MockContract;
+
+fn setup_env() -> (Env, Address) {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_address = e.register(MockContract, ());
+    (e, contract_address)
+}
+
+#[test]
+fn snapshot_ids_increase() {
+    let (e, contract_address) = setup_env();
+
+    e.as_contract(&contract_address, || {
+        assert_eq!(Snapshot::current_snapshot_id(&e), 0);
+        assert_eq!(Snapshot::snapshot(&e), 1);
+        assert_eq!(Snapshot::snapshot(&e), 2);
+        assert_eq!(Snapshot::current_snapshot_id(&e), 2);
+
+        let event_assert = EventAssertion::new(&e, contract_address.clone());
+        event_assert.assert_event_count(2);
+    });
+}
+
+#[test]
+fn balance_of_at_untouched_account_returns_current_balance() {
+    let (e, contract_address) = setup_env();
+    let alice = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        Snapshot::mint(&e, &alice, 100);
+        let id = Snapshot::snapshot(&e);
+
+        assert_eq!(Snapshot::balance_of_at(&e, &alice, id), 100);
+        assert_eq!(Snapshot::total_supply_at(&e, id), 100);
+    });
+}
+
+#[test]
+fn transfer_records_previous_balances() {
+    let (e, contract_address) = setup_env();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    let first = e.as_contract(&contract_address, || {
+        Snapshot::mint(&e, &alice, 100);
+        Snapshot::snapshot(&e)
+    });
+    e.as_contract(&contract_address, || {
+        Snapshot::transfer(&e, &alice, &MuxedAddress::from(bob.clone()), 30);
+    });
+    e.as_contract(&contract_address, || {
+        Snapshot::transfer(&e, &alice, &MuxedAddress::from(bob.clone()), 20);
+    });
+
+    e.as_contract(&contract_address, || {
+        assert_eq!(Snapshot::balance_of_at(&e, &alice, first), 100);
+        assert_eq!(Snapshot::balance_of_at(&e, &bob, first), 0);
+        assert_eq!(Base::balance(&e, &alice), 50);
+        assert_eq!(Base::balance(&e, &bob), 50);
+        assert_eq!(Snapshot::total_supply_at(&e, first), 100);
+    });
+}
+
+#[test]
+fn multiple_snapshots_track_history() {
+    let (e, contract_address) = setup_env();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    let first = e.as_contract(&contract_address, || {
+        Snapshot::mint(&e, &alice, 100);
+        Snapshot::snapshot(&e)
+    });
+    let (second, third) = e.as_contract(&contract_address, || {
+        Snapshot::transfer(&e, &alice, &MuxedAddress::from(bob.clone()), 10);
+        // No activity between the second and third snapshots.
+        (Snapshot::snapshot(&e), Snapshot::snapshot(&e))
+    });
+    let fourth = e.as_contract(&contract_address, || {
+        Snapshot::transfer(&e, &alice, &MuxedAddress::from(bob.clone()), 40);
+        Snapshot::snapshot(&e)
+    });
+    e.as_contract(&contract_address, || {
+        Snapshot::burn(&e, &bob, 50);
+    });
+
+    e.as_contract(&contract_address, || {
+        assert_eq!(Snapshot::balance_of_at(&e, &alice, first), 100);
+        assert_eq!(Snapshot::balance_of_at(&e, &alice, second), 90);
+        assert_eq!(Snapshot::balance_of_at(&e, &alice, third), 90);
+        assert_eq!(Snapshot::balance_of_at(&e, &alice, fourth), 50);
+
+        assert_eq!(Snapshot::balance_of_at(&e, &bob, first), 0);
+        assert_eq!(Snapshot::balance_of_at(&e, &bob, second), 10);
+        assert_eq!(Snapshot::balance_of_at(&e, &bob, third), 10);
+        assert_eq!(Snapshot::balance_of_at(&e, &bob, fourth), 50);
+        assert_eq!(Base::balance(&e, &bob), 0);
+
+        assert_eq!(Snapshot::total_supply_at(&e, first), 100);
+        assert_eq!(Snapshot::total_supply_at(&e, fourth), 100);
+        assert_eq!(Base::total_supply(&e), 50);
+    });
+}
+
+#[test]
+fn mint_and_burn_record_total_supply() {
+    let (e, contract_address) = setup_env();
+    let alice = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        Snapshot::mint(&e, &alice, 100);
+        let first = Snapshot::snapshot(&e);
+
+        Snapshot::mint(&e, &alice, 100);
+        let second = Snapshot::snapshot(&e);
+
+        Base::approve(&e, &alice, &spender, 150, 1000);
+        Snapshot::burn_from(&e, &spender, &alice, 150);
+
+        assert_eq!(Snapshot::total_supply_at(&e, first), 100);
+        assert_eq!(Snapshot::total_supply_at(&e, second), 200);
+        assert_eq!(Base::total_supply(&e), 50);
+        assert_eq!(Snapshot::balance_of_at(&e, &alice, second), 200);
+    });
+}
+
+#[test]
+fn overrides_record_snapshots() {
+    let (e, contract_address) = setup_env();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    let id = e.as_contract(&contract_address, || {
+        Snapshot::mint(&e, &alice, 100);
+        Snapshot::snapshot(&e)
+    });
+    e.as_contract(&contract_address, || {
+        <Snapshot as ContractOverrides>::transfer(&e, &alice, &MuxedAddress::from(bob.clone()), 25);
+    });
+    e.as_contract(&contract_address, || {
+        Base::approve(&e, &alice, &spender, 25, 1000);
+        <Snapshot as ContractOverrides>::transfer_from(&e, &spender, &alice, &bob, 25);
+    });
+
+    e.as_contract(&contract_address, || {
+        assert_eq!(Snapshot::balance_of_at(&e, &alice, id), 100);
+        assert_eq!(Snapshot::balance_of_at(&e, &bob, id), 0);
+        assert_eq!(Base::balance(&e, &alice), 50);
+        assert_eq!(Base::balance(&e, &bob), 50);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #115)")]
+fn balance_of_at_zero_id_panics() {
+    let (e, contract_address) = setup_env();
+    let alice = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        Snapshot::snapshot(&e);
+        Snapshot::balance_of_at(&e, &alice, 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #115)")]
+fn total_supply_at_future_id_panics() {
+    let (e, contract_address) = setup_env();
+
+    e.as_contract(&contract_address, || {
+        Snapshot::snapshot(&e);
+        Snapshot::total_supply_at(&e, 2);
+    });
+}
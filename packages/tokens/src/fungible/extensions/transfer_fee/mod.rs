@@ -0,0 +1,201 @@
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contractevent, Address, Env};
+pub use storage::{TransferFee, TransferFeeConfig, TransferFeeStorageKey};
+
+use crate::fungible::FungibleToken;
+
+/// Transfer Fee Trait for Fungible Token
+///
+/// The `FungibleTransferFee` trait extends the `FungibleToken` trait to charge
+/// a configurable fee on every transfer. The fee is expressed in basis points
+/// of the transferred amount, optionally capped to a maximum amount per
+/// transfer, and is sent to a treasury address. Accounts can be exempted from
+/// the fee, in which case any transfer from or to them is free of charge.
+///
+/// The sender is always debited the full (gross) `amount`, while the
+/// recipient receives the amount net of fees. In order to keep balance
+/// tracking accurate for indexers, two `transfer` events are emitted for a
+/// taxed transfer: one from the sender to the recipient with the net amount,
+/// and one from the sender to the treasury with the fee. Additionally, a
+/// `transfer_fee_charged` event reports the gross, net and fee amounts.
+///
+/// Minting and burning are never charged.
+///
+/// This trait is designed to be used in conjunction with the `FungibleToken`
+/// trait, with [`TransferFee`] as its `ContractType`.
+///
+/// **NOTE**
+///
+/// All setter functions, exposed in the `FungibleTransferFee` trait, include
+/// an additional parameter `operator: Address`. This account is the one
+/// authorizing the invocation. Having it as a parameter grants the flexibility
+/// to introduce simple or complex role-based access controls.
+///
+/// However, this parameter is omitted from the module functions, defined in
+/// "storage.rs", because the authorizations are to be handled in the access
+/// control helpers or directly implemented.
+///
+/// There is no default implementation for this trait on purpose.
+///
+/// Because, there are no default implementation to enforce how the
+/// authorization should be configured. Not providing a default implementation
+/// for this trait is a reminder for the implementor to provide the
+/// authorization logic for this trait.
+pub trait FungibleTransferFee: FungibleToken<ContractType = TransferFee> {
+    /// Returns the current transfer fee configuration, or `None` if no fee is
+    /// configured.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn transfer_fee_config(e: &Env) -> Option<TransferFeeConfig>;
+
+    /// Returns the fee that would be charged for transferring `amount` from
+    /// `from` to `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the tokens.
+    /// * `amount` - The gross amount of tokens to be transferred.
+    fn transfer_fee(e: &Env, from: Address, to: Address, amount: i128) -> i128;
+
+    /// Sets the transfer fee configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `config` - The new transfer fee configuration.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::fungible::FungibleTokenError::InvalidTransferFee`] - When the
+    ///   basis points exceed [`MAX_TRANSFER_FEE_BASIS_POINTS`] or `max_fee` is
+    ///   negative.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer_fee_config_set", treasury: Address]`
+    /// * data - `[basis_points: u32, max_fee: Option<i128>]`
+    fn set_transfer_fee_config(e: &Env, config: TransferFeeConfig, operator: Address);
+
+    /// Returns whether `account` is exempt from transfer fees.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address to check the exemption for.
+    fn is_fee_exempt(e: &Env, account: Address) -> bool;
+
+    /// Exempts `account` from transfer fees, or removes the exemption.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address to update.
+    /// * `exempt` - Whether the account is exempt from transfer fees.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["fee_exemption_set", account: Address]`
+    /// * data - `[exempt: bool]`
+    fn set_fee_exempt(e: &Env, account: Address, exempt: bool, operator: Address);
+}
+
+// ################## CONSTANTS ##################
+
+/// Denominator for the basis points used in fee calculations (100%).
+pub const BASIS_POINTS_DENOMINATOR: u32 = 10_000;
+
+/// Upper bound for the transfer fee rate, so that the fee can never exceed
+/// 10% of the transferred amount.
+pub const MAX_TRANSFER_FEE_BASIS_POINTS: u32 = 1_000;
+
+// ################## EVENTS ##################
+
+/// Event emitted when a transfer fee is charged.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferFeeCharged {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub gross_amount: i128,
+    pub net_amount: i128,
+    pub fee: i128,
+}
+
+/// Emits an event indicating a transfer fee was charged.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `to` - The address receiving the net amount.
+/// * `gross_amount` - The amount debited from `from`.
+/// * `net_amount` - The amount credited to `to`.
+/// * `fee` - The amount credited to the treasury.
+pub fn emit_transfer_fee_charged(
+    e: &Env,
+    from: &Address,
+    to: &Address,
+    gross_amount: i128,
+    net_amount: i128,
+    fee: i128,
+) {
+    TransferFeeCharged { from: from.clone(), to: to.clone(), gross_amount, net_amount, fee }
+        .publish(e);
+}
+
+/// Event emitted when the transfer fee configuration is set.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferFeeConfigSet {
+    #[topic]
+    pub treasury: Address,
+    pub basis_points: u32,
+    pub max_fee: Option<i128>,
+}
+
+/// Emits an event indicating the transfer fee configuration was set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `config` - The new transfer fee configuration.
+pub fn emit_transfer_fee_config_set(e: &Env, config: &TransferFeeConfig) {
+    TransferFeeConfigSet {
+        treasury: config.treasury.clone(),
+        basis_points: config.basis_points,
+        max_fee: config.max_fee,
+    }
+    .publish(e);
+}
+
+/// Event emitted when the fee exemption of an account is updated.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeExemptionSet {
+    #[topic]
+    pub account: Address,
+    pub exempt: bool,
+}
+
+/// Emits an event indicating the fee exemption of an account was updated.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The updated address.
+/// * `exempt` - Whether the account is exempt from transfer fees.
+pub fn emit_fee_exemption_set(e: &Env, account: &Address, exempt: bool) {
+    FeeExemptionSet { account: account.clone(), exempt }.publish(e);
+}
@@ -0,0 +1,308 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, MuxedAddress};
+use stellar_contract_utils::math::{mul_div_i128, Rounding};
+
+use crate::fungible::{
+    emit_transfer,
+    extensions::transfer_fee::{
+        emit_fee_exemption_set, emit_transfer_fee_charged, emit_transfer_fee_config_set,
+        BASIS_POINTS_DENOMINATOR, MAX_TRANSFER_FEE_BASIS_POINTS,
+    },
+    Base, ContractOverrides, FungibleTokenError, ALLOW_BLOCK_EXTEND_AMOUNT,
+    ALLOW_BLOCK_TTL_THRESHOLD,
+};
+
+pub struct TransferFee;
+
+impl ContractOverrides for TransferFee {
+    fn transfer(e: &Env, from: &Address, to: &MuxedAddress, amount: i128) {
+        TransferFee::transfer(e, from, to, amount);
+    }
+
+    fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, amount: i128) {
+        TransferFee::transfer_from(e, spender, from, to, amount);
+    }
+}
+
+/// Configuration of the transfer fee.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferFeeConfig {
+    /// The address receiving the fees.
+    pub treasury: Address,
+    /// The fee rate in basis points of the transferred amount (100 = 1%).
+    pub basis_points: u32,
+    /// The maximum fee charged for a single transfer, if any.
+    pub max_fee: Option<i128>,
+}
+
+/// Storage keys for the data associated with the transfer fee extension
+#[contracttype]
+pub enum TransferFeeStorageKey {
+    /// Stores the [`TransferFeeConfig`]
+    Config,
+    /// Stores the fee exemption of an account
+    Exempt(Address),
+}
+
+impl TransferFee {
+    // ################## QUERY STATE ##################
+
+    /// Returns the current transfer fee configuration, or `None` if no fee is
+    /// configured.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn config(e: &Env) -> Option<TransferFeeConfig> {
+        e.storage().instance().get(&TransferFeeStorageKey::Config)
+    }
+
+    /// Returns whether `account` is exempt from transfer fees.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address to check the exemption for.
+    pub fn is_fee_exempt(e: &Env, account: &Address) -> bool {
+        let key = TransferFeeStorageKey::Exempt(account.clone());
+        if e.storage().persistent().has(&key) {
+            e.storage().persistent().extend_ttl(
+                &key,
+                ALLOW_BLOCK_TTL_THRESHOLD,
+                ALLOW_BLOCK_EXTEND_AMOUNT,
+            );
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns the fee charged for transferring `amount` from `from` to `to`.
+    /// The fee is rounded down and is `0` when no fee is configured, or when
+    /// either `from` or `to` is exempt.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the tokens.
+    /// * `amount` - The gross amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::LessThanZero`] - When `amount < 0`.
+    pub fn transfer_fee(e: &Env, from: &Address, to: &Address, amount: i128) -> i128 {
+        if amount < 0 {
+            panic_with_error!(e, FungibleTokenError::LessThanZero);
+        }
+
+        let Some(config) = TransferFee::config(e) else {
+            return 0;
+        };
+        if config.basis_points == 0
+            || TransferFee::is_fee_exempt(e, from)
+            || TransferFee::is_fee_exempt(e, to)
+        {
+            return 0;
+        }
+
+        let fee = mul_div_i128(
+            e,
+            amount,
+            config.basis_points as i128,
+            BASIS_POINTS_DENOMINATOR as i128,
+            Rounding::Floor,
+        );
+        match config.max_fee {
+            Some(max_fee) => fee.min(max_fee),
+            None => fee,
+        }
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Sets the transfer fee configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `config` - The new transfer fee configuration.
+    ///
+    /// # Errors
+    ///
+    /// * [`FungibleTokenError::InvalidTransferFee`] - When the basis points
+    ///   exceed [`MAX_TRANSFER_FEE_BASIS_POINTS`] or `max_fee` is negative.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer_fee_config_set", treasury: Address]`
+    /// * data - `[basis_points: u32, max_fee: Option<i128>]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used:
+    /// - During contract initialization/construction
+    /// - In admin functions that implement their own authorization logic
+    pub fn set_config(e: &Env, config: &TransferFeeConfig) {
+        if config.basis_points > MAX_TRANSFER_FEE_BASIS_POINTS
+            || config.max_fee.is_some_and(|max_fee| max_fee < 0)
+        {
+            panic_with_error!(e, FungibleTokenError::InvalidTransferFee);
+        }
+
+        e.storage().instance().set(&TransferFeeStorageKey::Config, config);
+        emit_transfer_fee_config_set(e, config);
+    }
+
+    /// Exempts `account` from transfer fees, or removes the exemption.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address to update.
+    /// * `exempt` - Whether the account is exempt from transfer fees.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["fee_exemption_set", account: Address]`
+    /// * data - `[exempt: bool]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used:
+    /// - During contract initialization/construction
+    /// - In admin functions that implement their own authorization logic
+    pub fn set_fee_exempt(e: &Env, account: &Address, exempt: bool) {
+        let key = TransferFeeStorageKey::Exempt(account.clone());
+
+        if exempt == e.storage().persistent().has(&key) {
+            return;
+        }
+
+        if exempt {
+            e.storage().persistent().set(&key, &());
+        } else {
+            e.storage().persistent().remove(&key);
+        }
+        emit_fee_exemption_set(e, account, exempt);
+    }
+
+    /// Moves `amount` of tokens from `from`, crediting `to` with the amount
+    /// net of fees and the treasury with the fee. Returns the net amount.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the net amount.
+    /// * `to_muxed_id` - Optional muxed ID to be emitted in the event data.
+    /// * `amount` - The gross amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`TransferFee::transfer_fee`] errors.
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[to_muxed_id: Option<u64>, amount: i128]`
+    ///
+    /// If a fee is charged, additionally:
+    ///
+    /// * topics - `["transfer", from: Address, treasury: Address]`
+    /// * data - `[to_muxed_id: Option<u64>, fee: i128]`
+    ///
+    /// * topics - `["transfer_fee_charged", from: Address, to: Address]`
+    /// * data - `[gross_amount: i128, net_amount: i128, fee: i128]`
+    ///
+    /// # Notes
+    ///
+    /// This function does not enforce authorization. Ensure that authorization
+    /// is handled at a higher level.
+    pub fn update(
+        e: &Env,
+        from: &Address,
+        to: &Address,
+        to_muxed_id: Option<u64>,
+        amount: i128,
+    ) -> i128 {
+        let fee = TransferFee::transfer_fee(e, from, to, amount);
+        // NOTE: can't underflow, because `fee <= amount`.
+        let net_amount = amount - fee;
+
+        Base::update(e, Some(from), Some(to), net_amount);
+        emit_transfer(e, from, to, to_muxed_id, net_amount);
+
+        if fee > 0 {
+            // NOTE: `fee > 0` implies the configuration is set.
+            let treasury = TransferFee::config(e).expect("config to be set").treasury;
+            Base::update(e, Some(from), Some(&treasury), fee);
+            emit_transfer(e, from, &treasury, None, fee);
+            emit_transfer_fee_charged(e, from, to, amount, net_amount, fee);
+        }
+
+        net_amount
+    }
+
+    // ################## OVERRIDDEN FUNCTIONS ##################
+
+    /// Transfers `amount` of tokens from `from`, crediting `to` with the
+    /// amount net of fees and the treasury with the fee.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `amount` - The gross amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`TransferFee::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * refer to [`TransferFee::update`] events.
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn transfer(e: &Env, from: &Address, to: &MuxedAddress, amount: i128) {
+        from.require_auth();
+        TransferFee::update(e, from, &to.address(), to.id(), amount);
+    }
+
+    /// Transfers `amount` of tokens from `from` using the allowance mechanism,
+    /// crediting `to` with the amount net of fees and the treasury with the
+    /// fee. The gross `amount` is deducted from `spender`s allowance.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `spender` - The address authorizing the transfer, and having its
+    ///   allowance consumed during the transfer.
+    /// * `from` - The address holding the tokens which will be transferred.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `amount` - The gross amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::spend_allowance`] errors.
+    /// * refer to [`TransferFee::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * refer to [`TransferFee::update`] events.
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `spender` is required.
+    pub fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, amount: i128) {
+        spender.require_auth();
+        Base::spend_allowance(e, from, spender, amount);
+        TransferFee::update(e, from, to, None, amount);
+    }
+}
@@ -0,0 +1,227 @@
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, Address, Env, MuxedAddress};
+use stellar_event_assertion::EventAssertion;
+
+use crate::fungible::{
+    extensions::transfer_fee::{TransferFee, TransferFeeConfig},
+    Base,
+};
+
+#[contract]
+struct MockContract;
+
+fn setup_env() -> (Env, Address, Address) {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_address = e.register(MockContract, ());
+    let treasury = Address::generate(&e);
+    (e, contract_address, treasury)
+}
+
+fn config(treasury: &Address, basis_points: u32, max_fee: Option<i128>) -> TransferFeeConfig {
+    TransferFeeConfig { treasury: treasury.clone(), basis_points, max_fee }
+}
+
+#[test]
+fn transfer_charges_fee() {
+    let (e, contract_address, treasury) = setup_env();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        TransferFee::set_config(&e, &config(&treasury, 250, None));
+        Base::mint(&e, &alice, 1000);
+    });
+    e.as_contract(&contract_address, || {
+        TransferFee::transfer(&e, &alice, &MuxedAddress::from(bob.clone()), 400);
+
+        assert_eq!(Base::balance(&e, &alice), 600);
+        assert_eq!(Base::balance(&e, &bob), 390);
+        assert_eq!(Base::balance(&e, &treasury), 10);
+        assert_eq!(Base::total_supply(&e), 1000);
+
+        let mut event_assert = EventAssertion::new(&e, contract_address.clone());
+        event_assert.assert_event_count(3);
+        event_assert.assert_fungible_transfer(&alice, &bob, None, 390);
+        event_assert.assert_fungible_transfer(&alice, &treasury, None, 10);
+    });
+}
+
+#[test]
+fn transfer_fee_is_rounded_down() {
+    let (e, contract_address, treasury) = setup_env();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        TransferFee::set_config(&e, &config(&treasury, 100, None));
+
+        assert_eq!(TransferFee::transfer_fee(&e, &alice, &bob, 99), 0);
+        assert_eq!(TransferFee::transfer_fee(&e, &alice, &bob, 199), 1);
+        assert_eq!(TransferFee::transfer_fee(&e, &alice, &bob, 0), 0);
+    });
+}
+
+#[test]
+fn transfer_fee_is_capped() {
+    let (e, contract_address, treasury) = setup_env();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        TransferFee::set_config(&e, &config(&treasury, 1000, Some(5)));
+        Base::mint(&e, &alice, 1000);
+    });
+    e.as_contract(&contract_address, || {
+        assert_eq!(TransferFee::transfer_fee(&e, &alice, &bob, 30), 3);
+        assert_eq!(TransferFee::transfer_fee(&e, &alice, &bob, 1000), 5);
+
+        TransferFee::transfer(&e, &alice, &MuxedAddress::from(bob.clone()), 1000);
+        assert_eq!(Base::balance(&e, &bob), 995);
+        assert_eq!(Base::balance(&e, &treasury), 5);
+    });
+}
+
+#[test]
+fn transfer_without_config_is_free() {
+    let (e, contract_address, treasury) = setup_env();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        Base::mint(&e, &alice, 100);
+    });
+    e.as_contract(&contract_address, || {
+        assert_eq!(TransferFee::config(&e), None);
+
+        TransferFee::transfer(&e, &alice, &MuxedAddress::from(bob.clone()), 100);
+        assert_eq!(Base::balance(&e, &bob), 100);
+        assert_eq!(Base::balance(&e, &treasury), 0);
+
+        let mut event_assert = EventAssertion::new(&e, contract_address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_fungible_transfer(&alice, &bob, None, 100);
+    });
+}
+
+#[test]
+fn transfer_with_exempt_account_is_free() {
+    let (e, contract_address, treasury) = setup_env();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        TransferFee::set_config(&e, &config(&treasury, 500, None));
+        TransferFee::set_fee_exempt(&e, &bob, true);
+        Base::mint(&e, &alice, 200);
+    });
+    e.as_contract(&contract_address, || {
+        assert!(TransferFee::is_fee_exempt(&e, &bob));
+        assert!(!TransferFee::is_fee_exempt(&e, &alice));
+
+        // Exempt as recipient.
+        TransferFee::transfer(&e, &alice, &MuxedAddress::from(bob.clone()), 100);
+        assert_eq!(Base::balance(&e, &bob), 100);
+    });
+    e.as_contract(&contract_address, || {
+        // Exempt as sender.
+        TransferFee::transfer(&e, &bob, &MuxedAddress::from(alice.clone()), 100);
+        assert_eq!(Base::balance(&e, &alice), 200);
+        assert_eq!(Base::balance(&e, &treasury), 0);
+    });
+    e.as_contract(&contract_address, || {
+        TransferFee::set_fee_exempt(&e, &bob, false);
+        assert!(!TransferFee::is_fee_exempt(&e, &bob));
+        assert_eq!(TransferFee::transfer_fee(&e, &alice, &bob, 100), 5);
+    });
+}
+
+#[test]
+fn transfer_from_charges_fee_and_spends_gross_allowance() {
+    let (e, contract_address, treasury) = setup_env();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        TransferFee::set_config(&e, &config(&treasury, 1000, None));
+        Base::mint(&e, &alice, 1000);
+        Base::approve(&e, &alice, &spender, 500, 1000);
+    });
+    e.as_contract(&contract_address, || {
+        TransferFee::transfer_from(&e, &spender, &alice, &bob, 300);
+
+        assert_eq!(Base::balance(&e, &alice), 700);
+        assert_eq!(Base::balance(&e, &bob), 270);
+        assert_eq!(Base::balance(&e, &treasury), 30);
+        assert_eq!(Base::allowance(&e, &alice, &spender), 200);
+    });
+}
+
+#[test]
+fn mint_and_burn_are_not_charged() {
+    let (e, contract_address, treasury) = setup_env();
+    let alice = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        TransferFee::set_config(&e, &config(&treasury, 1000, None));
+        Base::mint(&e, &alice, 100);
+        Base::burn(&e, &alice, 40);
+
+        assert_eq!(Base::balance(&e, &alice), 60);
+        assert_eq!(Base::balance(&e, &treasury), 0);
+    });
+}
+
+#[test]
+fn set_fee_exempt_emits_event_only_on_change() {
+    let (e, contract_address, _) = setup_env();
+    let alice = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        TransferFee::set_fee_exempt(&e, &alice, true);
+        TransferFee::set_fee_exempt(&e, &alice, true);
+        TransferFee::set_fee_exempt(&e, &alice, false);
+        TransferFee::set_fee_exempt(&e, &alice, false);
+
+        let event_assert = EventAssertion::new(&e, contract_address.clone());
+        event_assert.assert_event_count(2);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #118)")]
+fn set_config_above_max_basis_points_panics() {
+    let (e, contract_address, treasury) = setup_env();
+
+    e.as_contract(&contract_address, || {
+        TransferFee::set_config(&e, &config(&treasury, 1001, None));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #118)")]
+fn set_config_negative_max_fee_panics() {
+    let (e, contract_address, treasury) = setup_env();
+
+    e.as_contract(&contract_address, || {
+        TransferFee::set_config(&e, &config(&treasury, 100, Some(-1)));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #100)")]
+fn transfer_insufficient_balance_panics() {
+    let (e, contract_address, treasury) = setup_env();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        TransferFee::set_config(&e, &config(&treasury, 100, None));
+        Base::mint(&e, &alice, 100);
+    });
+    e.as_contract(&contract_address, || {
+        TransferFee::transfer(&e, &alice, &MuxedAddress::from(bob.clone()), 101);
+    });
+}
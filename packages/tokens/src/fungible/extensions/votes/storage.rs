@@ -1,7 +1,7 @@
 use soroban_sdk::{Address, Env, MuxedAddress};
 use stellar_governance::votes::transfer_voting_units;
 
-use crate::fungible::{Base, ContractOverrides};
+use crate::fungible::{overrides::WrapperOverrides, Base, ContractOverrides};
 
 pub struct FungibleVotes;
 
@@ -15,6 +15,16 @@ impl ContractOverrides for FungibleVotes {
     }
 }
 
+impl WrapperOverrides for FungibleVotes {
+    fn mint(e: &Env, to: &Address, amount: i128) {
+        FungibleVotes::mint(e, to, amount);
+    }
+
+    fn burn(e: &Env, from: &Address, amount: i128) {
+        FungibleVotes::burn(e, from, amount);
+    }
+}
+
 impl FungibleVotes {
     /// Transfers `amount` of tokens from `from` to `to`.
     /// Also updates voting units for the respective delegates.
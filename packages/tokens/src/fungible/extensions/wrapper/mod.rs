@@ -0,0 +1,138 @@
+mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contracttrait, Address, Env};
+pub use storage::{
+    deposit_for, recover, set_underlying, underlying, withdraw_to, WrapperStorageKey,
+};
+
+use crate::fungible::{overrides::WrapperOverrides, FungibleToken};
+
+/// Wrapper Trait for Fungible Token
+///
+/// The `FungibleWrapper` trait extends the `FungibleToken` trait to issue
+/// wrapped tokens 1:1 against an underlying SEP-41 token, such as a Stellar
+/// Asset Contract (SAC). Users deposit the underlying token and receive the
+/// same amount of wrapped tokens, which can later be burned to withdraw the
+/// underlying token.
+///
+/// The main use case is adding functionality to an existing asset without
+/// migrating it. For example, combining this extension with
+/// [`crate::fungible::votes::FungibleVotes`] as the `ContractType` turns a
+/// plain SAC asset into a governance-capable token: wrapped balances are
+/// checkpointed and can be delegated.
+///
+/// Minting and burning are dispatched through the
+/// [`crate::fungible::WrapperOverrides`] implementation of the
+/// `ContractType`, so the bookkeeping of the chosen contract type (e.g. voting
+/// units, snapshots) stays consistent.
+///
+/// The underlying token must be set with [`set_underlying`], most likely in
+/// the constructor of the contract.
+///
+/// **NOTE**
+///
+/// [`FungibleWrapper::recover`] includes an additional parameter `operator:
+/// Address` and has no default implementation, because it mints tokens and the
+/// authorization logic is left to the implementor.
+///
+/// ```ignore
+/// #[contractimpl(contracttrait)]
+/// impl FungibleWrapper for ExampleContract {
+///     fn recover(e: &Env, account: Address, operator: Address) -> i128 {
+///         // authorization logic for `operator`, e.g. an admin check
+///         operator.require_auth();
+///         wrapper::recover::<Self::ContractType>(e, &account)
+///     }
+/// }
+/// ```
+#[contracttrait]
+pub trait FungibleWrapper: FungibleToken<ContractType: WrapperOverrides> {
+    /// Returns the address of the underlying token.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::fungible::FungibleTokenError::UnderlyingNotSet`] - When the
+    ///   underlying token address has not been set.
+    fn underlying(e: &Env) -> Address {
+        underlying(e)
+    }
+
+    /// Transfers `amount` of the underlying token from `from` to this
+    /// contract and mints the same amount of wrapped tokens to `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address providing the underlying tokens.
+    /// * `account` - The address receiving the wrapped tokens.
+    /// * `amount` - The amount of tokens to wrap.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`deposit_for`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", account: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    fn deposit_for(e: &Env, from: Address, account: Address, amount: i128) {
+        deposit_for::<Self::ContractType>(e, &from, &account, amount);
+    }
+
+    /// Burns `amount` of wrapped tokens from `from` and transfers the same
+    /// amount of the underlying token to `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address whose wrapped tokens are burned.
+    /// * `account` - The address receiving the underlying tokens.
+    /// * `amount` - The amount of tokens to unwrap.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`withdraw_to`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    fn withdraw_to(e: &Env, from: Address, account: Address, amount: i128) {
+        withdraw_to::<Self::ContractType>(e, &from, &account, amount);
+    }
+
+    /// Mints wrapped tokens to `account` for the underlying tokens that were
+    /// sent directly to this contract without going through
+    /// [`FungibleWrapper::deposit_for`]. Returns the amount minted.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address receiving the recovered wrapped tokens.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`recover`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", account: Address]`
+    /// * data - `[amount: i128]`
+    fn recover(e: &Env, account: Address, operator: Address) -> i128;
+}
@@ -0,0 +1,162 @@
+use soroban_sdk::{contracttype, panic_with_error, token::TokenClient, Address, Env};
+
+use crate::fungible::{overrides::WrapperOverrides, Base, FungibleTokenError};
+
+/// Storage keys for the data associated with the wrapper extension
+#[contracttype]
+pub enum WrapperStorageKey {
+    /// Address of the underlying token
+    Underlying,
+}
+
+// ################## QUERY STATE ##################
+
+/// Returns the address of the underlying token.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::UnderlyingNotSet`] - When the underlying token
+///   address has not been set.
+pub fn underlying(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&WrapperStorageKey::Underlying)
+        .unwrap_or_else(|| panic_with_error!(e, FungibleTokenError::UnderlyingNotSet))
+}
+
+// ################## CHANGE STATE ##################
+
+/// Sets the address of the underlying token.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `token` - The address of the underlying SEP-41 token.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::InvalidUnderlying`] - When `token` is the current
+///   contract.
+///
+/// # Notes
+///
+/// **IMPORTANT**: This function lacks authorization controls. You want to
+/// invoke it most likely from a constructor. Changing the underlying token
+/// once wrapped tokens were issued breaks the 1:1 backing.
+pub fn set_underlying(e: &Env, token: &Address) {
+    if *token == e.current_contract_address() {
+        panic_with_error!(e, FungibleTokenError::InvalidUnderlying);
+    }
+    e.storage().instance().set(&WrapperStorageKey::Underlying, token);
+}
+
+/// Transfers `amount` of the underlying token from `from` to this contract and
+/// mints the same amount of wrapped tokens to `account`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `from` - The address providing the underlying tokens.
+/// * `account` - The address receiving the wrapped tokens.
+/// * `amount` - The amount of tokens to wrap.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::LessThanZero`] - When `amount < 0`.
+/// * refer to [`underlying`] errors.
+/// * refer to [`WrapperOverrides::mint`] errors.
+///
+/// # Events
+///
+/// * topics - `["mint", account: Address]`
+/// * data - `[amount: i128]`
+///
+/// # Notes
+///
+/// Authorization for `from` is required.
+pub fn deposit_for<T: WrapperOverrides>(e: &Env, from: &Address, account: &Address, amount: i128) {
+    from.require_auth();
+    if amount < 0 {
+        panic_with_error!(e, FungibleTokenError::LessThanZero);
+    }
+
+    TokenClient::new(e, &underlying(e)).transfer(from, e.current_contract_address(), &amount);
+    T::mint(e, account, amount);
+}
+
+/// Burns `amount` of wrapped tokens from `from` and transfers the same amount
+/// of the underlying token to `account`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `from` - The address whose wrapped tokens are burned.
+/// * `account` - The address receiving the underlying tokens.
+/// * `amount` - The amount of tokens to unwrap.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::InvalidUnderlying`] - When `account` is the current
+///   contract.
+/// * refer to [`underlying`] errors.
+/// * refer to [`WrapperOverrides::burn`] errors.
+///
+/// # Events
+///
+/// * topics - `["burn", from: Address]`
+/// * data - `[amount: i128]`
+///
+/// # Notes
+///
+/// Authorization for `from` is required.
+pub fn withdraw_to<T: WrapperOverrides>(e: &Env, from: &Address, account: &Address, amount: i128) {
+    let contract = e.current_contract_address();
+    // Sending the underlying tokens to the contract itself would leave them as
+    // unbacked surplus.
+    if *account == contract {
+        panic_with_error!(e, FungibleTokenError::InvalidUnderlying);
+    }
+
+    T::burn(e, from, amount);
+    TokenClient::new(e, &underlying(e)).transfer(&contract, account, &amount);
+}
+
+/// Mints wrapped tokens to `account` for the surplus of underlying tokens held
+/// by this contract, i.e. tokens that were sent directly without going
+/// through [`deposit_for`]. Returns the amount minted.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `account` - The address receiving the recovered wrapped tokens.
+///
+/// # Errors
+///
+/// * refer to [`underlying`] errors.
+/// * refer to [`WrapperOverrides::mint`] errors.
+///
+/// # Events
+///
+/// * topics - `["mint", account: Address]`
+/// * data - `[amount: i128]`
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate
+/// access controls to ensure that only authorized accounts can recover the
+/// surplus.
+pub fn recover<T: WrapperOverrides>(e: &Env, account: &Address) -> i128 {
+    let balance = TokenClient::new(e, &underlying(e)).balance(&e.current_contract_address());
+    let surplus = balance - Base::total_supply(e);
+    if surplus <= 0 {
+        return 0;
+    }
+    T::mint(e, account, surplus);
+    surplus
+}
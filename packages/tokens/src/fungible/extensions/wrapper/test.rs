@@ -0,0 +1,187 @@
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, StellarAssetContract},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env,
+};
+use stellar_event_assertion::EventAssertion;
+use stellar_governance::votes::{delegate, get_votes};
+
+use crate::fungible::{
+    extensions::wrapper::{deposit_for, recover, set_underlying, underlying, withdraw_to},
+    votes::FungibleVotes,
+    Base,
+};
+
+#[contract]
+struct MockContract;
+
+fn setup_env() -> (Env, Address, Address) {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_address = e.register(MockContract, ());
+    let issuer = Address::generate(&e);
+    let sac: StellarAssetContract = e.register_stellar_asset_contract_v2(issuer);
+    let asset = sac.address();
+    e.as_contract(&contract_address, || set_underlying(&e, &asset));
+    (e, contract_address, asset)
+}
+
+#[test]
+fn deposit_for_mints_wrapped_tokens() {
+    let (e, contract_address, asset) = setup_env();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    StellarAssetClient::new(&e, &asset).mint(&alice, &100);
+
+    e.as_contract(&contract_address, || {
+        deposit_for::<Base>(&e, &alice, &bob, 60);
+
+        assert_eq!(Base::balance(&e, &bob), 60);
+        assert_eq!(Base::total_supply(&e), 60);
+        assert_eq!(underlying(&e), asset);
+
+        let mut event_assert = EventAssertion::new(&e, contract_address.clone());
+        event_assert.assert_fungible_mint(&bob, 60);
+    });
+
+    let asset_client = TokenClient::new(&e, &asset);
+    assert_eq!(asset_client.balance(&alice), 40);
+    assert_eq!(asset_client.balance(&contract_address), 60);
+}
+
+#[test]
+fn withdraw_to_burns_wrapped_tokens() {
+    let (e, contract_address, asset) = setup_env();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    StellarAssetClient::new(&e, &asset).mint(&alice, &100);
+
+    e.as_contract(&contract_address, || {
+        deposit_for::<Base>(&e, &alice, &alice, 100);
+    });
+    e.as_contract(&contract_address, || {
+        withdraw_to::<Base>(&e, &alice, &bob, 30);
+
+        assert_eq!(Base::balance(&e, &alice), 70);
+        assert_eq!(Base::total_supply(&e), 70);
+
+        let mut event_assert = EventAssertion::new(&e, contract_address.clone());
+        event_assert.assert_fungible_burn(&alice, 30);
+    });
+
+    let asset_client = TokenClient::new(&e, &asset);
+    assert_eq!(asset_client.balance(&bob), 30);
+    assert_eq!(asset_client.balance(&contract_address), 70);
+}
+
+#[test]
+fn recover_mints_surplus() {
+    let (e, contract_address, asset) = setup_env();
+    let alice = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    let asset_admin = StellarAssetClient::new(&e, &asset);
+    asset_admin.mint(&alice, &100);
+
+    e.as_contract(&contract_address, || {
+        deposit_for::<Base>(&e, &alice, &alice, 50);
+    });
+    // Underlying tokens sent directly to the wrapper are not backed by
+    // wrapped tokens.
+    TokenClient::new(&e, &asset).transfer(&alice, &contract_address, &20);
+
+    e.as_contract(&contract_address, || {
+        assert_eq!(recover::<Base>(&e, &treasury), 20);
+        assert_eq!(Base::balance(&e, &treasury), 20);
+        assert_eq!(Base::total_supply(&e), 70);
+
+        // Nothing left to recover.
+        assert_eq!(recover::<Base>(&e, &treasury), 0);
+    });
+}
+
+#[test]
+fn wrapper_with_votes_tracks_voting_power() {
+    let (e, contract_address, asset) = setup_env();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    StellarAssetClient::new(&e, &asset).mint(&alice, &100);
+
+    e.as_contract(&contract_address, || {
+        delegate(&e, &alice, &bob);
+    });
+    e.as_contract(&contract_address, || {
+        deposit_for::<FungibleVotes>(&e, &alice, &alice, 100);
+        assert_eq!(get_votes(&e, &bob), 100);
+    });
+    e.as_contract(&contract_address, || {
+        withdraw_to::<FungibleVotes>(&e, &alice, &alice, 40);
+        assert_eq!(get_votes(&e, &bob), 60);
+        assert_eq!(Base::balance(&e, &alice), 60);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #100)")]
+fn withdraw_more_than_balance_panics() {
+    let (e, contract_address, asset) = setup_env();
+    let alice = Address::generate(&e);
+    StellarAssetClient::new(&e, &asset).mint(&alice, &100);
+
+    e.as_contract(&contract_address, || {
+        deposit_for::<Base>(&e, &alice, &alice, 10);
+    });
+    e.as_contract(&contract_address, || {
+        withdraw_to::<Base>(&e, &alice, &alice, 11);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #103)")]
+fn deposit_negative_amount_panics() {
+    let (e, contract_address, _) = setup_env();
+    let alice = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        deposit_for::<Base>(&e, &alice, &alice, -1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #117)")]
+fn withdraw_to_self_panics() {
+    let (e, contract_address, asset) = setup_env();
+    let alice = Address::generate(&e);
+    StellarAssetClient::new(&e, &asset).mint(&alice, &100);
+
+    e.as_contract(&contract_address, || {
+        deposit_for::<Base>(&e, &alice, &alice, 10);
+    });
+    e.as_contract(&contract_address, || {
+        withdraw_to::<Base>(&e, &alice, &contract_address, 10);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #117)")]
+fn set_underlying_to_self_panics() {
+    let e = Env::default();
+    let contract_address = e.register(MockContract, ());
+
+    e.as_contract(&contract_address, || {
+        set_underlying(&e, &contract_address);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #116)")]
+fn underlying_not_set_panics() {
+    let e = Env::default();
+    let contract_address = e.register(MockContract, ());
+
+    e.as_contract(&contract_address, || {
+        underlying(&e);
+    });
+}
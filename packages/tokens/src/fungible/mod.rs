@@ -38,6 +38,12 @@
 //! - Burnable: Enables token holders to destroy their tokens, reducing the
 //!   total supply.
 //! - Capped: Enables the contract to set a maximum limit on the total supply.
+//! - Rebasing: Stores balances as shares of a supply that can be rebased by
+//!   updating a global index.
+//! - Snapshot: Records balances and total supply at specific snapshot ids for
+//!   later queries.
+//! - Transfer Fee: Charges a fee on transfers, sent to a treasury address.
+//! - Wrapper: Issues tokens 1:1 against deposits of an underlying SEP-41 token.
 //!
 //! ## Compatibility and Compliance
 //!
@@ -75,8 +81,10 @@ mod utils;
 #[cfg(test)]
 mod test;
 
-pub use extensions::{allowlist, blocklist, burnable, capped, votes};
-pub use overrides::{Base, ContractOverrides};
+pub use extensions::{
+    allowlist, blocklist, burnable, capped, rebasing, snapshot, transfer_fee, votes, wrapper,
+};
+pub use overrides::{Base, ContractOverrides, WrapperOverrides};
 use soroban_sdk::{
     contracterror, contractevent, contracttrait, Address, Env, MuxedAddress, String,
 };
@@ -314,6 +322,16 @@ pub enum FungibleTokenError {
     UserNotAllowed = 113,
     /// The user is blocked and cannot perform this operation
     UserBlocked = 114,
+    /// Indicates a snapshot id that is `0` or was not created yet.
+    InvalidSnapshotId = 115,
+    /// Indicates the underlying asset address was not set.
+    UnderlyingNotSet = 116,
+    /// Indicates an underlying asset address that cannot be wrapped.
+    InvalidUnderlying = 117,
+    /// Indicates an invalid transfer fee configuration.
+    InvalidTransferFee = 118,
+    /// Indicates a rebase index that is not strictly positive.
+    InvalidRebaseIndex = 119,
 }
 
 // ################## CONSTANTS ##################
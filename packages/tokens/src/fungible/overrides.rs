@@ -101,3 +101,21 @@ pub struct Base;
 
 // No override required for the `Base` contract type.
 impl ContractOverrides for Base {}
+
+/// Wrapper functionality
+///
+/// Trait for overriding the `mint` and `burn` functions used by the
+/// [`crate::fungible::wrapper::FungibleWrapper`] extension. The behavior of
+/// minting and burning changes across implementations, i.e. votes, snapshot,
+/// hence the need for an abstraction.
+pub trait WrapperOverrides {
+    fn mint(e: &Env, to: &Address, amount: i128) {
+        Base::mint(e, to, amount);
+    }
+
+    fn burn(e: &Env, from: &Address, amount: i128) {
+        Base::burn(e, from, amount);
+    }
+}
+
+impl WrapperOverrides for Base {}
@@ -7,6 +7,12 @@
 //!
 //! - `fungible`: Implementation of fungible tokens (similar to ERC-20)
 //! - `non_fungible`: Implementation of non-fungible tokens (similar to ERC-721)
+//! - `marketplace`: Listings, collection offers and auctions of non-fungible
+//!   tokens, with royalty enforcement
+//! - `multi_token`: Implementation of multi tokens (similar to ERC-1155)
+//! - `payment_splitter`: Split of received SEP-41 payments among payees by
+//!   shares
+//! - `vesting`: Linear and cliff vesting of SEP-41 tokens for beneficiaries
 //!
 //! Each module provides its own set of traits, functions, and extensions for
 //! working with the respective token type.
@@ -14,6 +20,10 @@
 #![no_std]
 
 pub mod fungible;
+pub mod marketplace;
+pub mod multi_token;
 pub mod non_fungible;
+pub mod payment_splitter;
 pub mod rwa;
 pub mod vault;
+pub mod vesting;
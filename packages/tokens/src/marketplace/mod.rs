@@ -0,0 +1,685 @@
+//! # Marketplace Module
+//!
+//! Trading of non-fungible tokens against any SEP-41 token, with royalty
+//! enforcement. The module supports three kinds of sales:
+//!
+//! - **Fixed-price listings**: the seller lists a token at a given price with
+//!   [`list`], and anyone can [`buy`] it. Listings are non-custodial: the token
+//!   stays with the seller, who must approve the marketplace contract (with
+//!   `approve` or `approve_for_all`) for the sale to go through. A listing can
+//!   only be bought while its seller owns the token.
+//! - **Collection offers**: a buyer offers a price for any token of a
+//!   collection with [`make_offer`], and the price is held in escrow by the
+//!   marketplace. Any holder of a token of the collection can [`accept_offer`],
+//!   given they approved the marketplace contract.
+//! - **English auctions**: the seller puts a token up for auction with
+//!   [`create_auction`], which transfers the token to the marketplace contract.
+//!   Bids are held in escrow, and the previous highest bidder can
+//!   [`withdraw_refund`] its bid when outbid. Once the auction has ended,
+//!   anyone can [`settle_auction`].
+//!
+//! On every sale, the marketplace calls `royalty_splits` on the collection
+//! (see [`crate::non_fungible::royalties::NonFungibleRoyaltySplits`]), or
+//! `royalty_info` if the former is not implemented (see
+//! [`crate::non_fungible::royalties::NonFungibleRoyalties`]), and pays the
+//! royalties to their receivers, the remainder going to the seller. The
+//! royalties paid never exceed the sale price.
+//! Collections which implement neither are traded without royalties.
+//!
+//! The module doesn't need any privileged role: every function is
+//! authorized by the account acting on its own listings, offers, bids or
+//! tokens.
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contracterror, contractevent, contracttrait, Address, Env};
+pub use storage::{
+    accept_offer, auction, bid, buy, cancel_auction, cancel_listing, cancel_offer, create_auction,
+    list, listing, make_offer, offer, refund, settle_auction, withdraw_refund, Auction, Listing,
+    MarketplaceStorageKey, Offer,
+};
+
+/// Marketplace Trait
+///
+/// The `Marketplace` trait exposes the functionalities of the marketplace
+/// module as contract endpoints. All the functions have default
+/// implementations, as the authorization is required from the account acting
+/// on its own listings, offers, bids or tokens.
+///
+/// ```ignore
+/// #[contractimpl(contracttrait)]
+/// impl Marketplace for ExampleContract {}
+/// ```
+#[contracttrait]
+pub trait Marketplace {
+    /// Returns the listing of `token_id` of the `nft` collection, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `nft` - The address of the collection.
+    /// * `token_id` - The identifier of the token.
+    fn listing(e: &Env, nft: Address, token_id: u32) -> Option<Listing> {
+        listing(e, &nft, token_id)
+    }
+
+    /// Returns the offer with `offer_id`, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `offer_id` - The identifier of the offer.
+    fn offer(e: &Env, offer_id: u32) -> Option<Offer> {
+        offer(e, offer_id)
+    }
+
+    /// Returns the auction of `token_id` of the `nft` collection, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `nft` - The address of the collection.
+    /// * `token_id` - The identifier of the token.
+    fn auction(e: &Env, nft: Address, token_id: u32) -> Option<Auction> {
+        auction(e, &nft, token_id)
+    }
+
+    /// Returns the amount of `payment_token` owed to `bidder` for the bids it
+    /// was outbid on.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `bidder` - The account that was outbid.
+    /// * `payment_token` - The address of the SEP-41 token of the bids.
+    fn refund(e: &Env, bidder: Address, payment_token: Address) -> i128 {
+        refund(e, &bidder, &payment_token)
+    }
+
+    /// Lists `token_id` of the `nft` collection for sale at `price`, paid in
+    /// `payment_token`. Listing an already listed token updates the listing.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `seller` - The owner of the token.
+    /// * `nft` - The address of the collection.
+    /// * `token_id` - The identifier of the token.
+    /// * `payment_token` - The address of the SEP-41 token used for payment.
+    /// * `price` - The price of the token.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`list`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["listed", nft: Address, token_id: u32]`
+    /// * data - `[seller: Address, payment_token: Address, price: i128]`
+    fn list(
+        e: &Env,
+        seller: Address,
+        nft: Address,
+        token_id: u32,
+        payment_token: Address,
+        price: i128,
+    ) {
+        list(e, &seller, &nft, token_id, &payment_token, price);
+    }
+
+    /// Cancels the listing of `token_id` of the `nft` collection.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `nft` - The address of the collection.
+    /// * `token_id` - The identifier of the token.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`cancel_listing`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["listing_cancelled", nft: Address, token_id: u32]`
+    /// * data - `[]`
+    fn cancel_listing(e: &Env, nft: Address, token_id: u32) {
+        cancel_listing(e, &nft, token_id);
+    }
+
+    /// Buys the listed `token_id` of the `nft` collection.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `buyer` - The account buying the token.
+    /// * `nft` - The address of the collection.
+    /// * `token_id` - The identifier of the token.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`buy`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["sold", nft: Address, token_id: u32]`
+    /// * data - `[seller: Address, buyer: Address, payment_token: Address,
+    ///   price: i128, royalty: i128]`
+    fn buy(e: &Env, buyer: Address, nft: Address, token_id: u32) {
+        buy(e, &buyer, &nft, token_id);
+    }
+
+    /// Offers `price` for any token of the `nft` collection, and returns the
+    /// identifier of the offer. The price is held in escrow.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `offerer` - The account making the offer.
+    /// * `nft` - The address of the collection.
+    /// * `payment_token` - The address of the SEP-41 token used for payment.
+    /// * `price` - The offered price.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`make_offer`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["offer_made", offer_id: u32]`
+    /// * data - `[offerer: Address, nft: Address, payment_token: Address,
+    ///   price: i128]`
+    fn make_offer(
+        e: &Env,
+        offerer: Address,
+        nft: Address,
+        payment_token: Address,
+        price: i128,
+    ) -> u32 {
+        make_offer(e, &offerer, &nft, &payment_token, price)
+    }
+
+    /// Cancels the offer with `offer_id` and refunds the offerer.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `offer_id` - The identifier of the offer.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`cancel_offer`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["offer_cancelled", offer_id: u32]`
+    /// * data - `[]`
+    fn cancel_offer(e: &Env, offer_id: u32) {
+        cancel_offer(e, offer_id);
+    }
+
+    /// Accepts the offer with `offer_id` by selling `token_id` to the
+    /// offerer.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `seller` - The owner of the token.
+    /// * `offer_id` - The identifier of the offer.
+    /// * `token_id` - The identifier of the token sold.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`accept_offer`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["offer_accepted", offer_id: u32]`
+    /// * data - `[token_id: u32]`
+    ///
+    /// * topics - `["sold", nft: Address, token_id: u32]`
+    /// * data - `[seller: Address, buyer: Address, payment_token: Address,
+    ///   price: i128, royalty: i128]`
+    fn accept_offer(e: &Env, seller: Address, offer_id: u32, token_id: u32) {
+        accept_offer(e, &seller, offer_id, token_id);
+    }
+
+    /// Puts `token_id` of the `nft` collection up for auction until
+    /// `end_time`. The token is transferred to the marketplace.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `seller` - The owner of the token.
+    /// * `nft` - The address of the collection.
+    /// * `token_id` - The identifier of the token.
+    /// * `payment_token` - The address of the SEP-41 token used for payment.
+    /// * `reserve_price` - The minimum accepted bid.
+    /// * `end_time` - The timestamp at which the auction ends.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`create_auction`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["auction_created", nft: Address, token_id: u32]`
+    /// * data - `[seller: Address, payment_token: Address, reserve_price: i128,
+    ///   end_time: u64]`
+    fn create_auction(
+        e: &Env,
+        seller: Address,
+        nft: Address,
+        token_id: u32,
+        payment_token: Address,
+        reserve_price: i128,
+        end_time: u64,
+    ) {
+        create_auction(e, &seller, &nft, token_id, &payment_token, reserve_price, end_time);
+    }
+
+    /// Bids `amount` on the auction of `token_id` of the `nft` collection. The
+    /// bid of the previous highest bidder is recorded as a refund.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `bidder` - The account bidding.
+    /// * `nft` - The address of the collection.
+    /// * `token_id` - The identifier of the token.
+    /// * `amount` - The amount of the bid.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`bid`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["bid_placed", nft: Address, token_id: u32]`
+    /// * data - `[bidder: Address, amount: i128]`
+    fn bid(e: &Env, bidder: Address, nft: Address, token_id: u32, amount: i128) {
+        bid(e, &bidder, &nft, token_id, amount);
+    }
+
+    /// Transfers to `bidder` the amount of `payment_token` it is owed for the
+    /// bids it was outbid on, and returns this amount.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `bidder` - The account that was outbid.
+    /// * `payment_token` - The address of the SEP-41 token of the bids.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`withdraw_refund`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["refund_withdrawn", bidder: Address, payment_token:
+    ///   Address]`
+    /// * data - `[amount: i128]`
+    fn withdraw_refund(e: &Env, bidder: Address, payment_token: Address) -> i128 {
+        withdraw_refund(e, &bidder, &payment_token)
+    }
+
+    /// Cancels the auction of `token_id` of the `nft` collection, which must
+    /// not have received any bid, and returns the token to the seller.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `nft` - The address of the collection.
+    /// * `token_id` - The identifier of the token.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`cancel_auction`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["auction_cancelled", nft: Address, token_id: u32]`
+    /// * data - `[]`
+    fn cancel_auction(e: &Env, nft: Address, token_id: u32) {
+        cancel_auction(e, &nft, token_id);
+    }
+
+    /// Settles the ended auction of `token_id` of the `nft` collection.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `nft` - The address of the collection.
+    /// * `token_id` - The identifier of the token.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`settle_auction`] errors.
+    ///
+    /// # Events
+    ///
+    /// If the auction received bids:
+    /// * topics - `["sold", nft: Address, token_id: u32]`
+    /// * data - `[seller: Address, buyer: Address, payment_token: Address,
+    ///   price: i128, royalty: i128]`
+    ///
+    /// Otherwise:
+    /// * topics - `["auction_cancelled", nft: Address, token_id: u32]`
+    /// * data - `[]`
+    fn settle_auction(e: &Env, nft: Address, token_id: u32) {
+        settle_auction(e, &nft, token_id);
+    }
+}
+
+// ################## ERRORS ##################
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MarketplaceError {
+    /// Indicates a price, a reserve price or a bid that is not positive.
+    InvalidPrice = 800,
+    /// Indicates a token that is not listed.
+    ListingNotFound = 801,
+    /// Indicates an offer that does not exist.
+    OfferNotFound = 802,
+    /// Indicates a token that is not up for auction.
+    AuctionNotFound = 803,
+    /// Indicates the seller is not the owner of the token.
+    NotTokenOwner = 804,
+    /// Indicates an end time of an auction that is not in the future.
+    InvalidEndTime = 805,
+    /// Indicates a bid on an auction that has ended.
+    AuctionEnded = 806,
+    /// Indicates an attempt to settle an auction that has not ended yet.
+    AuctionNotEnded = 807,
+    /// Indicates a bid lower than the reserve price or the highest bid.
+    BidTooLow = 808,
+    /// Indicates an attempt to cancel an auction that received bids.
+    AuctionHasBids = 809,
+    /// Indicates a royalty that is negative.
+    InvalidRoyaltyAmount = 810,
+    /// Indicates an attempt to withdraw a refund when nothing is owed.
+    RefundNotFound = 811,
+}
+
+// ################## CONSTANTS ##################
+
+const DAY_IN_LEDGERS: u32 = 17280;
+pub const MARKETPLACE_EXTEND_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const MARKETPLACE_TTL_THRESHOLD: u32 = MARKETPLACE_EXTEND_AMOUNT - DAY_IN_LEDGERS;
+
+// ################## EVENTS ##################
+
+/// Event emitted when a token is listed.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Listed {
+    #[topic]
+    pub nft: Address,
+    #[topic]
+    pub token_id: u32,
+    pub seller: Address,
+    pub payment_token: Address,
+    pub price: i128,
+}
+
+/// Emits an event indicating a token was listed.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `listing` - The listing.
+pub fn emit_listed(e: &Env, listing: &Listing) {
+    Listed {
+        nft: listing.nft.clone(),
+        token_id: listing.token_id,
+        seller: listing.seller.clone(),
+        payment_token: listing.payment_token.clone(),
+        price: listing.price,
+    }
+    .publish(e);
+}
+
+/// Event emitted when a listing is cancelled.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ListingCancelled {
+    #[topic]
+    pub nft: Address,
+    #[topic]
+    pub token_id: u32,
+}
+
+/// Emits an event indicating a listing was cancelled.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `nft` - The address of the collection.
+/// * `token_id` - The identifier of the token.
+pub fn emit_listing_cancelled(e: &Env, nft: &Address, token_id: u32) {
+    ListingCancelled { nft: nft.clone(), token_id }.publish(e);
+}
+
+/// Event emitted when a token is sold.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sold {
+    #[topic]
+    pub nft: Address,
+    #[topic]
+    pub token_id: u32,
+    pub seller: Address,
+    pub buyer: Address,
+    pub payment_token: Address,
+    pub price: i128,
+    pub royalty: i128,
+}
+
+/// Emits an event indicating a token was sold.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `nft` - The address of the collection.
+/// * `token_id` - The identifier of the token.
+/// * `seller` - The previous owner of the token.
+/// * `buyer` - The new owner of the token.
+/// * `payment_token` - The address of the SEP-41 token used for payment.
+/// * `price` - The sale price.
+/// * `royalty` - The part of the price paid as royalty.
+#[allow(clippy::too_many_arguments)]
+pub fn emit_sold(
+    e: &Env,
+    nft: &Address,
+    token_id: u32,
+    seller: &Address,
+    buyer: &Address,
+    payment_token: &Address,
+    price: i128,
+    royalty: i128,
+) {
+    Sold {
+        nft: nft.clone(),
+        token_id,
+        seller: seller.clone(),
+        buyer: buyer.clone(),
+        payment_token: payment_token.clone(),
+        price,
+        royalty,
+    }
+    .publish(e);
+}
+
+/// Event emitted when an offer is made.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OfferMade {
+    #[topic]
+    pub offer_id: u32,
+    pub offerer: Address,
+    pub nft: Address,
+    pub payment_token: Address,
+    pub price: i128,
+}
+
+/// Emits an event indicating an offer was made.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `offer_id` - The identifier of the offer.
+/// * `offer` - The offer.
+pub fn emit_offer_made(e: &Env, offer_id: u32, offer: &Offer) {
+    OfferMade {
+        offer_id,
+        offerer: offer.offerer.clone(),
+        nft: offer.nft.clone(),
+        payment_token: offer.payment_token.clone(),
+        price: offer.price,
+    }
+    .publish(e);
+}
+
+/// Event emitted when an offer is cancelled.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OfferCancelled {
+    #[topic]
+    pub offer_id: u32,
+}
+
+/// Emits an event indicating an offer was cancelled.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `offer_id` - The identifier of the offer.
+pub fn emit_offer_cancelled(e: &Env, offer_id: u32) {
+    OfferCancelled { offer_id }.publish(e);
+}
+
+/// Event emitted when an offer is accepted.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OfferAccepted {
+    #[topic]
+    pub offer_id: u32,
+    pub token_id: u32,
+}
+
+/// Emits an event indicating an offer was accepted.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `offer_id` - The identifier of the offer.
+/// * `token_id` - The identifier of the token sold.
+pub fn emit_offer_accepted(e: &Env, offer_id: u32, token_id: u32) {
+    OfferAccepted { offer_id, token_id }.publish(e);
+}
+
+/// Event emitted when an auction is created.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuctionCreated {
+    #[topic]
+    pub nft: Address,
+    #[topic]
+    pub token_id: u32,
+    pub seller: Address,
+    pub payment_token: Address,
+    pub reserve_price: i128,
+    pub end_time: u64,
+}
+
+/// Emits an event indicating an auction was created.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `auction` - The auction.
+pub fn emit_auction_created(e: &Env, auction: &Auction) {
+    AuctionCreated {
+        nft: auction.nft.clone(),
+        token_id: auction.token_id,
+        seller: auction.seller.clone(),
+        payment_token: auction.payment_token.clone(),
+        reserve_price: auction.reserve_price,
+        end_time: auction.end_time,
+    }
+    .publish(e);
+}
+
+/// Event emitted when a bid is placed.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BidPlaced {
+    #[topic]
+    pub nft: Address,
+    #[topic]
+    pub token_id: u32,
+    pub bidder: Address,
+    pub amount: i128,
+}
+
+/// Emits an event indicating a bid was placed.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `nft` - The address of the collection.
+/// * `token_id` - The identifier of the token.
+/// * `bidder` - The account bidding.
+/// * `amount` - The amount of the bid.
+pub fn emit_bid_placed(e: &Env, nft: &Address, token_id: u32, bidder: &Address, amount: i128) {
+    BidPlaced { nft: nft.clone(), token_id, bidder: bidder.clone(), amount }.publish(e);
+}
+
+/// Event emitted when an auction is cancelled.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuctionCancelled {
+    #[topic]
+    pub nft: Address,
+    #[topic]
+    pub token_id: u32,
+}
+
+/// Emits an event indicating an auction was cancelled.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `nft` - The address of the collection.
+/// * `token_id` - The identifier of the token.
+pub fn emit_auction_cancelled(e: &Env, nft: &Address, token_id: u32) {
+    AuctionCancelled { nft: nft.clone(), token_id }.publish(e);
+}
+
+/// Event emitted when an outbid bidder withdraws its refund.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundWithdrawn {
+    #[topic]
+    pub bidder: Address,
+    #[topic]
+    pub payment_token: Address,
+    pub amount: i128,
+}
+
+/// Emits an event indicating an outbid bidder withdrew its refund.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `bidder` - The account that was outbid.
+/// * `payment_token` - The address of the SEP-41 token of the bids.
+/// * `amount` - The amount withdrawn.
+pub fn emit_refund_withdrawn(e: &Env, bidder: &Address, payment_token: &Address, amount: i128) {
+    RefundWithdrawn { bidder: bidder.clone(), payment_token: payment_token.clone(), amount }
+        .publish(e);
+}
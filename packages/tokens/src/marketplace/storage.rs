@@ -0,0 +1,804 @@
+use soroban_sdk::{
+    contractclient, contracttype, panic_with_error, token::TokenClient, vec, Address, Env, Vec,
+};
+
+use crate::marketplace::{
+    emit_auction_cancelled, emit_auction_created, emit_bid_placed, emit_listed,
+    emit_listing_cancelled, emit_offer_accepted, emit_offer_cancelled, emit_offer_made,
+    emit_refund_withdrawn, emit_sold, MarketplaceError, MARKETPLACE_EXTEND_AMOUNT,
+    MARKETPLACE_TTL_THRESHOLD,
+};
+
+/// Fixed-price listing of a token
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Listing {
+    pub seller: Address,
+    pub nft: Address,
+    pub token_id: u32,
+    pub payment_token: Address,
+    pub price: i128,
+}
+
+/// Offer for any token of a collection
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Offer {
+    pub offerer: Address,
+    pub nft: Address,
+    pub payment_token: Address,
+    pub price: i128,
+}
+
+/// English auction of a token
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Auction {
+    pub seller: Address,
+    pub nft: Address,
+    pub token_id: u32,
+    pub payment_token: Address,
+    pub reserve_price: i128,
+    pub end_time: u64,
+    pub highest_bidder: Option<Address>,
+    pub highest_bid: i128,
+}
+
+/// Storage keys for the data associated with the marketplace module
+#[contracttype]
+pub enum MarketplaceStorageKey {
+    /// Listing of a token (collection, token_id)
+    Listing(Address, u32),
+    /// Offer by its identifier
+    Offer(u32),
+    /// Identifier of the next offer
+    NextOfferId,
+    /// Auction of a token (collection, token_id)
+    Auction(Address, u32),
+    /// Refund owed to an outbid bidder (bidder, payment_token)
+    Refund(Address, Address),
+}
+
+// We only need a subset of the non-fungible token interface, including
+// `royalty_info` and `royalty_splits` from the royalties extension, hence a
+// dedicated client.
+#[allow(unused)]
+#[contractclient(name = "NonFungibleCollectionClient")]
+trait NonFungibleCollection {
+    fn owner_of(e: &Env, token_id: u32) -> Address;
+
+    fn transfer(e: &Env, from: Address, to: Address, token_id: u32);
+
+    fn transfer_from(e: &Env, spender: Address, from: Address, to: Address, token_id: u32);
+
+    fn royalty_info(e: &Env, token_id: u32, sale_price: i128) -> (Address, i128);
+
+    fn royalty_splits(e: &Env, token_id: u32, sale_price: i128) -> Vec<(Address, i128)>;
+}
+
+// ################## QUERY STATE ##################
+
+/// Returns the listing of `token_id` of the `nft` collection, if any.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `nft` - The address of the collection.
+/// * `token_id` - The identifier of the token.
+pub fn listing(e: &Env, nft: &Address, token_id: u32) -> Option<Listing> {
+    get_entry(e, &MarketplaceStorageKey::Listing(nft.clone(), token_id))
+}
+
+/// Returns the offer with `offer_id`, if any.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `offer_id` - The identifier of the offer.
+pub fn offer(e: &Env, offer_id: u32) -> Option<Offer> {
+    get_entry(e, &MarketplaceStorageKey::Offer(offer_id))
+}
+
+/// Returns the auction of `token_id` of the `nft` collection, if any.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `nft` - The address of the collection.
+/// * `token_id` - The identifier of the token.
+pub fn auction(e: &Env, nft: &Address, token_id: u32) -> Option<Auction> {
+    get_entry(e, &MarketplaceStorageKey::Auction(nft.clone(), token_id))
+}
+
+/// Returns the amount of `payment_token` owed to `bidder` for the bids it
+/// was outbid on, and that can be withdrawn with [`withdraw_refund`].
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `bidder` - The account that was outbid.
+/// * `payment_token` - The address of the SEP-41 token of the bids.
+pub fn refund(e: &Env, bidder: &Address, payment_token: &Address) -> i128 {
+    get_entry(e, &MarketplaceStorageKey::Refund(bidder.clone(), payment_token.clone())).unwrap_or(0)
+}
+
+// ################## CHANGE STATE ##################
+
+/// Lists `token_id` of the `nft` collection for sale at `price`, paid in
+/// `payment_token`. Listing an already listed token replaces the listing.
+///
+/// The token stays with the seller, who must approve the marketplace contract
+/// to transfer it, otherwise [`buy`] fails. [`buy`] also fails while the
+/// seller doesn't own the token anymore.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `seller` - The owner of the token.
+/// * `nft` - The address of the collection.
+/// * `token_id` - The identifier of the token.
+/// * `payment_token` - The address of the SEP-41 token used for payment.
+/// * `price` - The price of the token.
+///
+/// # Errors
+///
+/// * [`MarketplaceError::InvalidPrice`] - When `price` is not positive.
+/// * [`MarketplaceError::NotTokenOwner`] - When `seller` is not the owner of
+///   the token.
+///
+/// # Events
+///
+/// * topics - `["listed", nft: Address, token_id: u32]`
+/// * data - `[seller: Address, payment_token: Address, price: i128]`
+///
+/// # Notes
+///
+/// Authorization for `seller` is required.
+pub fn list(
+    e: &Env,
+    seller: &Address,
+    nft: &Address,
+    token_id: u32,
+    payment_token: &Address,
+    price: i128,
+) {
+    seller.require_auth();
+
+    if price <= 0 {
+        panic_with_error!(e, MarketplaceError::InvalidPrice);
+    }
+    if NonFungibleCollectionClient::new(e, nft).owner_of(&token_id) != *seller {
+        panic_with_error!(e, MarketplaceError::NotTokenOwner);
+    }
+
+    let listing = Listing {
+        seller: seller.clone(),
+        nft: nft.clone(),
+        token_id,
+        payment_token: payment_token.clone(),
+        price,
+    };
+    e.storage().persistent().set(&MarketplaceStorageKey::Listing(nft.clone(), token_id), &listing);
+
+    emit_listed(e, &listing);
+}
+
+/// Cancels the listing of `token_id` of the `nft` collection.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `nft` - The address of the collection.
+/// * `token_id` - The identifier of the token.
+///
+/// # Errors
+///
+/// * [`MarketplaceError::ListingNotFound`] - When the token is not listed.
+///
+/// # Events
+///
+/// * topics - `["listing_cancelled", nft: Address, token_id: u32]`
+/// * data - `[]`
+///
+/// # Notes
+///
+/// Authorization for the seller of the listing is required.
+pub fn cancel_listing(e: &Env, nft: &Address, token_id: u32) {
+    let listing = listing(e, nft, token_id)
+        .unwrap_or_else(|| panic_with_error!(e, MarketplaceError::ListingNotFound));
+    listing.seller.require_auth();
+
+    e.storage().persistent().remove(&MarketplaceStorageKey::Listing(nft.clone(), token_id));
+
+    emit_listing_cancelled(e, nft, token_id);
+}
+
+/// Buys the listed `token_id` of the `nft` collection. The price is paid by
+/// `buyer`, and split between the royalty receiver and the seller.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `buyer` - The account buying the token.
+/// * `nft` - The address of the collection.
+/// * `token_id` - The identifier of the token.
+///
+/// # Errors
+///
+/// * [`MarketplaceError::ListingNotFound`] - When the token is not listed.
+/// * [`MarketplaceError::NotTokenOwner`] - When the seller of the listing is no
+///   longer the owner of the token.
+/// * [`MarketplaceError::InvalidRoyaltyAmount`] - When a royalty returned by
+///   the collection is negative.
+/// * refer to the `transfer_from` errors of the collection, e.g. when the
+///   seller didn't approve the marketplace.
+///
+/// # Events
+///
+/// * topics - `["sold", nft: Address, token_id: u32]`
+/// * data - `[seller: Address, buyer: Address, payment_token: Address, price:
+///   i128, royalty: i128]`
+///
+/// # Notes
+///
+/// Authorization for `buyer` is required.
+pub fn buy(e: &Env, buyer: &Address, nft: &Address, token_id: u32) {
+    buyer.require_auth();
+
+    let listing = listing(e, nft, token_id)
+        .unwrap_or_else(|| panic_with_error!(e, MarketplaceError::ListingNotFound));
+    e.storage().persistent().remove(&MarketplaceStorageKey::Listing(nft.clone(), token_id));
+
+    let client = NonFungibleCollectionClient::new(e, nft);
+    if client.owner_of(&token_id) != listing.seller {
+        panic_with_error!(e, MarketplaceError::NotTokenOwner);
+    }
+
+    client.transfer_from(&e.current_contract_address(), &listing.seller, buyer, &token_id);
+
+    let royalty =
+        pay(e, &listing.payment_token, buyer, &listing.seller, nft, token_id, listing.price);
+
+    emit_sold(
+        e,
+        nft,
+        token_id,
+        &listing.seller,
+        buyer,
+        &listing.payment_token,
+        listing.price,
+        royalty,
+    );
+}
+
+/// Offers `price` for any token of the `nft` collection, and returns the
+/// identifier of the offer. The price is transferred from `offerer` to the
+/// marketplace, and held in escrow until the offer is accepted or cancelled.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `offerer` - The account making the offer.
+/// * `nft` - The address of the collection.
+/// * `payment_token` - The address of the SEP-41 token used for payment.
+/// * `price` - The offered price.
+///
+/// # Errors
+///
+/// * [`MarketplaceError::InvalidPrice`] - When `price` is not positive.
+///
+/// # Events
+///
+/// * topics - `["offer_made", offer_id: u32]`
+/// * data - `[offerer: Address, nft: Address, payment_token: Address, price:
+///   i128]`
+///
+/// # Notes
+///
+/// Authorization for `offerer` is required.
+pub fn make_offer(
+    e: &Env,
+    offerer: &Address,
+    nft: &Address,
+    payment_token: &Address,
+    price: i128,
+) -> u32 {
+    offerer.require_auth();
+
+    if price <= 0 {
+        panic_with_error!(e, MarketplaceError::InvalidPrice);
+    }
+
+    let offer_id: u32 =
+        e.storage().instance().get(&MarketplaceStorageKey::NextOfferId).unwrap_or(0);
+    // NOTE: practically unreachable, as it would require `u32::MAX` offers.
+    e.storage().instance().set(&MarketplaceStorageKey::NextOfferId, &(offer_id + 1));
+
+    let offer = Offer {
+        offerer: offerer.clone(),
+        nft: nft.clone(),
+        payment_token: payment_token.clone(),
+        price,
+    };
+    e.storage().persistent().set(&MarketplaceStorageKey::Offer(offer_id), &offer);
+
+    TokenClient::new(e, payment_token).transfer(offerer, e.current_contract_address(), &price);
+
+    emit_offer_made(e, offer_id, &offer);
+
+    offer_id
+}
+
+/// Cancels the offer with `offer_id` and refunds the offerer.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `offer_id` - The identifier of the offer.
+///
+/// # Errors
+///
+/// * [`MarketplaceError::OfferNotFound`] - When the offer does not exist.
+///
+/// # Events
+///
+/// * topics - `["offer_cancelled", offer_id: u32]`
+/// * data - `[]`
+///
+/// # Notes
+///
+/// Authorization for the offerer is required.
+pub fn cancel_offer(e: &Env, offer_id: u32) {
+    let offer =
+        offer(e, offer_id).unwrap_or_else(|| panic_with_error!(e, MarketplaceError::OfferNotFound));
+    offer.offerer.require_auth();
+
+    e.storage().persistent().remove(&MarketplaceStorageKey::Offer(offer_id));
+
+    TokenClient::new(e, &offer.payment_token).transfer(
+        &e.current_contract_address(),
+        &offer.offerer,
+        &offer.price,
+    );
+
+    emit_offer_cancelled(e, offer_id);
+}
+
+/// Accepts the offer with `offer_id` by selling `token_id` to the offerer.
+/// The escrowed price is split between the royalty receiver and the seller.
+/// Any listing of the token is removed.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `seller` - The owner of the token.
+/// * `offer_id` - The identifier of the offer.
+/// * `token_id` - The identifier of the token sold.
+///
+/// # Errors
+///
+/// * [`MarketplaceError::OfferNotFound`] - When the offer does not exist.
+/// * [`MarketplaceError::InvalidRoyaltyAmount`] - When a royalty returned by
+///   the collection is negative.
+/// * refer to the `transfer_from` errors of the collection, e.g. when the
+///   seller doesn't own the token or didn't approve the marketplace.
+///
+/// # Events
+///
+/// * topics - `["offer_accepted", offer_id: u32]`
+/// * data - `[token_id: u32]`
+///
+/// * topics - `["sold", nft: Address, token_id: u32]`
+/// * data - `[seller: Address, buyer: Address, payment_token: Address, price:
+///   i128, royalty: i128]`
+///
+/// # Notes
+///
+/// Authorization for `seller` is required.
+pub fn accept_offer(e: &Env, seller: &Address, offer_id: u32, token_id: u32) {
+    seller.require_auth();
+
+    let offer =
+        offer(e, offer_id).unwrap_or_else(|| panic_with_error!(e, MarketplaceError::OfferNotFound));
+    e.storage().persistent().remove(&MarketplaceStorageKey::Offer(offer_id));
+    e.storage().persistent().remove(&MarketplaceStorageKey::Listing(offer.nft.clone(), token_id));
+
+    NonFungibleCollectionClient::new(e, &offer.nft).transfer_from(
+        &e.current_contract_address(),
+        seller,
+        &offer.offerer,
+        &token_id,
+    );
+
+    let royalty = pay(
+        e,
+        &offer.payment_token,
+        &e.current_contract_address(),
+        seller,
+        &offer.nft,
+        token_id,
+        offer.price,
+    );
+
+    emit_offer_accepted(e, offer_id, token_id);
+    emit_sold(
+        e,
+        &offer.nft,
+        token_id,
+        seller,
+        &offer.offerer,
+        &offer.payment_token,
+        offer.price,
+        royalty,
+    );
+}
+
+/// Puts `token_id` of the `nft` collection up for auction until `end_time`.
+/// The token is transferred from `seller` to the marketplace, which must be
+/// approved to do so. Any listing of the token is removed.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `seller` - The owner of the token.
+/// * `nft` - The address of the collection.
+/// * `token_id` - The identifier of the token.
+/// * `payment_token` - The address of the SEP-41 token used for payment.
+/// * `reserve_price` - The minimum accepted bid.
+/// * `end_time` - The timestamp at which the auction ends.
+///
+/// # Errors
+///
+/// * [`MarketplaceError::InvalidPrice`] - When `reserve_price` is not positive.
+/// * [`MarketplaceError::InvalidEndTime`] - When `end_time` is not in the
+///   future.
+/// * refer to the `transfer_from` errors of the collection, e.g. when the
+///   seller doesn't own the token or didn't approve the marketplace.
+///
+/// # Events
+///
+/// * topics - `["auction_created", nft: Address, token_id: u32]`
+/// * data - `[seller: Address, payment_token: Address, reserve_price: i128,
+///   end_time: u64]`
+///
+/// # Notes
+///
+/// Authorization for `seller` is required.
+pub fn create_auction(
+    e: &Env,
+    seller: &Address,
+    nft: &Address,
+    token_id: u32,
+    payment_token: &Address,
+    reserve_price: i128,
+    end_time: u64,
+) {
+    seller.require_auth();
+
+    if reserve_price <= 0 {
+        panic_with_error!(e, MarketplaceError::InvalidPrice);
+    }
+    if end_time <= e.ledger().timestamp() {
+        panic_with_error!(e, MarketplaceError::InvalidEndTime);
+    }
+
+    e.storage().persistent().remove(&MarketplaceStorageKey::Listing(nft.clone(), token_id));
+
+    let contract_address = e.current_contract_address();
+    NonFungibleCollectionClient::new(e, nft).transfer_from(
+        &contract_address,
+        seller,
+        &contract_address,
+        &token_id,
+    );
+
+    let auction = Auction {
+        seller: seller.clone(),
+        nft: nft.clone(),
+        token_id,
+        payment_token: payment_token.clone(),
+        reserve_price,
+        end_time,
+        highest_bidder: None,
+        highest_bid: 0,
+    };
+    e.storage().persistent().set(&MarketplaceStorageKey::Auction(nft.clone(), token_id), &auction);
+
+    emit_auction_created(e, &auction);
+}
+
+/// Bids `amount` on the auction of `token_id` of the `nft` collection. The
+/// amount is transferred from `bidder` to the marketplace, and the bid of the
+/// previous highest bidder is recorded as a refund, to be withdrawn with
+/// [`withdraw_refund`].
+///
+/// The refund is not transferred directly, so that a previous bidder unable
+/// to receive the payment token (e.g. a deauthorized account of a Stellar
+/// Asset Contract) can't block the auction.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `bidder` - The account bidding.
+/// * `nft` - The address of the collection.
+/// * `token_id` - The identifier of the token.
+/// * `amount` - The amount of the bid.
+///
+/// # Errors
+///
+/// * [`MarketplaceError::AuctionNotFound`] - When the token is not up for
+///   auction.
+/// * [`MarketplaceError::AuctionEnded`] - When the auction has ended.
+/// * [`MarketplaceError::BidTooLow`] - When `amount` is lower than the reserve
+///   price, or not higher than the highest bid.
+///
+/// # Events
+///
+/// * topics - `["bid_placed", nft: Address, token_id: u32]`
+/// * data - `[bidder: Address, amount: i128]`
+///
+/// # Notes
+///
+/// Authorization for `bidder` is required.
+pub fn bid(e: &Env, bidder: &Address, nft: &Address, token_id: u32, amount: i128) {
+    bidder.require_auth();
+
+    let mut auction = auction(e, nft, token_id)
+        .unwrap_or_else(|| panic_with_error!(e, MarketplaceError::AuctionNotFound));
+    if e.ledger().timestamp() >= auction.end_time {
+        panic_with_error!(e, MarketplaceError::AuctionEnded);
+    }
+    if amount < auction.reserve_price || amount <= auction.highest_bid {
+        panic_with_error!(e, MarketplaceError::BidTooLow);
+    }
+
+    TokenClient::new(e, &auction.payment_token).transfer(
+        bidder,
+        e.current_contract_address(),
+        &amount,
+    );
+    if let Some(previous_bidder) = &auction.highest_bidder {
+        let key =
+            MarketplaceStorageKey::Refund(previous_bidder.clone(), auction.payment_token.clone());
+        // NOTE: can't overflow, as the refunds are held by the marketplace in
+        // the payment token, whose supply fits in an `i128`.
+        let pending = refund(e, previous_bidder, &auction.payment_token) + auction.highest_bid;
+        e.storage().persistent().set(&key, &pending);
+    }
+
+    auction.highest_bidder = Some(bidder.clone());
+    auction.highest_bid = amount;
+    e.storage().persistent().set(&MarketplaceStorageKey::Auction(nft.clone(), token_id), &auction);
+
+    emit_bid_placed(e, nft, token_id, bidder, amount);
+}
+
+/// Transfers to `bidder` the amount of `payment_token` it is owed for the
+/// bids it was outbid on, and returns this amount.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `bidder` - The account that was outbid.
+/// * `payment_token` - The address of the SEP-41 token of the bids.
+///
+/// # Errors
+///
+/// * [`MarketplaceError::RefundNotFound`] - When nothing is owed to `bidder` in
+///   `payment_token`.
+///
+/// # Events
+///
+/// * topics - `["refund_withdrawn", bidder: Address, payment_token: Address]`
+/// * data - `[amount: i128]`
+///
+/// # Notes
+///
+/// Authorization for `bidder` is required.
+pub fn withdraw_refund(e: &Env, bidder: &Address, payment_token: &Address) -> i128 {
+    bidder.require_auth();
+
+    let amount = refund(e, bidder, payment_token);
+    if amount == 0 {
+        panic_with_error!(e, MarketplaceError::RefundNotFound);
+    }
+    e.storage()
+        .persistent()
+        .remove(&MarketplaceStorageKey::Refund(bidder.clone(), payment_token.clone()));
+
+    TokenClient::new(e, payment_token).transfer(&e.current_contract_address(), bidder, &amount);
+
+    emit_refund_withdrawn(e, bidder, payment_token, amount);
+
+    amount
+}
+
+/// Cancels the auction of `token_id` of the `nft` collection and returns the
+/// token to the seller.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `nft` - The address of the collection.
+/// * `token_id` - The identifier of the token.
+///
+/// # Errors
+///
+/// * [`MarketplaceError::AuctionNotFound`] - When the token is not up for
+///   auction.
+/// * [`MarketplaceError::AuctionHasBids`] - When the auction received bids.
+///
+/// # Events
+///
+/// * topics - `["auction_cancelled", nft: Address, token_id: u32]`
+/// * data - `[]`
+///
+/// # Notes
+///
+/// Authorization for the seller of the auction is required.
+pub fn cancel_auction(e: &Env, nft: &Address, token_id: u32) {
+    let auction = auction(e, nft, token_id)
+        .unwrap_or_else(|| panic_with_error!(e, MarketplaceError::AuctionNotFound));
+    auction.seller.require_auth();
+
+    if auction.highest_bidder.is_some() {
+        panic_with_error!(e, MarketplaceError::AuctionHasBids);
+    }
+
+    e.storage().persistent().remove(&MarketplaceStorageKey::Auction(nft.clone(), token_id));
+
+    NonFungibleCollectionClient::new(e, nft).transfer(
+        &e.current_contract_address(),
+        &auction.seller,
+        &token_id,
+    );
+
+    emit_auction_cancelled(e, nft, token_id);
+}
+
+/// Settles the ended auction of `token_id` of the `nft` collection. The token
+/// is transferred to the highest bidder, and the highest bid is split between
+/// the royalty receiver and the seller. If the auction received no bid, the
+/// token is returned to the seller.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `nft` - The address of the collection.
+/// * `token_id` - The identifier of the token.
+///
+/// # Errors
+///
+/// * [`MarketplaceError::AuctionNotFound`] - When the token is not up for
+///   auction.
+/// * [`MarketplaceError::AuctionNotEnded`] - When the auction has not ended.
+/// * [`MarketplaceError::InvalidRoyaltyAmount`] - When a royalty returned by
+///   the collection is negative.
+///
+/// # Events
+///
+/// If the auction received bids:
+/// * topics - `["sold", nft: Address, token_id: u32]`
+/// * data - `[seller: Address, buyer: Address, payment_token: Address, price:
+///   i128, royalty: i128]`
+///
+/// Otherwise:
+/// * topics - `["auction_cancelled", nft: Address, token_id: u32]`
+/// * data - `[]`
+///
+/// # Notes
+///
+/// No authorization is required, as the outcome of the auction is already
+/// determined.
+pub fn settle_auction(e: &Env, nft: &Address, token_id: u32) {
+    let auction = auction(e, nft, token_id)
+        .unwrap_or_else(|| panic_with_error!(e, MarketplaceError::AuctionNotFound));
+    if e.ledger().timestamp() < auction.end_time {
+        panic_with_error!(e, MarketplaceError::AuctionNotEnded);
+    }
+
+    e.storage().persistent().remove(&MarketplaceStorageKey::Auction(nft.clone(), token_id));
+
+    let contract_address = e.current_contract_address();
+    let recipient = auction.highest_bidder.clone().unwrap_or_else(|| auction.seller.clone());
+    NonFungibleCollectionClient::new(e, nft).transfer(&contract_address, &recipient, &token_id);
+
+    if auction.highest_bidder.is_none() {
+        emit_auction_cancelled(e, nft, token_id);
+        return;
+    }
+
+    let royalty = pay(
+        e,
+        &auction.payment_token,
+        &contract_address,
+        &auction.seller,
+        nft,
+        token_id,
+        auction.highest_bid,
+    );
+
+    emit_sold(
+        e,
+        nft,
+        token_id,
+        &auction.seller,
+        &recipient,
+        &auction.payment_token,
+        auction.highest_bid,
+        royalty,
+    );
+}
+
+// ################## INTERNAL HELPERS ##################
+
+/// Returns the entry stored under `key`, if any, extending its TTL.
+fn get_entry<T: soroban_sdk::TryFromVal<Env, soroban_sdk::Val>>(
+    e: &Env,
+    key: &MarketplaceStorageKey,
+) -> Option<T> {
+    let entry = e.storage().persistent().get::<_, T>(key);
+    if entry.is_some() {
+        e.storage().persistent().extend_ttl(
+            key,
+            MARKETPLACE_TTL_THRESHOLD,
+            MARKETPLACE_EXTEND_AMOUNT,
+        );
+    }
+    entry
+}
+
+/// Transfers `price` of `payment_token` from `from`, paying the royalties of
+/// the token to their receivers and the remainder to `seller`. Returns the
+/// total royalty amount.
+///
+/// The royalties are retrieved with `royalty_splits`, falling back to
+/// `royalty_info` for collections without splits, and to no royalty for
+/// collections implementing neither. The total royalty is capped at `price`:
+/// receivers are paid in order, and the ones beyond the cap are paid only the
+/// remainder, if any.
+fn pay(
+    e: &Env,
+    payment_token: &Address,
+    from: &Address,
+    seller: &Address,
+    nft: &Address,
+    token_id: u32,
+    price: i128,
+) -> i128 {
+    let token = TokenClient::new(e, payment_token);
+
+    let mut royalty: i128 = 0;
+    for (receiver, amount) in royalties(e, nft, token_id, price).iter() {
+        if amount < 0 {
+            panic_with_error!(e, MarketplaceError::InvalidRoyaltyAmount);
+        }
+        // NOTE: can't underflow, as `royalty <= price`.
+        let amount = amount.min(price - royalty);
+        royalty += amount;
+        if amount > 0 {
+            token.transfer(from, &receiver, &amount);
+        }
+    }
+
+    // NOTE: can't underflow, as `royalty <= price`.
+    let proceeds = price - royalty;
+    if proceeds > 0 {
+        token.transfer(from, seller, &proceeds);
+    }
+
+    royalty
+}
+
+/// Returns the royalty receivers of `token_id` of the `nft` collection, with
+/// their amounts for a sale at `price`.
+fn royalties(e: &Env, nft: &Address, token_id: u32, price: i128) -> Vec<(Address, i128)> {
+    let client = NonFungibleCollectionClient::new(e, nft);
+    if let Ok(Ok(splits)) = client.try_royalty_splits(&token_id, &price) {
+        return splits;
+    }
+    match client.try_royalty_info(&token_id, &price) {
+        Ok(Ok(royalty)) => vec![e, royalty],
+        _ => Vec::new(e),
+    }
+}
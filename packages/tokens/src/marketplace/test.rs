@@ -0,0 +1,546 @@
+extern crate std;
+
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, IssuerFlags, Ledger as _},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env, Vec,
+};
+
+use crate::{
+    marketplace::{
+        accept_offer, auction, bid, buy, cancel_auction, cancel_listing, cancel_offer,
+        create_auction, list, listing, make_offer, offer, refund, settle_auction, withdraw_refund,
+    },
+    non_fungible::{royalties::RoyaltyInfo, Base},
+};
+
+#[contract]
+struct MockContract;
+
+/// Collection paying a 10% royalty to the receiver given at construction.
+#[contract]
+struct MockRoyaltyCollection;
+
+#[contractimpl]
+impl MockRoyaltyCollection {
+    pub fn __constructor(e: &Env, royalty_receiver: Address) {
+        Base::set_default_royalty(e, &royalty_receiver, 1000);
+    }
+
+    pub fn mint(e: &Env, to: Address) -> u32 {
+        Base::sequential_mint(e, &to)
+    }
+
+    pub fn owner_of(e: &Env, token_id: u32) -> Address {
+        Base::owner_of(e, token_id)
+    }
+
+    pub fn transfer(e: &Env, from: Address, to: Address, token_id: u32) {
+        Base::transfer(e, &from, &to, token_id);
+    }
+
+    pub fn transfer_from(e: &Env, spender: Address, from: Address, to: Address, token_id: u32) {
+        Base::transfer_from(e, &spender, &from, &to, token_id);
+    }
+
+    pub fn approve_for_all(e: &Env, owner: Address, operator: Address, live_until_ledger: u32) {
+        Base::approve_for_all(e, &owner, &operator, live_until_ledger);
+    }
+
+    pub fn royalty_info(e: &Env, token_id: u32, sale_price: i128) -> (Address, i128) {
+        Base::royalty_info(e, token_id, sale_price)
+    }
+}
+
+/// Collection splitting a 10% royalty between two receivers.
+#[contract]
+struct MockSplitsCollection;
+
+#[contractimpl]
+impl MockSplitsCollection {
+    pub fn __constructor(e: &Env, artist: Address, collective: Address) {
+        Base::set_default_royalty_splits(
+            e,
+            &vec![
+                e,
+                RoyaltyInfo { receiver: artist, basis_points: 600 },
+                RoyaltyInfo { receiver: collective, basis_points: 400 },
+            ],
+        );
+    }
+
+    pub fn mint(e: &Env, to: Address) -> u32 {
+        Base::sequential_mint(e, &to)
+    }
+
+    pub fn owner_of(e: &Env, token_id: u32) -> Address {
+        Base::owner_of(e, token_id)
+    }
+
+    pub fn transfer_from(e: &Env, spender: Address, from: Address, to: Address, token_id: u32) {
+        Base::transfer_from(e, &spender, &from, &to, token_id);
+    }
+
+    pub fn approve_for_all(e: &Env, owner: Address, operator: Address, live_until_ledger: u32) {
+        Base::approve_for_all(e, &owner, &operator, live_until_ledger);
+    }
+
+    pub fn royalty_info(e: &Env, token_id: u32, sale_price: i128) -> (Address, i128) {
+        Base::royalty_info(e, token_id, sale_price)
+    }
+
+    pub fn royalty_splits(e: &Env, token_id: u32, sale_price: i128) -> Vec<(Address, i128)> {
+        Base::royalty_splits(e, token_id, sale_price)
+    }
+}
+
+/// Collection claiming the whole sale price twice as royalties.
+#[contract]
+struct MockGreedyCollection;
+
+#[contractimpl]
+impl MockGreedyCollection {
+    pub fn __constructor(e: &Env, first: Address, second: Address) {
+        e.storage().instance().set(&0u32, &vec![e, first, second]);
+    }
+
+    pub fn mint(e: &Env, to: Address) -> u32 {
+        Base::sequential_mint(e, &to)
+    }
+
+    pub fn owner_of(e: &Env, token_id: u32) -> Address {
+        Base::owner_of(e, token_id)
+    }
+
+    pub fn transfer_from(e: &Env, spender: Address, from: Address, to: Address, token_id: u32) {
+        Base::transfer_from(e, &spender, &from, &to, token_id);
+    }
+
+    pub fn approve_for_all(e: &Env, owner: Address, operator: Address, live_until_ledger: u32) {
+        Base::approve_for_all(e, &owner, &operator, live_until_ledger);
+    }
+
+    pub fn royalty_splits(e: &Env, _token_id: u32, sale_price: i128) -> Vec<(Address, i128)> {
+        let receivers: Vec<Address> = e.storage().instance().get(&0u32).unwrap();
+        let mut splits = Vec::new(e);
+        for receiver in receivers.iter() {
+            splits.push_back((receiver, sale_price));
+        }
+        splits
+    }
+}
+
+/// Collection without royalties.
+#[contract]
+struct MockCollection;
+
+#[contractimpl]
+impl MockCollection {
+    pub fn mint(e: &Env, to: Address) -> u32 {
+        Base::sequential_mint(e, &to)
+    }
+
+    pub fn owner_of(e: &Env, token_id: u32) -> Address {
+        Base::owner_of(e, token_id)
+    }
+
+    pub fn transfer(e: &Env, from: Address, to: Address, token_id: u32) {
+        Base::transfer(e, &from, &to, token_id);
+    }
+
+    pub fn transfer_from(e: &Env, spender: Address, from: Address, to: Address, token_id: u32) {
+        Base::transfer_from(e, &spender, &from, &to, token_id);
+    }
+
+    pub fn approve_for_all(e: &Env, owner: Address, operator: Address, live_until_ledger: u32) {
+        Base::approve_for_all(e, &owner, &operator, live_until_ledger);
+    }
+}
+
+struct Setup {
+    e: Env,
+    marketplace: Address,
+    nft: Address,
+    payment_token: Address,
+    royalty_receiver: Address,
+    seller: Address,
+    token_id: u32,
+}
+
+fn setup() -> Setup {
+    let e = Env::default();
+    e.mock_all_auths();
+    let marketplace = e.register(MockContract, ());
+    let royalty_receiver = Address::generate(&e);
+    let nft = e.register(MockRoyaltyCollection, (royalty_receiver.clone(),));
+    let payment_token = e.register_stellar_asset_contract_v2(Address::generate(&e)).address();
+    let seller = Address::generate(&e);
+
+    let client = MockRoyaltyCollectionClient::new(&e, &nft);
+    let token_id = client.mint(&seller);
+    client.approve_for_all(&seller, &marketplace, &1000);
+
+    Setup { e, marketplace, nft, payment_token, royalty_receiver, seller, token_id }
+}
+
+fn fund(e: &Env, token: &Address, to: &Address, amount: i128) {
+    StellarAssetClient::new(e, token).mint(to, &amount);
+}
+
+#[test]
+fn buy_listing_pays_royalty() {
+    let Setup { e, marketplace, nft, payment_token, royalty_receiver, seller, token_id } = setup();
+    let buyer = Address::generate(&e);
+    fund(&e, &payment_token, &buyer, 1000);
+
+    e.as_contract(&marketplace, || {
+        list(&e, &seller, &nft, token_id, &payment_token, 1000);
+        assert_eq!(listing(&e, &nft, token_id).unwrap().price, 1000);
+    });
+
+    e.as_contract(&marketplace, || {
+        buy(&e, &buyer, &nft, token_id);
+        assert_eq!(listing(&e, &nft, token_id), None);
+    });
+
+    assert_eq!(MockRoyaltyCollectionClient::new(&e, &nft).owner_of(&token_id), buyer);
+    let token = TokenClient::new(&e, &payment_token);
+    assert_eq!(token.balance(&buyer), 0);
+    assert_eq!(token.balance(&royalty_receiver), 100);
+    assert_eq!(token.balance(&seller), 900);
+}
+
+#[test]
+fn buy_listing_without_royalties_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let marketplace = e.register(MockContract, ());
+    let nft = e.register(MockCollection, ());
+    let payment_token = e.register_stellar_asset_contract_v2(Address::generate(&e)).address();
+    let seller = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    fund(&e, &payment_token, &buyer, 500);
+
+    let client = MockCollectionClient::new(&e, &nft);
+    let token_id = client.mint(&seller);
+    client.approve_for_all(&seller, &marketplace, &1000);
+
+    e.as_contract(&marketplace, || {
+        list(&e, &seller, &nft, token_id, &payment_token, 500);
+    });
+    e.as_contract(&marketplace, || {
+        buy(&e, &buyer, &nft, token_id);
+    });
+
+    assert_eq!(client.owner_of(&token_id), buyer);
+    assert_eq!(TokenClient::new(&e, &payment_token).balance(&seller), 500);
+}
+
+#[test]
+fn buy_listing_pays_royalty_splits() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let marketplace = e.register(MockContract, ());
+    let artist = Address::generate(&e);
+    let collective = Address::generate(&e);
+    let nft = e.register(MockSplitsCollection, (artist.clone(), collective.clone()));
+    let payment_token = e.register_stellar_asset_contract_v2(Address::generate(&e)).address();
+    let seller = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    fund(&e, &payment_token, &buyer, 1000);
+
+    let client = MockSplitsCollectionClient::new(&e, &nft);
+    let token_id = client.mint(&seller);
+    client.approve_for_all(&seller, &marketplace, &1000);
+
+    e.as_contract(&marketplace, || {
+        list(&e, &seller, &nft, token_id, &payment_token, 1000);
+    });
+    e.as_contract(&marketplace, || {
+        buy(&e, &buyer, &nft, token_id);
+    });
+
+    let token = TokenClient::new(&e, &payment_token);
+    assert_eq!(token.balance(&artist), 60);
+    assert_eq!(token.balance(&collective), 40);
+    assert_eq!(token.balance(&seller), 900);
+}
+
+#[test]
+fn buy_listing_caps_royalties_at_price() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let marketplace = e.register(MockContract, ());
+    let first = Address::generate(&e);
+    let second = Address::generate(&e);
+    let nft = e.register(MockGreedyCollection, (first.clone(), second.clone()));
+    let payment_token = e.register_stellar_asset_contract_v2(Address::generate(&e)).address();
+    let seller = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    fund(&e, &payment_token, &buyer, 5000);
+
+    let client = MockGreedyCollectionClient::new(&e, &nft);
+    let token_id = client.mint(&seller);
+    client.approve_for_all(&seller, &marketplace, &1000);
+
+    e.as_contract(&marketplace, || {
+        list(&e, &seller, &nft, token_id, &payment_token, 1000);
+    });
+    e.as_contract(&marketplace, || {
+        buy(&e, &buyer, &nft, token_id);
+    });
+
+    let token = TokenClient::new(&e, &payment_token);
+    assert_eq!(token.balance(&buyer), 4000);
+    assert_eq!(token.balance(&first), 1000);
+    assert_eq!(token.balance(&second), 0);
+    assert_eq!(token.balance(&seller), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #804)")]
+fn buy_listing_of_former_owner_panics() {
+    let Setup { e, marketplace, nft, payment_token, seller, token_id, .. } = setup();
+    let other = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    fund(&e, &payment_token, &buyer, 1000);
+
+    e.as_contract(&marketplace, || {
+        list(&e, &seller, &nft, token_id, &payment_token, 1000);
+    });
+
+    // the token is sold elsewhere, to an account that also approved the
+    // marketplace
+    let client = MockRoyaltyCollectionClient::new(&e, &nft);
+    client.transfer(&seller, &other, &token_id);
+    client.approve_for_all(&other, &marketplace, &1000);
+
+    e.as_contract(&marketplace, || {
+        buy(&e, &buyer, &nft, token_id);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #804)")]
+fn list_not_owned_token_panics() {
+    let Setup { e, marketplace, nft, payment_token, token_id, .. } = setup();
+    let other = Address::generate(&e);
+
+    e.as_contract(&marketplace, || {
+        list(&e, &other, &nft, token_id, &payment_token, 1000);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #801)")]
+fn buy_cancelled_listing_panics() {
+    let Setup { e, marketplace, nft, payment_token, seller, token_id, .. } = setup();
+    let buyer = Address::generate(&e);
+
+    e.as_contract(&marketplace, || {
+        list(&e, &seller, &nft, token_id, &payment_token, 1000);
+    });
+    e.as_contract(&marketplace, || {
+        cancel_listing(&e, &nft, token_id);
+    });
+    e.as_contract(&marketplace, || {
+        buy(&e, &buyer, &nft, token_id);
+    });
+}
+
+#[test]
+fn accept_offer_works() {
+    let Setup { e, marketplace, nft, payment_token, royalty_receiver, seller, token_id } = setup();
+    let offerer = Address::generate(&e);
+    fund(&e, &payment_token, &offerer, 2000);
+    let token = TokenClient::new(&e, &payment_token);
+
+    let offer_id =
+        e.as_contract(&marketplace, || make_offer(&e, &offerer, &nft, &payment_token, 2000));
+    assert_eq!(token.balance(&marketplace), 2000);
+
+    e.as_contract(&marketplace, || {
+        accept_offer(&e, &seller, offer_id, token_id);
+        assert_eq!(offer(&e, offer_id), None);
+    });
+
+    assert_eq!(MockRoyaltyCollectionClient::new(&e, &nft).owner_of(&token_id), offerer);
+    assert_eq!(token.balance(&marketplace), 0);
+    assert_eq!(token.balance(&royalty_receiver), 200);
+    assert_eq!(token.balance(&seller), 1800);
+}
+
+#[test]
+fn cancel_offer_refunds() {
+    let Setup { e, marketplace, nft, payment_token, .. } = setup();
+    let offerer = Address::generate(&e);
+    fund(&e, &payment_token, &offerer, 300);
+
+    let offer_id =
+        e.as_contract(&marketplace, || make_offer(&e, &offerer, &nft, &payment_token, 300));
+    e.as_contract(&marketplace, || {
+        cancel_offer(&e, offer_id);
+    });
+
+    assert_eq!(TokenClient::new(&e, &payment_token).balance(&offerer), 300);
+}
+
+#[test]
+fn auction_settles_to_highest_bidder() {
+    let Setup { e, marketplace, nft, payment_token, royalty_receiver, seller, token_id } = setup();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    fund(&e, &payment_token, &alice, 1000);
+    fund(&e, &payment_token, &bob, 1000);
+    let token = TokenClient::new(&e, &payment_token);
+    let collection = MockRoyaltyCollectionClient::new(&e, &nft);
+
+    e.as_contract(&marketplace, || {
+        create_auction(&e, &seller, &nft, token_id, &payment_token, 100, 1000);
+    });
+    assert_eq!(collection.owner_of(&token_id), marketplace);
+
+    e.as_contract(&marketplace, || bid(&e, &alice, &nft, token_id, 100));
+    e.as_contract(&marketplace, || bid(&e, &bob, &nft, token_id, 500));
+
+    // alice's bid is recorded as a refund when outbid
+    assert_eq!(token.balance(&alice), 900);
+    assert_eq!(token.balance(&marketplace), 600);
+    e.as_contract(&marketplace, || {
+        assert_eq!(refund(&e, &alice, &payment_token), 100);
+        assert_eq!(withdraw_refund(&e, &alice, &payment_token), 100);
+        assert_eq!(refund(&e, &alice, &payment_token), 0);
+    });
+    assert_eq!(token.balance(&alice), 1000);
+    assert_eq!(token.balance(&marketplace), 500);
+
+    e.ledger().set_timestamp(1000);
+    e.as_contract(&marketplace, || {
+        settle_auction(&e, &nft, token_id);
+        assert_eq!(auction(&e, &nft, token_id), None);
+    });
+
+    assert_eq!(collection.owner_of(&token_id), bob);
+    assert_eq!(token.balance(&bob), 500);
+    assert_eq!(token.balance(&royalty_receiver), 50);
+    assert_eq!(token.balance(&seller), 450);
+    assert_eq!(token.balance(&marketplace), 0);
+}
+
+#[test]
+fn failing_refund_does_not_block_bids() {
+    let Setup { e, marketplace, nft, seller, token_id, .. } = setup();
+    // revocable asset, so that the authorization of bidders can be removed
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(&e));
+    sac.issuer().set_flag(IssuerFlags::RevocableFlag);
+    let payment_token = sac.address();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let carol = Address::generate(&e);
+    fund(&e, &payment_token, &alice, 1000);
+    fund(&e, &payment_token, &bob, 1000);
+    fund(&e, &payment_token, &carol, 1000);
+    let token = TokenClient::new(&e, &payment_token);
+    let asset = StellarAssetClient::new(&e, &payment_token);
+
+    e.as_contract(&marketplace, || {
+        create_auction(&e, &seller, &nft, token_id, &payment_token, 100, 1000);
+    });
+    e.as_contract(&marketplace, || bid(&e, &alice, &nft, token_id, 100));
+
+    // alice can no longer receive the payment token
+    asset.set_authorized(&alice, &false);
+
+    e.as_contract(&marketplace, || bid(&e, &bob, &nft, token_id, 200));
+    e.as_contract(&marketplace, || bid(&e, &carol, &nft, token_id, 300));
+    e.as_contract(&marketplace, || {
+        assert_eq!(refund(&e, &alice, &payment_token), 100);
+        assert_eq!(refund(&e, &bob, &payment_token), 200);
+        assert_eq!(auction(&e, &nft, token_id).unwrap().highest_bidder, Some(carol.clone()));
+    });
+
+    asset.set_authorized(&alice, &true);
+    e.as_contract(&marketplace, || withdraw_refund(&e, &alice, &payment_token));
+    assert_eq!(token.balance(&alice), 1000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #811)")]
+fn withdraw_without_refund_panics() {
+    let Setup { e, marketplace, payment_token, .. } = setup();
+    let alice = Address::generate(&e);
+
+    e.as_contract(&marketplace, || withdraw_refund(&e, &alice, &payment_token));
+}
+
+#[test]
+fn auction_without_bids_returns_token() {
+    let Setup { e, marketplace, nft, payment_token, seller, token_id, .. } = setup();
+
+    e.as_contract(&marketplace, || {
+        create_auction(&e, &seller, &nft, token_id, &payment_token, 100, 1000);
+    });
+
+    e.ledger().set_timestamp(1000);
+    e.as_contract(&marketplace, || {
+        settle_auction(&e, &nft, token_id);
+    });
+
+    assert_eq!(MockRoyaltyCollectionClient::new(&e, &nft).owner_of(&token_id), seller);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #808)")]
+fn bid_not_higher_panics() {
+    let Setup { e, marketplace, nft, payment_token, seller, token_id, .. } = setup();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    fund(&e, &payment_token, &alice, 1000);
+    fund(&e, &payment_token, &bob, 1000);
+
+    e.as_contract(&marketplace, || {
+        create_auction(&e, &seller, &nft, token_id, &payment_token, 100, 1000);
+    });
+    e.as_contract(&marketplace, || bid(&e, &alice, &nft, token_id, 200));
+    e.as_contract(&marketplace, || bid(&e, &bob, &nft, token_id, 200));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #806)")]
+fn bid_after_end_panics() {
+    let Setup { e, marketplace, nft, payment_token, seller, token_id, .. } = setup();
+    let alice = Address::generate(&e);
+    fund(&e, &payment_token, &alice, 1000);
+
+    e.as_contract(&marketplace, || {
+        create_auction(&e, &seller, &nft, token_id, &payment_token, 100, 1000);
+    });
+    e.ledger().set_timestamp(1000);
+    e.as_contract(&marketplace, || bid(&e, &alice, &nft, token_id, 200));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #807)")]
+fn settle_before_end_panics() {
+    let Setup { e, marketplace, nft, payment_token, seller, token_id, .. } = setup();
+
+    e.as_contract(&marketplace, || {
+        create_auction(&e, &seller, &nft, token_id, &payment_token, 100, 1000);
+    });
+    e.as_contract(&marketplace, || settle_auction(&e, &nft, token_id));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #809)")]
+fn cancel_auction_with_bids_panics() {
+    let Setup { e, marketplace, nft, payment_token, seller, token_id, .. } = setup();
+    let alice = Address::generate(&e);
+    fund(&e, &payment_token, &alice, 1000);
+
+    e.as_contract(&marketplace, || {
+        create_auction(&e, &seller, &nft, token_id, &payment_token, 100, 1000);
+    });
+    e.as_contract(&marketplace, || bid(&e, &alice, &nft, token_id, 200));
+    e.as_contract(&marketplace, || cancel_auction(&e, &nft, token_id));
+}
@@ -0,0 +1,192 @@
+mod storage;
+use crate::multi_token::{BurnableOverrides, MultiToken};
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contractevent, contracttrait, Address, Env, Vec};
+
+/// Burnable Trait for Multi Token
+///
+/// The `MultiTokenBurnable` trait extends the `MultiToken` trait to provide
+/// the capability to burn tokens. This trait is designed to be used in
+/// conjunction with the `MultiToken` trait.
+///
+/// `storage.rs` file of this module provides the `MultiTokenBurnable` trait
+/// implementation for the `Base` contract type. For the `Supply` contract
+/// type, the overrides of the `MultiTokenBurnable` trait methods can be found
+/// in its respective `storage.rs` file.
+///
+/// ```ignore
+/// impl MultiTokenBurnable for ExampleContract {
+///     fn burn(e: &Env, from: Address, token_id: u32, amount: i128) {
+///         Self::ContractType::burn(e, &from, token_id, amount);
+///     }
+///
+///     /* and so on */
+/// }
+/// ```
+#[contracttrait]
+pub trait MultiTokenBurnable: MultiToken<ContractType: BurnableOverrides> {
+    /// Destroys `amount` of tokens of type `token_id` from `from`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The account whose tokens are destroyed.
+    /// * `token_id` - The identifier of the token type.
+    /// * `amount` - The amount of tokens to burn.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::multi_token::MultiTokenError::InsufficientBalance`] - When
+    ///   attempting to burn more tokens than `from` current balance.
+    /// * [`crate::multi_token::MultiTokenError::LessThanZero`] - When `amount <
+    ///   0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[token_id: u32, amount: i128]`
+    fn burn(e: &Env, from: Address, token_id: u32, amount: i128) {
+        Self::ContractType::burn(e, &from, token_id, amount);
+    }
+
+    /// Destroys `amount` of tokens of type `token_id` from `from`, on behalf
+    /// of `from` by an approved operator `spender`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorizing the burn.
+    /// * `from` - The account whose tokens are destroyed.
+    /// * `token_id` - The identifier of the token type.
+    /// * `amount` - The amount of tokens to burn.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::multi_token::MultiTokenError::InsufficientApproval`] - When
+    ///   `spender` is neither `from` nor an approved operator of `from`.
+    /// * [`crate::multi_token::MultiTokenError::InsufficientBalance`] - When
+    ///   attempting to burn more tokens than `from` current balance.
+    /// * [`crate::multi_token::MultiTokenError::LessThanZero`] - When `amount <
+    ///   0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[token_id: u32, amount: i128]`
+    fn burn_from(e: &Env, spender: Address, from: Address, token_id: u32, amount: i128) {
+        Self::ContractType::burn_from(e, &spender, &from, token_id, amount);
+    }
+
+    /// Destroys `amounts` of tokens of types `token_ids` from `from`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The account whose tokens are destroyed.
+    /// * `token_ids` - The identifiers of the token types.
+    /// * `amounts` - The amounts of tokens to burn, one per token type.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::multi_token::MultiTokenError::LengthMismatch`] - When
+    ///   `token_ids` and `amounts` have different lengths.
+    /// * [`crate::multi_token::MultiTokenError::InsufficientBalance`] - When
+    ///   attempting to burn more tokens than `from` current balance.
+    /// * [`crate::multi_token::MultiTokenError::LessThanZero`] - When an amount
+    ///   is negative.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn_batch", from: Address]`
+    /// * data - `[token_ids: Vec<u32>, amounts: Vec<i128>]`
+    fn burn_batch(e: &Env, from: Address, token_ids: Vec<u32>, amounts: Vec<i128>) {
+        Self::ContractType::burn_batch(e, &from, &token_ids, &amounts);
+    }
+
+    /// Destroys `amounts` of tokens of types `token_ids` from `from`, on
+    /// behalf of `from` by an approved operator `spender`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorizing the burn.
+    /// * `from` - The account whose tokens are destroyed.
+    /// * `token_ids` - The identifiers of the token types.
+    /// * `amounts` - The amounts of tokens to burn, one per token type.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::multi_token::MultiTokenError::InsufficientApproval`] - When
+    ///   `spender` is neither `from` nor an approved operator of `from`.
+    /// * [`crate::multi_token::MultiTokenError::LengthMismatch`] - When
+    ///   `token_ids` and `amounts` have different lengths.
+    /// * [`crate::multi_token::MultiTokenError::InsufficientBalance`] - When
+    ///   attempting to burn more tokens than `from` current balance.
+    /// * [`crate::multi_token::MultiTokenError::LessThanZero`] - When an amount
+    ///   is negative.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn_batch", from: Address]`
+    /// * data - `[token_ids: Vec<u32>, amounts: Vec<i128>]`
+    fn burn_batch_from(
+        e: &Env,
+        spender: Address,
+        from: Address,
+        token_ids: Vec<u32>,
+        amounts: Vec<i128>,
+    ) {
+        Self::ContractType::burn_batch_from(e, &spender, &from, &token_ids, &amounts);
+    }
+}
+
+// ################## EVENTS ##################
+
+/// Event emitted when tokens of a single type are burned.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Burn {
+    #[topic]
+    pub from: Address,
+    pub token_id: u32,
+    pub amount: i128,
+}
+
+/// Emits an event for a burn of `amount` tokens of type `token_id` from
+/// `from`.
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `token_id` - The identifier of the token type.
+/// * `amount` - The amount of tokens burned.
+pub fn emit_burn(e: &Env, from: &Address, token_id: u32, amount: i128) {
+    Burn { from: from.clone(), token_id, amount }.publish(e);
+}
+
+/// Event emitted when tokens of multiple types are burned.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BurnBatch {
+    #[topic]
+    pub from: Address,
+    pub token_ids: Vec<u32>,
+    pub amounts: Vec<i128>,
+}
+
+/// Emits an event for a batch burn of tokens from `from`.
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `token_ids` - The identifiers of the token types.
+/// * `amounts` - The amounts of tokens burned.
+pub fn emit_burn_batch(e: &Env, from: &Address, token_ids: &Vec<u32>, amounts: &Vec<i128>) {
+    BurnBatch { from: from.clone(), token_ids: token_ids.clone(), amounts: amounts.clone() }
+        .publish(e);
+}
@@ -0,0 +1,132 @@
+use soroban_sdk::{vec, Address, Env, Vec};
+
+use crate::multi_token::{
+    burnable::{emit_burn, emit_burn_batch},
+    Base,
+};
+
+impl Base {
+    /// Destroys `amount` of tokens of type `token_id` from `from`, and emits a
+    /// `burn` event.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The account whose tokens are destroyed.
+    /// * `token_id` - The identifier of the token type.
+    /// * `amount` - The amount of tokens to burn.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[token_id: u32, amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn burn(e: &Env, from: &Address, token_id: u32, amount: i128) {
+        from.require_auth();
+        Base::update(e, Some(from), None, &vec![e, token_id], &vec![e, amount]);
+        emit_burn(e, from, token_id, amount);
+    }
+
+    /// Destroys `amount` of tokens of type `token_id` from `from`, ensuring
+    /// approval checks, and emits a `burn` event.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorizing the burn.
+    /// * `from` - The account whose tokens are destroyed.
+    /// * `token_id` - The identifier of the token type.
+    /// * `amount` - The amount of tokens to burn.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::check_spender_approval`] errors.
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[token_id: u32, amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `spender` is required.
+    pub fn burn_from(e: &Env, spender: &Address, from: &Address, token_id: u32, amount: i128) {
+        spender.require_auth();
+        Base::check_spender_approval(e, spender, from);
+        Base::update(e, Some(from), None, &vec![e, token_id], &vec![e, amount]);
+        emit_burn(e, from, token_id, amount);
+    }
+
+    /// Destroys `amounts` of tokens of types `token_ids` from `from`, and
+    /// emits a `burn_batch` event.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The account whose tokens are destroyed.
+    /// * `token_ids` - The identifiers of the token types.
+    /// * `amounts` - The amounts of tokens to burn.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn_batch", from: Address]`
+    /// * data - `[token_ids: Vec<u32>, amounts: Vec<i128>]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn burn_batch(e: &Env, from: &Address, token_ids: &Vec<u32>, amounts: &Vec<i128>) {
+        from.require_auth();
+        Base::update(e, Some(from), None, token_ids, amounts);
+        emit_burn_batch(e, from, token_ids, amounts);
+    }
+
+    /// Destroys `amounts` of tokens of types `token_ids` from `from`, ensuring
+    /// approval checks, and emits a `burn_batch` event.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorizing the burn.
+    /// * `from` - The account whose tokens are destroyed.
+    /// * `token_ids` - The identifiers of the token types.
+    /// * `amounts` - The amounts of tokens to burn.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::check_spender_approval`] errors.
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn_batch", from: Address]`
+    /// * data - `[token_ids: Vec<u32>, amounts: Vec<i128>]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `spender` is required.
+    pub fn burn_batch_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        token_ids: &Vec<u32>,
+        amounts: &Vec<i128>,
+    ) {
+        spender.require_auth();
+        Base::check_spender_approval(e, spender, from);
+        Base::update(e, Some(from), None, token_ids, amounts);
+        emit_burn_batch(e, from, token_ids, amounts);
+    }
+}
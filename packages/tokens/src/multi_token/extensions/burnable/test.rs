@@ -0,0 +1,97 @@
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, vec, Address, Env};
+use stellar_event_assertion::EventAssertion;
+
+use crate::multi_token::Base;
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn burn_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1, 100);
+        Base::burn(&e, &owner, 1, 40);
+
+        assert_eq!(Base::balance(&e, &owner, 1), 60);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+    });
+}
+
+#[test]
+fn burn_batch_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint_batch(&e, &owner, &vec![&e, 1, 2], &vec![&e, 100, 5]);
+        Base::burn_batch(&e, &owner, &vec![&e, 1, 2], &vec![&e, 100, 2]);
+
+        assert_eq!(Base::balance(&e, &owner, 1), 0);
+        assert_eq!(Base::balance(&e, &owner, 2), 3);
+    });
+}
+
+#[test]
+fn burn_from_with_operator_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint_batch(&e, &owner, &vec![&e, 1, 2], &vec![&e, 100, 5]);
+        Base::approve_for_all(&e, &owner, &operator, 1000);
+    });
+
+    e.as_contract(&address, || {
+        Base::burn_from(&e, &operator, &owner, 1, 10);
+    });
+
+    e.as_contract(&address, || {
+        Base::burn_batch_from(&e, &operator, &owner, &vec![&e, 1, 2], &vec![&e, 10, 5]);
+
+        assert_eq!(Base::balance(&e, &owner, 1), 80);
+        assert_eq!(Base::balance(&e, &owner, 2), 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #700)")]
+fn burn_insufficient_balance_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1, 10);
+        Base::burn(&e, &owner, 1, 11);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #702)")]
+fn burn_from_without_approval_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1, 10);
+        Base::burn_from(&e, &spender, &owner, 1, 1);
+    });
+}
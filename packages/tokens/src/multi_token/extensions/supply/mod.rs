@@ -0,0 +1,62 @@
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contracttrait, Env};
+pub use storage::{MultiTokenSupplyStorageKey, Supply};
+
+use crate::multi_token::MultiToken;
+
+/// Supply Trait for Multi Token
+///
+/// The `MultiTokenSupply` trait extends the `MultiToken` trait to keep track
+/// of the total supply of every token type, as well as the total supply across
+/// all token types.
+///
+/// The supply is updated whenever tokens are minted or burned through the
+/// functions of the [`Supply`] contract type, e.g. [`Supply::mint`],
+/// [`Supply::mint_batch`] and the overrides of
+/// [`crate::multi_token::BurnableOverrides`].
+///
+/// This trait is designed to be used in conjunction with the `MultiToken`
+/// trait, and is compatible with the
+/// [`crate::multi_token::burnable::MultiTokenBurnable`] trait.
+///
+/// # Notes
+///
+/// Minting or burning with [`crate::multi_token::Base`] functions bypasses
+/// the supply tracking. Always use the [`Supply`] counterparts when this
+/// extension is enabled.
+#[contracttrait]
+pub trait MultiTokenSupply: MultiToken<ContractType = Supply> {
+    /// Returns the total amount of tokens of type `token_id` in circulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token type.
+    fn total_supply(e: &Env, token_id: u32) -> i128 {
+        Supply::total_supply(e, token_id)
+    }
+
+    /// Returns the total amount of tokens in circulation, across all token
+    /// types.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn total_supply_all(e: &Env) -> i128 {
+        Supply::total_supply_all(e)
+    }
+
+    /// Returns whether any token of type `token_id` is in circulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token type.
+    fn exists(e: &Env, token_id: u32) -> bool {
+        Supply::exists(e, token_id)
+    }
+}
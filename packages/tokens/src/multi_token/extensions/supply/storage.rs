@@ -0,0 +1,330 @@
+use soroban_sdk::{contracttype, panic_with_error, vec, Address, Env, Vec};
+
+use crate::multi_token::{
+    burnable::{emit_burn, emit_burn_batch},
+    emit_mint, emit_mint_batch, Base, BurnableOverrides, ContractOverrides, MultiTokenError,
+    BALANCE_EXTEND_AMOUNT, BALANCE_TTL_THRESHOLD,
+};
+
+pub struct Supply;
+
+// Transfers don't affect the supply, no override required.
+impl ContractOverrides for Supply {}
+
+impl BurnableOverrides for Supply {
+    fn burn(e: &Env, from: &Address, token_id: u32, amount: i128) {
+        Supply::burn(e, from, token_id, amount);
+    }
+
+    fn burn_from(e: &Env, spender: &Address, from: &Address, token_id: u32, amount: i128) {
+        Supply::burn_from(e, spender, from, token_id, amount);
+    }
+
+    fn burn_batch(e: &Env, from: &Address, token_ids: &Vec<u32>, amounts: &Vec<i128>) {
+        Supply::burn_batch(e, from, token_ids, amounts);
+    }
+
+    fn burn_batch_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        token_ids: &Vec<u32>,
+        amounts: &Vec<i128>,
+    ) {
+        Supply::burn_batch_from(e, spender, from, token_ids, amounts);
+    }
+}
+
+/// Storage keys for the data associated with the supply extension of
+/// `MultiToken`
+#[contracttype]
+pub enum MultiTokenSupplyStorageKey {
+    TotalSupply(u32 /* token_id */),
+    TotalSupplyAll,
+}
+
+impl Supply {
+    // ################## QUERY STATE ##################
+
+    /// Returns the total amount of tokens of type `token_id` in circulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token type.
+    pub fn total_supply(e: &Env, token_id: u32) -> i128 {
+        let key = MultiTokenSupplyStorageKey::TotalSupply(token_id);
+        if let Some(supply) = e.storage().persistent().get::<_, i128>(&key) {
+            e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+            supply
+        } else {
+            0
+        }
+    }
+
+    /// Returns the total amount of tokens in circulation, across all token
+    /// types.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn total_supply_all(e: &Env) -> i128 {
+        e.storage().instance().get(&MultiTokenSupplyStorageKey::TotalSupplyAll).unwrap_or(0)
+    }
+
+    /// Returns whether any token of type `token_id` is in circulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token type.
+    pub fn exists(e: &Env, token_id: u32) -> bool {
+        Supply::total_supply(e, token_id) > 0
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Creates `amount` of tokens of type `token_id` and assigns them to
+    /// `to`, updating the supply.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The address receiving the new tokens.
+    /// * `token_id` - The identifier of the token type.
+    /// * `amount` - The amount of tokens to mint.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Supply::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[token_id: u32, amount: i128]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can execute
+    /// minting operations.
+    pub fn mint(e: &Env, to: &Address, token_id: u32, amount: i128) {
+        Supply::update(e, None, Some(to), &vec![e, token_id], &vec![e, amount]);
+        emit_mint(e, to, token_id, amount);
+    }
+
+    /// Creates `amounts` of tokens of types `token_ids` and assigns them to
+    /// `to`, updating the supply.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The address receiving the new tokens.
+    /// * `token_ids` - The identifiers of the token types.
+    /// * `amounts` - The amounts of tokens to mint.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Supply::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint_batch", to: Address]`
+    /// * data - `[token_ids: Vec<u32>, amounts: Vec<i128>]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can execute
+    /// minting operations.
+    pub fn mint_batch(e: &Env, to: &Address, token_ids: &Vec<u32>, amounts: &Vec<i128>) {
+        Supply::update(e, None, Some(to), token_ids, amounts);
+        emit_mint_batch(e, to, token_ids, amounts);
+    }
+
+    /// Destroys `amount` of tokens of type `token_id` from `from`, updating
+    /// the supply.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The account whose tokens are destroyed.
+    /// * `token_id` - The identifier of the token type.
+    /// * `amount` - The amount of tokens to burn.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Supply::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[token_id: u32, amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn burn(e: &Env, from: &Address, token_id: u32, amount: i128) {
+        from.require_auth();
+        Supply::update(e, Some(from), None, &vec![e, token_id], &vec![e, amount]);
+        emit_burn(e, from, token_id, amount);
+    }
+
+    /// Destroys `amount` of tokens of type `token_id` from `from`, ensuring
+    /// approval checks and updating the supply.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorizing the burn.
+    /// * `from` - The account whose tokens are destroyed.
+    /// * `token_id` - The identifier of the token type.
+    /// * `amount` - The amount of tokens to burn.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::check_spender_approval`] errors.
+    /// * refer to [`Supply::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[token_id: u32, amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `spender` is required.
+    pub fn burn_from(e: &Env, spender: &Address, from: &Address, token_id: u32, amount: i128) {
+        spender.require_auth();
+        Base::check_spender_approval(e, spender, from);
+        Supply::update(e, Some(from), None, &vec![e, token_id], &vec![e, amount]);
+        emit_burn(e, from, token_id, amount);
+    }
+
+    /// Destroys `amounts` of tokens of types `token_ids` from `from`, updating
+    /// the supply.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The account whose tokens are destroyed.
+    /// * `token_ids` - The identifiers of the token types.
+    /// * `amounts` - The amounts of tokens to burn.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Supply::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn_batch", from: Address]`
+    /// * data - `[token_ids: Vec<u32>, amounts: Vec<i128>]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn burn_batch(e: &Env, from: &Address, token_ids: &Vec<u32>, amounts: &Vec<i128>) {
+        from.require_auth();
+        Supply::update(e, Some(from), None, token_ids, amounts);
+        emit_burn_batch(e, from, token_ids, amounts);
+    }
+
+    /// Destroys `amounts` of tokens of types `token_ids` from `from`, ensuring
+    /// approval checks and updating the supply.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorizing the burn.
+    /// * `from` - The account whose tokens are destroyed.
+    /// * `token_ids` - The identifiers of the token types.
+    /// * `amounts` - The amounts of tokens to burn.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::check_spender_approval`] errors.
+    /// * refer to [`Supply::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn_batch", from: Address]`
+    /// * data - `[token_ids: Vec<u32>, amounts: Vec<i128>]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `spender` is required.
+    pub fn burn_batch_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        token_ids: &Vec<u32>,
+        amounts: &Vec<i128>,
+    ) {
+        spender.require_auth();
+        Base::check_spender_approval(e, spender, from);
+        Supply::update(e, Some(from), None, token_ids, amounts);
+        emit_burn_batch(e, from, token_ids, amounts);
+    }
+
+    /// Low-level function wrapping [`Base::update`], which additionally
+    /// increases the supply when minting (`from` is `None`) and decreases it
+    /// when burning (`to` is `None`).
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens. `None` when minting.
+    /// * `to` - The address receiving the tokens. `None` when burning.
+    /// * `token_ids` - The identifiers of the token types.
+    /// * `amounts` - The amounts of tokens, one per token type.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::update`] errors.
+    /// * [`MultiTokenError::MathOverflow`] - When the supply overflows.
+    pub fn update(
+        e: &Env,
+        from: Option<&Address>,
+        to: Option<&Address>,
+        token_ids: &Vec<u32>,
+        amounts: &Vec<i128>,
+    ) {
+        Base::update(e, from, to, token_ids, amounts);
+
+        if from.is_some() == to.is_some() {
+            return;
+        }
+
+        let mut total_supply_all = Supply::total_supply_all(e);
+        for (token_id, amount) in token_ids.iter().zip(amounts.iter()) {
+            let supply = Supply::total_supply(e, token_id);
+            let new_supply = if from.is_none() {
+                let Some(new_supply) = supply.checked_add(amount) else {
+                    panic_with_error!(e, MultiTokenError::MathOverflow);
+                };
+                let Some(new_total) = total_supply_all.checked_add(amount) else {
+                    panic_with_error!(e, MultiTokenError::MathOverflow);
+                };
+                total_supply_all = new_total;
+                new_supply
+            } else {
+                // NOTE: can't underflow, because the balance of `from`, which is
+                // part of the supply, was already decreased.
+                total_supply_all -= amount;
+                supply - amount
+            };
+
+            let key = MultiTokenSupplyStorageKey::TotalSupply(token_id);
+            if new_supply == 0 {
+                e.storage().persistent().remove(&key);
+            } else {
+                e.storage().persistent().set(&key, &new_supply);
+            }
+        }
+        e.storage().instance().set(&MultiTokenSupplyStorageKey::TotalSupplyAll, &total_supply_all);
+    }
+}
@@ -0,0 +1,83 @@
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, vec, Address, Env};
+
+use crate::multi_token::{supply::Supply, Base, BurnableOverrides, ContractOverrides};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn mint_tracks_supply() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        assert!(!Supply::exists(&e, 1));
+
+        Supply::mint(&e, &alice, 1, 100);
+        Supply::mint_batch(&e, &bob, &vec![&e, 1, 2], &vec![&e, 50, 7]);
+
+        assert!(Supply::exists(&e, 1));
+        assert_eq!(Supply::total_supply(&e, 1), 150);
+        assert_eq!(Supply::total_supply(&e, 2), 7);
+        assert_eq!(Supply::total_supply_all(&e), 157);
+        assert_eq!(Base::balance(&e, &bob, 1), 50);
+    });
+}
+
+#[test]
+fn burn_tracks_supply() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Supply::mint_batch(&e, &owner, &vec![&e, 1, 2], &vec![&e, 100, 5]);
+        <Supply as BurnableOverrides>::burn(&e, &owner, 1, 30);
+    });
+
+    e.as_contract(&address, || {
+        <Supply as BurnableOverrides>::burn_batch(&e, &owner, &vec![&e, 1, 2], &vec![&e, 70, 1]);
+
+        assert!(!Supply::exists(&e, 1));
+        assert_eq!(Supply::total_supply(&e, 1), 0);
+        assert_eq!(Supply::total_supply(&e, 2), 4);
+        assert_eq!(Supply::total_supply_all(&e), 4);
+    });
+}
+
+#[test]
+fn transfer_keeps_supply() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Supply::mint(&e, &owner, 1, 100);
+        <Supply as ContractOverrides>::transfer(&e, &owner, &recipient, 1, 60);
+
+        assert_eq!(Supply::total_supply(&e, 1), 100);
+        assert_eq!(Supply::total_supply_all(&e), 100);
+        assert_eq!(Base::balance(&e, &recipient, 1), 60);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #704)")]
+fn mint_supply_overflow_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Supply::mint(&e, &alice, 1, i128::MAX);
+        Supply::mint(&e, &bob, 1, 1);
+    });
+}
@@ -0,0 +1,513 @@
+//! # Multi Token Contract Module.
+//!
+//! Implements utilities for handling multiple token types in a single Soroban
+//! contract, each identified by a `token_id`. A token type can be fungible
+//! (e.g. an in-game currency), non-fungible (a token type with a supply of
+//! `1`) or semi-fungible (e.g. a limited edition of game items).
+//!
+//! ## Design Overview
+//!
+//! This module is structured to provide flexibility to developers by splitting
+//! functionalities into higher-level and lower-level operations:
+//!
+//! - **High-Level Functions**: These include all necessary checks,
+//!   verifications, authorizations, state-changing logic, and event emissions.
+//!   They simplify usage by handling core logic securely. Users can directly
+//!   call these functions for typical token operations without worrying about
+//!   implementation details.
+//!
+//! - **Low-Level Functions**: These offer granular control for developers who
+//!   need to compose their own workflows. Such functions expose internal
+//!   mechanisms and require the caller to handle verifications and
+//!   authorizations manually.
+//!
+//! By offering this dual-layered approach, developers can choose between
+//! convenience and customization, depending on their project requirements.
+//!
+//! ## Structure
+//!
+//! The base module includes:
+//!
+//! - Balances per account and `token_id`
+//! - Single and batch transfers
+//! - Operator approval management
+//! - Metadata management (`uri`)
+//!
+//! The following optional extensions are available:
+//!
+//! - *Burnable* enables token holders to destroy their tokens.
+//! - *Supply* tracks the total supply of every `token_id`, as well as the total
+//!   supply across all token ids.
+//!
+//! ## Compatibility and Compliance
+//!
+//! The ERC-1155 interface is adapted to Stellar Ecosystem, with the following
+//! differences:
+//!
+//! - `transfer()` and `batch_transfer()` functions are made available for
+//!   consistency with the Fungible and NonFungible Token interfaces.
+//! - Amounts are represented as `i128`, consistently with SEP-41.
+//! - Operator approvals expire at `live_until_ledger`, consistently with the
+//!   NonFungible Token interface.
+//! - Acceptance checks on the recipient (`onERC1155Received`) are not
+//!   performed, as Soroban does not allow re-entrant calls into the token
+//!   contract.
+//!
+//! ## Notes for Developers
+//!
+//! - **Security Considerations**: While high-level functions handle necessary
+//!   checks, users of low-level functions must take extra care to ensure
+//!   correctness and security.
+//! - **Composable Design**: The modular structure encourages developers to
+//!   extend functionality by combining provided primitives or creating custom
+//!   extensions.
+
+mod extensions;
+mod overrides;
+mod storage;
+
+#[cfg(test)]
+mod test;
+
+pub use extensions::{burnable, supply};
+pub use overrides::{Base, BurnableOverrides, ContractOverrides};
+// ################## TRAIT ##################
+use soroban_sdk::{contracterror, contractevent, contracttrait, Address, Env, String, Vec};
+pub use storage::MultiTokenStorageKey;
+
+/// Vanilla Multi Token Trait
+///
+/// The `MultiToken` trait defines the core functionality for contracts
+/// managing multiple token types. It provides a standard interface for
+/// querying balances, transferring tokens, individually or in batches, and
+/// managing operator approvals.
+///
+/// Events for `mint` and `mint_batch` are defined, but the functions
+/// themselves are not included as methods in this trait because they are not
+/// a part of the standard, the function signature may change depending on the
+/// implementation. We do provide [`crate::multi_token::Base::mint`] and
+/// [`crate::multi_token::Base::mint_batch`] functions.
+///
+/// This trait is implemented for the following Contract Types:
+/// * [`crate::multi_token::Base`] (covering the vanilla case, and compatible
+///   with [`crate::multi_token::extensions::burnable::MultiTokenBurnable`])
+///   trait
+/// * [`crate::multi_token::extensions::supply::Supply`] (enabling the
+///   compatibility and overrides for
+///   [`crate::multi_token::extensions::supply::MultiTokenSupply`]) trait,
+///   compatible with
+///   [`crate::multi_token::extensions::burnable::MultiTokenBurnable`].
+///
+/// You can find the default implementations of this trait for `Base` and
+/// `Supply`, by navigating to: `ContractType::{method_name}`.
+#[contracttrait]
+pub trait MultiToken {
+    /// Helper type that allows us to override some of the functionality of the
+    /// base trait based on the extensions implemented. You should use
+    /// [`crate::multi_token::Base`] as the type if you are not using
+    /// [`crate::multi_token::extensions::supply::Supply`] extension.
+    type ContractType: ContractOverrides;
+
+    /// Returns the amount of tokens of type `token_id` owned by `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the balance is being queried.
+    /// * `token_id` - The identifier of the token type.
+    fn balance(e: &Env, account: Address, token_id: u32) -> i128 {
+        Self::ContractType::balance(e, &account, token_id)
+    }
+
+    /// Returns the balances of multiple `(account, token_id)` pairs.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `accounts` - The addresses for which the balances are being queried.
+    /// * `token_ids` - The identifiers of the token types, one per account.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::LengthMismatch`] - When `accounts` and `token_ids`
+    ///   have different lengths.
+    fn balance_of_batch(e: &Env, accounts: Vec<Address>, token_ids: Vec<u32>) -> Vec<i128> {
+        Self::ContractType::balance_of_batch(e, &accounts, &token_ids)
+    }
+
+    /// Transfers `amount` of tokens of type `token_id` from `from` to `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `token_id` - The identifier of the token type.
+    /// * `amount` - The amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::InsufficientBalance`] - When attempting to transfer
+    ///   more tokens than `from` current balance.
+    /// * [`MultiTokenError::LessThanZero`] - When `amount < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: u32, amount: i128]`
+    fn transfer(e: &Env, from: Address, to: Address, token_id: u32, amount: i128) {
+        Self::ContractType::transfer(e, &from, &to, token_id, amount);
+    }
+
+    /// Transfers `amount` of tokens of type `token_id` from `from` to `to`,
+    /// on behalf of `from` by an approved operator `spender`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorizing the transfer.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `token_id` - The identifier of the token type.
+    /// * `amount` - The amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::InsufficientApproval`] - When `spender` is neither
+    ///   `from` nor an approved operator of `from`.
+    /// * [`MultiTokenError::InsufficientBalance`] - When attempting to transfer
+    ///   more tokens than `from` current balance.
+    /// * [`MultiTokenError::LessThanZero`] - When `amount < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: u32, amount: i128]`
+    fn transfer_from(
+        e: &Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        token_id: u32,
+        amount: i128,
+    ) {
+        Self::ContractType::transfer_from(e, &spender, &from, &to, token_id, amount);
+    }
+
+    /// Transfers `amounts` of tokens of types `token_ids` from `from` to
+    /// `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `token_ids` - The identifiers of the token types.
+    /// * `amounts` - The amounts of tokens to be transferred, one per token
+    ///   type.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::LengthMismatch`] - When `token_ids` and `amounts`
+    ///   have different lengths.
+    /// * [`MultiTokenError::InsufficientBalance`] - When attempting to transfer
+    ///   more tokens than `from` current balance.
+    /// * [`MultiTokenError::LessThanZero`] - When an amount is negative.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer_batch", from: Address, to: Address]`
+    /// * data - `[token_ids: Vec<u32>, amounts: Vec<i128>]`
+    fn batch_transfer(
+        e: &Env,
+        from: Address,
+        to: Address,
+        token_ids: Vec<u32>,
+        amounts: Vec<i128>,
+    ) {
+        Self::ContractType::batch_transfer(e, &from, &to, &token_ids, &amounts);
+    }
+
+    /// Transfers `amounts` of tokens of types `token_ids` from `from` to `to`,
+    /// on behalf of `from` by an approved operator `spender`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorizing the transfer.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `token_ids` - The identifiers of the token types.
+    /// * `amounts` - The amounts of tokens to be transferred, one per token
+    ///   type.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::InsufficientApproval`] - When `spender` is neither
+    ///   `from` nor an approved operator of `from`.
+    /// * [`MultiTokenError::LengthMismatch`] - When `token_ids` and `amounts`
+    ///   have different lengths.
+    /// * [`MultiTokenError::InsufficientBalance`] - When attempting to transfer
+    ///   more tokens than `from` current balance.
+    /// * [`MultiTokenError::LessThanZero`] - When an amount is negative.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer_batch", from: Address, to: Address]`
+    /// * data - `[token_ids: Vec<u32>, amounts: Vec<i128>]`
+    fn batch_transfer_from(
+        e: &Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        token_ids: Vec<u32>,
+        amounts: Vec<i128>,
+    ) {
+        Self::ContractType::batch_transfer_from(e, &spender, &from, &to, &token_ids, &amounts);
+    }
+
+    /// Approve or remove `operator` as an operator for the owner.
+    ///
+    /// Operators can call `transfer_from()` and `batch_transfer_from()` for
+    /// any token held by `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `operator` - Account to add to the set of authorized operators.
+    /// * `live_until_ledger` - The ledger number at which the approval expires.
+    ///   If `live_until_ledger` is `0`, the approval is revoked.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::InvalidLiveUntilLedger`] - If the ledger number is
+    ///   less than the current ledger number.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["approve_for_all", owner: Address]`
+    /// * data - `[operator: Address, live_until_ledger: u32]`
+    fn approve_for_all(e: &Env, owner: Address, operator: Address, live_until_ledger: u32) {
+        Self::ContractType::approve_for_all(e, &owner, &operator, live_until_ledger);
+    }
+
+    /// Returns whether the `operator` is allowed to manage all the assets of
+    /// `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - Account of the token's owner.
+    /// * `operator` - Account to be checked.
+    fn is_approved_for_all(e: &Env, owner: Address, operator: Address) -> bool {
+        Self::ContractType::is_approved_for_all(e, &owner, &operator)
+    }
+
+    /// Returns the Uniform Resource Identifier (URI) for the token type
+    /// `token_id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token type.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::UnsetMetadata`] - When neither the URI of
+    ///   `token_id` nor the base URI is set.
+    fn uri(e: &Env, token_id: u32) -> String {
+        Self::ContractType::uri(e, token_id)
+    }
+}
+
+// ################## ERRORS ##################
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MultiTokenError {
+    /// Indicates an error related to the current balance of account from which
+    /// tokens are expected to be transferred.
+    InsufficientBalance = 700,
+    /// Indicates an invalid amount, e.g. when the amount is negative.
+    LessThanZero = 701,
+    /// Indicates a failure with the `spender`s approval. Used in transfers.
+    InsufficientApproval = 702,
+    /// Indicates an invalid value for `live_until_ledger` when setting
+    /// approvals.
+    InvalidLiveUntilLedger = 703,
+    /// Indicates overflow when adding two values.
+    MathOverflow = 704,
+    /// Indicates arrays of token ids and amounts (or accounts) of different
+    /// lengths.
+    LengthMismatch = 705,
+    /// Indicates access to unset metadata.
+    UnsetMetadata = 706,
+    /// Indicates the length of the URI exceeds the maximum allowed.
+    UriMaxLenExceeded = 707,
+}
+
+// ################## CONSTANTS ##################
+
+const DAY_IN_LEDGERS: u32 = 17280;
+pub const BALANCE_EXTEND_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const BALANCE_TTL_THRESHOLD: u32 = BALANCE_EXTEND_AMOUNT - DAY_IN_LEDGERS;
+pub const METADATA_EXTEND_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const METADATA_TTL_THRESHOLD: u32 = METADATA_EXTEND_AMOUNT - DAY_IN_LEDGERS;
+
+/// Max. allowed length for a URI.
+pub const MAX_URI_LEN: usize = 200;
+
+// ################## EVENTS ##################
+
+/// Event emitted when tokens of a single type are transferred.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Transfer {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub token_id: u32,
+    pub amount: i128,
+}
+
+/// Emits an event for a transfer of `amount` tokens of type `token_id` from
+/// `from` to `to`.
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+/// * `from` - The sender address.
+/// * `to` - The recipient address.
+/// * `token_id` - The identifier of the token type.
+/// * `amount` - The amount of tokens transferred.
+pub fn emit_transfer(e: &Env, from: &Address, to: &Address, token_id: u32, amount: i128) {
+    Transfer { from: from.clone(), to: to.clone(), token_id, amount }.publish(e);
+}
+
+/// Event emitted when tokens of multiple types are transferred.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferBatch {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub token_ids: Vec<u32>,
+    pub amounts: Vec<i128>,
+}
+
+/// Emits an event for a batch transfer of tokens from `from` to `to`.
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+/// * `from` - The sender address.
+/// * `to` - The recipient address.
+/// * `token_ids` - The identifiers of the token types.
+/// * `amounts` - The amounts of tokens transferred.
+pub fn emit_transfer_batch(
+    e: &Env,
+    from: &Address,
+    to: &Address,
+    token_ids: &Vec<u32>,
+    amounts: &Vec<i128>,
+) {
+    TransferBatch {
+        from: from.clone(),
+        to: to.clone(),
+        token_ids: token_ids.clone(),
+        amounts: amounts.clone(),
+    }
+    .publish(e);
+}
+
+/// Event emitted when approval for all tokens is granted.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApproveForAll {
+    #[topic]
+    pub owner: Address,
+    pub operator: Address,
+    pub live_until_ledger: u32,
+}
+
+/// Emits an event when `owner` enables `operator` to manage all of its tokens
+/// until `live_until_ledger`.
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+/// * `owner` - The owner address.
+/// * `operator` - The operator address.
+/// * `live_until_ledger` - The ledger number until which the approval is valid.
+pub fn emit_approve_for_all(e: &Env, owner: &Address, operator: &Address, live_until_ledger: u32) {
+    ApproveForAll { owner: owner.clone(), operator: operator.clone(), live_until_ledger }
+        .publish(e);
+}
+
+/// Event emitted when tokens of a single type are minted.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Mint {
+    #[topic]
+    pub to: Address,
+    pub token_id: u32,
+    pub amount: i128,
+}
+
+/// Emits an event indicating a mint of `amount` tokens of type `token_id`.
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+/// * `to` - The recipient address.
+/// * `token_id` - The identifier of the token type.
+/// * `amount` - The amount of tokens minted.
+pub fn emit_mint(e: &Env, to: &Address, token_id: u32, amount: i128) {
+    Mint { to: to.clone(), token_id, amount }.publish(e);
+}
+
+/// Event emitted when tokens of multiple types are minted.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MintBatch {
+    #[topic]
+    pub to: Address,
+    pub token_ids: Vec<u32>,
+    pub amounts: Vec<i128>,
+}
+
+/// Emits an event indicating a batch mint of tokens.
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+/// * `to` - The recipient address.
+/// * `token_ids` - The identifiers of the token types.
+/// * `amounts` - The amounts of tokens minted.
+pub fn emit_mint_batch(e: &Env, to: &Address, token_ids: &Vec<u32>, amounts: &Vec<i128>) {
+    MintBatch { to: to.clone(), token_ids: token_ids.clone(), amounts: amounts.clone() }.publish(e);
+}
+
+/// Event emitted when the URI of a token type is set.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Uri {
+    #[topic]
+    pub token_id: u32,
+    pub uri: String,
+}
+
+/// Emits an event indicating the URI of `token_id` was set.
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+/// * `token_id` - The identifier of the token type.
+/// * `uri` - The new URI.
+pub fn emit_uri(e: &Env, token_id: u32, uri: &String) {
+    Uri { token_id, uri: uri.clone() }.publish(e);
+}
@@ -0,0 +1,121 @@
+use soroban_sdk::{Address, Env, String, Vec};
+
+/// Based on the extension, some default behavior of
+/// [`crate::multi_token::MultiToken`] might have to be overridden. This is a
+/// helper trait that allows us this override mechanism that favors the DevX.
+///
+/// It follows the same design as
+/// [`crate::non_fungible::ContractOverrides`]: the `MultiToken` trait calls
+/// every method on its associated `ContractType`, which provides either the
+/// overridden or the base variant according to the extension.
+///
+/// Example:
+///
+/// ```rust
+/// impl MultiToken for ExampleContract {
+///     type ContractType = Supply;
+///
+///     fn balance(e: &Env, account: Address, token_id: u32) -> i128 {
+///         Self::ContractType::balance(e, &account, token_id)
+///     }
+///
+///     fn transfer(e: &Env, from: Address, to: Address, token_id: u32, amount: i128) {
+///         Self::ContractType::transfer(e, &from, &to, token_id, amount);
+///     }
+///
+///     /* and so on */
+/// }
+/// ```
+pub trait ContractOverrides {
+    fn balance(e: &Env, account: &Address, token_id: u32) -> i128 {
+        Base::balance(e, account, token_id)
+    }
+
+    fn balance_of_batch(e: &Env, accounts: &Vec<Address>, token_ids: &Vec<u32>) -> Vec<i128> {
+        Base::balance_of_batch(e, accounts, token_ids)
+    }
+
+    fn transfer(e: &Env, from: &Address, to: &Address, token_id: u32, amount: i128) {
+        Base::transfer(e, from, to, token_id, amount);
+    }
+
+    fn transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        token_id: u32,
+        amount: i128,
+    ) {
+        Base::transfer_from(e, spender, from, to, token_id, amount);
+    }
+
+    fn batch_transfer(
+        e: &Env,
+        from: &Address,
+        to: &Address,
+        token_ids: &Vec<u32>,
+        amounts: &Vec<i128>,
+    ) {
+        Base::batch_transfer(e, from, to, token_ids, amounts);
+    }
+
+    fn batch_transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        token_ids: &Vec<u32>,
+        amounts: &Vec<i128>,
+    ) {
+        Base::batch_transfer_from(e, spender, from, to, token_ids, amounts);
+    }
+
+    fn approve_for_all(e: &Env, owner: &Address, operator: &Address, live_until_ledger: u32) {
+        Base::approve_for_all(e, owner, operator, live_until_ledger);
+    }
+
+    fn is_approved_for_all(e: &Env, owner: &Address, operator: &Address) -> bool {
+        Base::is_approved_for_all(e, owner, operator)
+    }
+
+    fn uri(e: &Env, token_id: u32) -> String {
+        Base::uri(e, token_id)
+    }
+}
+
+/// Default marker type
+pub struct Base;
+
+// No override required for the `Base` contract type.
+impl ContractOverrides for Base {}
+
+/// Burnable functionality
+///
+/// Trait for overriding the burn functions. The behavior of burning changes
+/// across implementations, i.e. supply, hence the need for an abstraction.
+pub trait BurnableOverrides {
+    fn burn(e: &Env, from: &Address, token_id: u32, amount: i128) {
+        Base::burn(e, from, token_id, amount);
+    }
+
+    fn burn_from(e: &Env, spender: &Address, from: &Address, token_id: u32, amount: i128) {
+        Base::burn_from(e, spender, from, token_id, amount);
+    }
+
+    fn burn_batch(e: &Env, from: &Address, token_ids: &Vec<u32>, amounts: &Vec<i128>) {
+        Base::burn_batch(e, from, token_ids, amounts);
+    }
+
+    fn burn_batch_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        token_ids: &Vec<u32>,
+        amounts: &Vec<i128>,
+    ) {
+        Base::burn_batch_from(e, spender, from, token_ids, amounts);
+    }
+}
+
+impl BurnableOverrides for Base {}
@@ -0,0 +1,537 @@
+use soroban_sdk::{contracttype, panic_with_error, vec, Address, Env, String, Vec};
+
+use crate::{
+    multi_token::{
+        emit_approve_for_all, emit_mint, emit_mint_batch, emit_transfer, emit_transfer_batch,
+        emit_uri, Base, MultiTokenError, BALANCE_EXTEND_AMOUNT, BALANCE_TTL_THRESHOLD, MAX_URI_LEN,
+        METADATA_EXTEND_AMOUNT, METADATA_TTL_THRESHOLD,
+    },
+    non_fungible,
+};
+
+/// Storage keys for the data associated with `MultiToken`
+#[contracttype]
+pub enum MultiTokenStorageKey {
+    Balance(Address, u32 /* token_id */),
+    ApprovalForAll(Address /* owner */, Address /* operator */),
+    BaseUri,
+    TokenUri(u32),
+}
+
+impl Base {
+    // ################## QUERY STATE ##################
+
+    /// Returns the amount of tokens of type `token_id` held by `account`.
+    /// Defaults to `0` if no balance is stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the balance is being queried.
+    /// * `token_id` - The identifier of the token type.
+    pub fn balance(e: &Env, account: &Address, token_id: u32) -> i128 {
+        let key = MultiTokenStorageKey::Balance(account.clone(), token_id);
+        if let Some(balance) = e.storage().persistent().get::<_, i128>(&key) {
+            e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+            balance
+        } else {
+            0
+        }
+    }
+
+    /// Returns the balances of multiple `(account, token_id)` pairs.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `accounts` - The addresses for which the balances are being queried.
+    /// * `token_ids` - The identifiers of the token types, one per account.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::LengthMismatch`] - When `accounts` and `token_ids`
+    ///   have different lengths.
+    pub fn balance_of_batch(e: &Env, accounts: &Vec<Address>, token_ids: &Vec<u32>) -> Vec<i128> {
+        if accounts.len() != token_ids.len() {
+            panic_with_error!(e, MultiTokenError::LengthMismatch);
+        }
+
+        let mut balances = Vec::new(e);
+        for (account, token_id) in accounts.iter().zip(token_ids.iter()) {
+            balances.push_back(Base::balance(e, &account, token_id));
+        }
+        balances
+    }
+
+    /// Returns whether the operator is allowed to manage all assets of the
+    /// owner:
+    /// * `true` - If the operator has a valid, non-expired approval for all
+    ///   tokens
+    /// * `false` - If there is no approval or if the approval has expired
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address that owns the tokens.
+    /// * `operator` - The address to check for approval status.
+    pub fn is_approved_for_all(e: &Env, owner: &Address, operator: &Address) -> bool {
+        let key = MultiTokenStorageKey::ApprovalForAll(owner.clone(), operator.clone());
+
+        match e.storage().temporary().get::<_, u32>(&key) {
+            Some(live_until_ledger) => live_until_ledger >= e.ledger().sequence(),
+            None => false,
+        }
+    }
+
+    /// Returns the base URI shared by all token types.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::UnsetMetadata`] - When the base URI is not set.
+    pub fn base_uri(e: &Env) -> String {
+        e.storage()
+            .instance()
+            .get(&MultiTokenStorageKey::BaseUri)
+            .unwrap_or_else(|| panic_with_error!(e, MultiTokenError::UnsetMetadata))
+    }
+
+    /// Returns the URI for the token type `token_id`. If a URI was set for
+    /// `token_id` with [`Base::set_token_uri`], it is returned as is.
+    /// Otherwise, the URI is composed by appending `token_id` to the base URI.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token type.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::UnsetMetadata`] - When neither the URI of
+    ///   `token_id` nor the base URI is set.
+    pub fn uri(e: &Env, token_id: u32) -> String {
+        let key = MultiTokenStorageKey::TokenUri(token_id);
+        if let Some(uri) = e.storage().persistent().get::<_, String>(&key) {
+            e.storage().persistent().extend_ttl(
+                &key,
+                METADATA_TTL_THRESHOLD,
+                METADATA_EXTEND_AMOUNT,
+            );
+            return uri;
+        }
+
+        non_fungible::Base::compose_uri_for_token(e, Base::base_uri(e), token_id)
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Transfers `amount` of tokens of type `token_id` from `from` to `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `token_id` - The identifier of the token type.
+    /// * `amount` - The amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: u32, amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn transfer(e: &Env, from: &Address, to: &Address, token_id: u32, amount: i128) {
+        from.require_auth();
+        Base::update(e, Some(from), Some(to), &vec![e, token_id], &vec![e, amount]);
+        emit_transfer(e, from, to, token_id, amount);
+    }
+
+    /// Transfers `amount` of tokens of type `token_id` from `from` to `to`,
+    /// on behalf of `from` by `spender`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorizing the transfer.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `token_id` - The identifier of the token type.
+    /// * `amount` - The amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::check_spender_approval`] errors.
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: u32, amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `spender` is required.
+    pub fn transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        token_id: u32,
+        amount: i128,
+    ) {
+        spender.require_auth();
+        Base::check_spender_approval(e, spender, from);
+        Base::update(e, Some(from), Some(to), &vec![e, token_id], &vec![e, amount]);
+        emit_transfer(e, from, to, token_id, amount);
+    }
+
+    /// Transfers `amounts` of tokens of types `token_ids` from `from` to
+    /// `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `token_ids` - The identifiers of the token types.
+    /// * `amounts` - The amounts of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer_batch", from: Address, to: Address]`
+    /// * data - `[token_ids: Vec<u32>, amounts: Vec<i128>]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn batch_transfer(
+        e: &Env,
+        from: &Address,
+        to: &Address,
+        token_ids: &Vec<u32>,
+        amounts: &Vec<i128>,
+    ) {
+        from.require_auth();
+        Base::update(e, Some(from), Some(to), token_ids, amounts);
+        emit_transfer_batch(e, from, to, token_ids, amounts);
+    }
+
+    /// Transfers `amounts` of tokens of types `token_ids` from `from` to `to`,
+    /// on behalf of `from` by `spender`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorizing the transfer.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `token_ids` - The identifiers of the token types.
+    /// * `amounts` - The amounts of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::check_spender_approval`] errors.
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer_batch", from: Address, to: Address]`
+    /// * data - `[token_ids: Vec<u32>, amounts: Vec<i128>]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `spender` is required.
+    pub fn batch_transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        token_ids: &Vec<u32>,
+        amounts: &Vec<i128>,
+    ) {
+        spender.require_auth();
+        Base::check_spender_approval(e, spender, from);
+        Base::update(e, Some(from), Some(to), token_ids, amounts);
+        emit_transfer_batch(e, from, to, token_ids, amounts);
+    }
+
+    /// Approves or revokes an operator to manage all tokens of the owner.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address granting approval for all their tokens.
+    /// * `operator` - The address being granted or revoked approval.
+    /// * `live_until_ledger` - The ledger number at which the allowance
+    ///   expires. If `live_until_ledger` is `0`, the approval is revoked.
+    ///   `live_until_ledger` argument is implicitly bounded by the maximum
+    ///   allowed TTL extension for a temporary storage entry and specifying a
+    ///   higher value will cause the code to panic.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::InvalidLiveUntilLedger`] - If the ledger number is
+    ///   less than the current ledger number.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["approve_for_all", owner: Address]`
+    /// * data - `[operator: Address, live_until_ledger: u32]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `owner` is required.
+    pub fn approve_for_all(e: &Env, owner: &Address, operator: &Address, live_until_ledger: u32) {
+        owner.require_auth();
+
+        let key = MultiTokenStorageKey::ApprovalForAll(owner.clone(), operator.clone());
+
+        if live_until_ledger == 0 {
+            e.storage().temporary().remove(&key);
+            emit_approve_for_all(e, owner, operator, live_until_ledger);
+            return;
+        }
+
+        let current_ledger = e.ledger().sequence();
+        if live_until_ledger < current_ledger {
+            panic_with_error!(e, MultiTokenError::InvalidLiveUntilLedger);
+        }
+
+        e.storage().temporary().set(&key, &live_until_ledger);
+
+        let live_for = live_until_ledger - current_ledger;
+        e.storage().temporary().extend_ttl(&key, live_for, live_for);
+
+        emit_approve_for_all(e, owner, operator, live_until_ledger);
+    }
+
+    /// Low-level function for handling transfers, mints and burns of multiple
+    /// token types, without handling authorization. Updates the balances of
+    /// `from` and `to` for every `(token_id, amount)` pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens. `None` when minting.
+    /// * `to` - The address receiving the tokens. `None` when burning.
+    /// * `token_ids` - The identifiers of the token types.
+    /// * `amounts` - The amounts of tokens, one per token type.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::LengthMismatch`] - When `token_ids` and `amounts`
+    ///   have different lengths.
+    /// * [`MultiTokenError::LessThanZero`] - When an amount is negative.
+    /// * [`MultiTokenError::InsufficientBalance`] - When `from` doesn't hold
+    ///   enough tokens.
+    /// * [`MultiTokenError::MathOverflow`] - When the balance of `to`
+    ///   overflows.
+    pub fn update(
+        e: &Env,
+        from: Option<&Address>,
+        to: Option<&Address>,
+        token_ids: &Vec<u32>,
+        amounts: &Vec<i128>,
+    ) {
+        if token_ids.len() != amounts.len() {
+            panic_with_error!(e, MultiTokenError::LengthMismatch);
+        }
+
+        for (token_id, amount) in token_ids.iter().zip(amounts.iter()) {
+            if amount < 0 {
+                panic_with_error!(e, MultiTokenError::LessThanZero);
+            }
+
+            if let Some(account) = from {
+                let balance = Base::balance(e, account, token_id);
+                if balance < amount {
+                    panic_with_error!(e, MultiTokenError::InsufficientBalance);
+                }
+                // NOTE: can't underflow because of the check above.
+                Base::set_balance(e, account, token_id, balance - amount);
+            }
+
+            if let Some(account) = to {
+                let Some(balance) = Base::balance(e, account, token_id).checked_add(amount) else {
+                    panic_with_error!(e, MultiTokenError::MathOverflow);
+                };
+                Base::set_balance(e, account, token_id, balance);
+            }
+        }
+    }
+
+    /// Low-level function for checking if `spender` can move the tokens of
+    /// `owner`, i.e. `spender` is either `owner` itself or an approved
+    /// operator.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address attempting to transfer the tokens.
+    /// * `owner` - The address holding the tokens.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::InsufficientApproval`] - If `spender` is neither
+    ///   `owner` nor an approved operator.
+    pub fn check_spender_approval(e: &Env, spender: &Address, owner: &Address) {
+        if spender != owner && !Base::is_approved_for_all(e, owner, spender) {
+            panic_with_error!(e, MultiTokenError::InsufficientApproval);
+        }
+    }
+
+    /// Creates `amount` of tokens of type `token_id` and assigns them to
+    /// `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The address receiving the new tokens.
+    /// * `token_id` - The identifier of the token type.
+    /// * `amount` - The amount of tokens to mint.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[token_id: u32, amount: i128]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can execute
+    /// minting operations. Failure to implement proper authorization could
+    /// lead to security vulnerabilities and unauthorized token creation.
+    ///
+    /// You probably want to do something like this (pseudo-code):
+    ///
+    /// ```ignore
+    /// let admin = read_administrator(e);
+    /// admin.require_auth();
+    /// ```
+    pub fn mint(e: &Env, to: &Address, token_id: u32, amount: i128) {
+        Base::update(e, None, Some(to), &vec![e, token_id], &vec![e, amount]);
+        emit_mint(e, to, token_id, amount);
+    }
+
+    /// Creates `amounts` of tokens of types `token_ids` and assigns them to
+    /// `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The address receiving the new tokens.
+    /// * `token_ids` - The identifiers of the token types.
+    /// * `amounts` - The amounts of tokens to mint.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint_batch", to: Address]`
+    /// * data - `[token_ids: Vec<u32>, amounts: Vec<i128>]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can execute
+    /// minting operations.
+    pub fn mint_batch(e: &Env, to: &Address, token_ids: &Vec<u32>, amounts: &Vec<i128>) {
+        Base::update(e, None, Some(to), token_ids, amounts);
+        emit_mint_batch(e, to, token_ids, amounts);
+    }
+
+    /// Sets the base URI shared by all token types. The URI of a token type
+    /// is composed by appending its `token_id` to the base URI, unless a
+    /// specific URI was set with [`Base::set_token_uri`].
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `base_uri` - The base URI, expected to end with `/`.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::UriMaxLenExceeded`] - If the length of `base_uri`
+    ///   exceeds [`MAX_URI_LEN`].
+    ///
+    /// # Notes
+    ///
+    /// **IMPORTANT**: This function lacks authorization controls. Most likely,
+    /// you want to invoke it from a constructor or from another function
+    /// with admin-only authorization.
+    pub fn set_base_uri(e: &Env, base_uri: &String) {
+        if base_uri.len() as usize > MAX_URI_LEN {
+            panic_with_error!(e, MultiTokenError::UriMaxLenExceeded);
+        }
+        e.storage().instance().set(&MultiTokenStorageKey::BaseUri, base_uri);
+    }
+
+    /// Sets the URI of the token type `token_id`, overriding the one composed
+    /// from the base URI.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token type.
+    /// * `uri` - The URI of the token type.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::UriMaxLenExceeded`] - If the length of `uri`
+    ///   exceeds [`MAX_URI_LEN`].
+    ///
+    /// # Events
+    ///
+    /// * topics - `["uri", token_id: u32]`
+    /// * data - `[uri: String]`
+    ///
+    /// # Notes
+    ///
+    /// **IMPORTANT**: This function lacks authorization controls. Most likely,
+    /// you want to invoke it from a constructor or from another function
+    /// with admin-only authorization.
+    pub fn set_token_uri(e: &Env, token_id: u32, uri: &String) {
+        if uri.len() as usize > MAX_URI_LEN {
+            panic_with_error!(e, MultiTokenError::UriMaxLenExceeded);
+        }
+        e.storage().persistent().set(&MultiTokenStorageKey::TokenUri(token_id), uri);
+        emit_uri(e, token_id, uri);
+    }
+
+    // ################## INTERNAL HELPERS ##################
+
+    /// Stores the balance of `account` for `token_id`, removing the entry
+    /// when the balance drops to `0`.
+    fn set_balance(e: &Env, account: &Address, token_id: u32, balance: i128) {
+        let key = MultiTokenStorageKey::Balance(account.clone(), token_id);
+        if balance == 0 {
+            e.storage().persistent().remove(&key);
+        } else {
+            e.storage().persistent().set(&key, &balance);
+        }
+    }
+}
@@ -0,0 +1,255 @@
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, String,
+};
+use stellar_event_assertion::EventAssertion;
+
+use crate::multi_token::Base;
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn mint_and_balance_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1, 100);
+        Base::mint_batch(&e, &owner, &vec![&e, 1, 2], &vec![&e, 50, 7]);
+
+        assert_eq!(Base::balance(&e, &owner, 1), 150);
+        assert_eq!(Base::balance(&e, &owner, 2), 7);
+        assert_eq!(Base::balance(&e, &owner, 3), 0);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+    });
+}
+
+#[test]
+fn balance_of_batch_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &alice, 1, 10);
+        Base::mint(&e, &bob, 2, 20);
+
+        let balances = Base::balance_of_batch(
+            &e,
+            &vec![&e, alice.clone(), bob.clone(), bob.clone()],
+            &vec![&e, 1, 1, 2],
+        );
+        assert_eq!(balances, vec![&e, 10, 0, 20]);
+    });
+}
+
+#[test]
+fn transfer_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1, 100);
+        Base::transfer(&e, &owner, &recipient, 1, 40);
+
+        assert_eq!(Base::balance(&e, &owner, 1), 60);
+        assert_eq!(Base::balance(&e, &recipient, 1), 40);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+    });
+}
+
+#[test]
+fn batch_transfer_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint_batch(&e, &owner, &vec![&e, 1, 2], &vec![&e, 100, 5]);
+        Base::batch_transfer(&e, &owner, &recipient, &vec![&e, 1, 2], &vec![&e, 30, 5]);
+
+        assert_eq!(Base::balance(&e, &owner, 1), 70);
+        assert_eq!(Base::balance(&e, &owner, 2), 0);
+        assert_eq!(Base::balance(&e, &recipient, 1), 30);
+        assert_eq!(Base::balance(&e, &recipient, 2), 5);
+    });
+}
+
+#[test]
+fn transfer_from_with_operator_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint_batch(&e, &owner, &vec![&e, 1, 2], &vec![&e, 100, 5]);
+        Base::approve_for_all(&e, &owner, &operator, 1000);
+        assert!(Base::is_approved_for_all(&e, &owner, &operator));
+    });
+
+    e.as_contract(&address, || {
+        Base::transfer_from(&e, &operator, &owner, &recipient, 1, 10);
+    });
+
+    e.as_contract(&address, || {
+        Base::batch_transfer_from(
+            &e,
+            &operator,
+            &owner,
+            &recipient,
+            &vec![&e, 1, 2],
+            &vec![&e, 10, 5],
+        );
+
+        assert_eq!(Base::balance(&e, &owner, 1), 80);
+        assert_eq!(Base::balance(&e, &recipient, 1), 20);
+        assert_eq!(Base::balance(&e, &recipient, 2), 5);
+    });
+}
+
+#[test]
+fn approve_for_all_expires_and_revokes() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::approve_for_all(&e, &owner, &operator, 1000);
+    });
+
+    e.ledger().set_sequence_number(1001);
+    e.as_contract(&address, || {
+        assert!(!Base::is_approved_for_all(&e, &owner, &operator));
+        Base::approve_for_all(&e, &owner, &operator, 2000);
+        assert!(Base::is_approved_for_all(&e, &owner, &operator));
+    });
+
+    e.as_contract(&address, || {
+        Base::approve_for_all(&e, &owner, &operator, 0);
+        assert!(!Base::is_approved_for_all(&e, &owner, &operator));
+    });
+}
+
+#[test]
+fn uri_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Base::set_base_uri(&e, &String::from_str(&e, "https://example.com/"));
+        assert_eq!(Base::uri(&e, 42), String::from_str(&e, "https://example.com/42"));
+
+        let custom = String::from_str(&e, "ipfs://custom");
+        Base::set_token_uri(&e, 42, &custom);
+        assert_eq!(Base::uri(&e, 42), custom);
+        assert_eq!(Base::uri(&e, 7), String::from_str(&e, "https://example.com/7"));
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #700)")]
+fn transfer_insufficient_balance_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1, 10);
+        Base::transfer(&e, &owner, &recipient, 1, 11);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #701)")]
+fn transfer_negative_amount_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::transfer(&e, &owner, &recipient, 1, -1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #702)")]
+fn transfer_from_without_approval_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1, 10);
+        Base::transfer_from(&e, &spender, &owner, &spender, 1, 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #703)")]
+fn approve_for_all_with_past_ledger_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+
+    e.ledger().set_sequence_number(10);
+    e.as_contract(&address, || {
+        Base::approve_for_all(&e, &owner, &operator, 9);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #705)")]
+fn batch_transfer_length_mismatch_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1, 10);
+        Base::batch_transfer(&e, &owner, &recipient, &vec![&e, 1, 2], &vec![&e, 1]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #706)")]
+fn uri_unset_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Base::uri(&e, 1);
+    });
+}
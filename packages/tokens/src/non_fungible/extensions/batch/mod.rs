@@ -0,0 +1,124 @@
+mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contracttrait, Address, Env, Vec};
+
+use crate::non_fungible::{Base, NonFungibleToken};
+
+/// Batch Trait for Non-Fungible Token
+///
+/// The `NonFungibleBatch` trait extends the `NonFungibleToken` trait to
+/// transfer and approve multiple tokens in a single invocation, which is
+/// handy for marketplaces and migrations.
+///
+/// Transfers are optimized to update the balances of the sender and the
+/// recipient only once per call, instead of once per token. Every token still
+/// gets its own `Transfer` (resp. `Approve`) event, so that indexers don't
+/// have to handle batches separately.
+///
+/// # Notes
+///
+/// The batch operations bypass the overrides of the contract type, and update
+/// the storage of the `Base` contract type directly. Therefore, this trait is
+/// INCOMPATIBLE with the `Enumerable` and `Consecutive` extensions.
+#[contracttrait]
+pub trait NonFungibleBatch: NonFungibleToken<ContractType = Base> {
+    /// Transfers the tokens with `token_ids` from `from` to `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `token_ids` - The identifiers of the tokens to transfer.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::non_fungible::NonFungibleTokenError::IncorrectOwner`] - If
+    ///   `from` is not the owner of one of the tokens.
+    /// * [`crate::non_fungible::NonFungibleTokenError::NonExistentToken`] - If
+    ///   one of the tokens does not exist.
+    ///
+    /// # Events
+    ///
+    /// For each token:
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: u32]`
+    fn batch_transfer(e: &Env, from: Address, to: Address, token_ids: Vec<u32>) {
+        Base::batch_transfer(e, &from, &to, &token_ids);
+    }
+
+    /// Transfers the tokens with `token_ids` from `from` to `to` by using
+    /// `spender`s approvals.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorizing the transfer.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `token_ids` - The identifiers of the tokens to transfer.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::non_fungible::NonFungibleTokenError::IncorrectOwner`] - If
+    ///   `from` is not the owner of one of the tokens.
+    /// * [`crate::non_fungible::NonFungibleTokenError::InsufficientApproval`] -
+    ///   If `spender` is not approved for one of the tokens.
+    /// * [`crate::non_fungible::NonFungibleTokenError::NonExistentToken`] - If
+    ///   one of the tokens does not exist.
+    ///
+    /// # Events
+    ///
+    /// For each token:
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: u32]`
+    fn batch_transfer_from(
+        e: &Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        token_ids: Vec<u32>,
+    ) {
+        Base::batch_transfer_from(e, &spender, &from, &to, &token_ids);
+    }
+
+    /// Gives permission to `approved` to transfer the tokens with `token_ids`
+    /// to another account.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `approver` - The address of the approver (should be `owner` or
+    ///   `operator`).
+    /// * `approved` - The address receiving the approvals.
+    /// * `token_ids` - The identifiers of the tokens to approve.
+    /// * `live_until_ledger` - The ledger number at which the approvals expire.
+    ///   If `live_until_ledger` is `0`, the approvals are revoked.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::non_fungible::NonFungibleTokenError::NonExistentToken`] - If
+    ///   one of the tokens does not exist.
+    /// * [`crate::non_fungible::NonFungibleTokenError::InvalidApprover`] - If
+    ///   `approver` is neither the owner nor an operator of one of the tokens.
+    /// * [`crate::non_fungible::NonFungibleTokenError::InvalidLiveUntilLedger`]
+    ///   - If the ledger number is less than the current ledger number.
+    ///
+    /// # Events
+    ///
+    /// For each token:
+    /// * topics - `["approve", owner: Address, token_id: u32]`
+    /// * data - `[approved: Address, live_until_ledger: u32]`
+    fn batch_approve(
+        e: &Env,
+        approver: Address,
+        approved: Address,
+        token_ids: Vec<u32>,
+        live_until_ledger: u32,
+    ) {
+        Base::batch_approve(e, &approver, &approved, &token_ids, live_until_ledger);
+    }
+}
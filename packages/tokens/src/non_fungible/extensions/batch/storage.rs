@@ -0,0 +1,168 @@
+use soroban_sdk::{panic_with_error, Address, Env, Vec};
+
+use crate::non_fungible::{emit_transfer, Base, NFTStorageKey, NonFungibleTokenError};
+
+impl Base {
+    /// Transfers the tokens with `token_ids`, ensuring ownership checks.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_ids` - The identifiers of the tokens being transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::batch_update`] errors.
+    ///
+    /// # Events
+    ///
+    /// For each token:
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: u32]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `from` is required.
+    /// * **IMPORTANT**: If the recipient is unable to receive, the NFTs may get
+    ///   lost.
+    pub fn batch_transfer(e: &Env, from: &Address, to: &Address, token_ids: &Vec<u32>) {
+        from.require_auth();
+        Base::batch_update(e, from, to, token_ids);
+        for token_id in token_ids.iter() {
+            emit_transfer(e, from, to, token_id);
+        }
+    }
+
+    /// Transfers the tokens with `token_ids`, ensuring ownership and approval
+    /// checks.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address attempting to transfer the tokens.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_ids` - The identifiers of the tokens being transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::check_spender_approval`] errors.
+    /// * refer to [`Base::batch_update`] errors.
+    ///
+    /// # Events
+    ///
+    /// For each token:
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: u32]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `spender` is required.
+    /// * **IMPORTANT**: If the recipient is unable to receive, the NFTs may get
+    ///   lost.
+    pub fn batch_transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        token_ids: &Vec<u32>,
+    ) {
+        spender.require_auth();
+        // Approval for all is checked only once, as it covers every token.
+        if !Base::is_approved_for_all(e, from, spender) {
+            for token_id in token_ids.iter() {
+                Base::check_spender_approval(e, spender, from, token_id);
+            }
+        }
+        Base::batch_update(e, from, to, token_ids);
+        for token_id in token_ids.iter() {
+            emit_transfer(e, from, to, token_id);
+        }
+    }
+
+    /// Approves an address to transfer the tokens with `token_ids`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `approver` - The address of the approver (should be `owner` or
+    ///   `operator`).
+    /// * `approved` - The address receiving the approvals.
+    /// * `token_ids` - The identifiers of the tokens to be approved.
+    /// * `live_until_ledger` - The ledger number at which the approvals expire.
+    ///   If `live_until_ledger` is `0`, the approvals are revoked.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::owner_of`] errors.
+    /// * refer to [`Base::approve_for_owner`] errors.
+    ///
+    /// # Events
+    ///
+    /// For each token:
+    /// * topics - `["approve", owner: Address, token_id: u32]`
+    /// * data - `[approved: Address, live_until_ledger: u32]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `approver` is required.
+    pub fn batch_approve(
+        e: &Env,
+        approver: &Address,
+        approved: &Address,
+        token_ids: &Vec<u32>,
+        live_until_ledger: u32,
+    ) {
+        approver.require_auth();
+
+        for token_id in token_ids.iter() {
+            let owner = Base::owner_of(e, token_id);
+            Base::approve_for_owner(e, &owner, approver, approved, token_id, live_until_ledger);
+        }
+    }
+
+    /// Low-level function for transferring multiple NFTs at once, without
+    /// handling authorization. Updates ownership records and clears existing
+    /// approvals and user roles for every token, while adjusting the balances
+    /// only once.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address of the current owner of the tokens.
+    /// * `to` - The address of the recipient.
+    /// * `token_ids` - The identifiers of the tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::IncorrectOwner`] - If the `from` address is
+    ///   not the owner of one of the tokens.
+    /// * refer to [`Base::owner_of`] errors.
+    /// * refer to [`Base::decrease_balance`] errors.
+    /// * refer to [`Base::increase_balance`] errors.
+    pub fn batch_update(e: &Env, from: &Address, to: &Address, token_ids: &Vec<u32>) {
+        let rentable_enabled = Base::rentable_enabled(e);
+        for token_id in token_ids.iter() {
+            // Ensure the `from` address is indeed the owner. This also rejects
+            // duplicated ids when `from` and `to` differ.
+            if Base::owner_of(e, token_id) != *from {
+                panic_with_error!(e, NonFungibleTokenError::IncorrectOwner);
+            }
+
+            // Clear any existing approval
+            e.storage().temporary().remove(&NFTStorageKey::Approval(token_id));
+
+            // Clear the user role of the rentable extension, if it is in use
+            if rentable_enabled {
+                Base::remove_user(e, token_id);
+            }
+
+            e.storage().persistent().set(&NFTStorageKey::Owner(token_id), to);
+        }
+
+        Base::decrease_balance(e, from, token_ids.len());
+        Base::increase_balance(e, to, token_ids.len());
+    }
+}
@@ -0,0 +1,150 @@
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, vec, Address, Env};
+use stellar_event_assertion::EventAssertion;
+
+use crate::non_fungible::Base;
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn batch_transfer_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        for _ in 0..5 {
+            Base::sequential_mint(&e, &owner);
+        }
+    });
+
+    e.as_contract(&address, || {
+        Base::batch_transfer(&e, &owner, &recipient, &vec![&e, 0, 2, 4]);
+
+        assert_eq!(Base::balance(&e, &owner), 2);
+        assert_eq!(Base::balance(&e, &recipient), 3);
+        assert_eq!(Base::owner_of(&e, 0), recipient);
+        assert_eq!(Base::owner_of(&e, 1), owner);
+        assert_eq!(Base::owner_of(&e, 4), recipient);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(3);
+    });
+}
+
+#[test]
+fn batch_transfer_from_with_approval_for_all_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::sequential_mint(&e, &owner);
+        Base::sequential_mint(&e, &owner);
+        Base::approve_for_all(&e, &owner, &operator, 1000);
+    });
+
+    e.as_contract(&address, || {
+        Base::batch_transfer_from(&e, &operator, &owner, &recipient, &vec![&e, 0, 1]);
+
+        assert_eq!(Base::balance(&e, &owner), 0);
+        assert_eq!(Base::balance(&e, &recipient), 2);
+    });
+}
+
+#[test]
+fn batch_approve_and_transfer_from_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::sequential_mint(&e, &owner);
+        Base::sequential_mint(&e, &owner);
+        Base::sequential_mint(&e, &owner);
+    });
+
+    e.as_contract(&address, || {
+        Base::batch_approve(&e, &owner, &spender, &vec![&e, 0, 2], 1000);
+
+        assert_eq!(Base::get_approved(&e, 0), Some(spender.clone()));
+        assert_eq!(Base::get_approved(&e, 1), None);
+        assert_eq!(Base::get_approved(&e, 2), Some(spender.clone()));
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+    });
+
+    e.as_contract(&address, || {
+        Base::batch_transfer_from(&e, &spender, &owner, &recipient, &vec![&e, 0, 2]);
+
+        assert_eq!(Base::balance(&e, &owner), 1);
+        assert_eq!(Base::balance(&e, &recipient), 2);
+        // approvals are cleared on transfer
+        assert_eq!(Base::get_approved(&e, 0), None);
+        assert_eq!(Base::get_approved(&e, 2), None);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #202)")]
+fn batch_transfer_from_without_approval_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::sequential_mint(&e, &owner);
+        Base::sequential_mint(&e, &owner);
+        Base::approve(&e, &owner, &spender, 0, 1000);
+    });
+
+    e.as_contract(&address, || {
+        Base::batch_transfer_from(&e, &spender, &owner, &recipient, &vec![&e, 0, 1]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #201)")]
+fn batch_transfer_not_owned_token_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let other = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::sequential_mint(&e, &owner);
+        Base::sequential_mint(&e, &other);
+        Base::batch_transfer(&e, &owner, &other, &vec![&e, 0, 1]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #201)")]
+fn batch_transfer_duplicated_token_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::sequential_mint(&e, &owner);
+        Base::sequential_mint(&e, &owner);
+        Base::batch_transfer(&e, &owner, &recipient, &vec![&e, 0, 0]);
+    });
+}
@@ -104,6 +104,25 @@ impl Consecutive {
     /// * [`NonFungibleTokenError::NonExistentToken`] - Occurs if the provided
     ///   `token_id` does not exist.
     pub fn owner_of(e: &Env, token_id: u32) -> Address {
+        let marker_id = Consecutive::ownership_marker(e, token_id);
+        Consecutive::get_persistent_entry(e, &NFTConsecutiveStorageKey::Owner(marker_id))
+            .unwrap_or_else(|| panic_with_error!(&e, NonFungibleTokenError::NonExistentToken))
+    }
+
+    /// Returns the identifier of the token holding the ownership record of
+    /// `token_id`, i.e. the closest token, from `token_id` onwards, whose bit
+    /// is set in the ownership buckets.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token ID as a number.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::NonExistentToken`] - Occurs if the provided
+    ///   `token_id` does not exist.
+    pub fn ownership_marker(e: &Env, token_id: u32) -> u32 {
         let next_id = sequential::next_token_id(e);
         if next_id == 0 {
             panic_with_error!(&e, NonFungibleTokenError::NonExistentToken);
@@ -138,14 +157,12 @@ impl Consecutive {
                 find_bit_in_bucket(bucket, from_id)
                     .map(|pos_in_bucket| i * ids_in_bucket + pos_in_bucket)
             })
-            .iter()
-            .find_map(|id| {
-                Consecutive::get_persistent_entry(e, &NFTConsecutiveStorageKey::Owner(*id))
-            })
             .unwrap_or_else(|| panic_with_error!(&e, NonFungibleTokenError::NonExistentToken))
     }
 
-    /// Returns the URI for a specific `token_id`.
+    /// Returns the URI for a specific `token_id`: the individual URI set with
+    /// [`Base::set_token_uri`] if any, otherwise the one composed from the
+    /// base URI.
     ///
     /// # Arguments
     ///
@@ -165,6 +182,10 @@ impl Consecutive {
             panic_with_error!(e, NonFungibleTokenError::NonExistentToken);
         }
 
+        if let Some(uri) = Base::stored_token_uri(e, token_id) {
+            return uri;
+        }
+
         let base_uri = Base::base_uri(e);
         Base::compose_uri_for_token(e, base_uri, token_id)
     }
@@ -452,6 +473,12 @@ impl Consecutive {
             e.storage().persistent().remove(&NFTConsecutiveStorageKey::Owner(token_id));
 
             e.storage().persistent().set(&NFTConsecutiveStorageKey::BurnedToken(token_id), &true);
+
+            // Clear the individual URI of the uri storage extension, if any
+            Base::remove_token_uri(e, token_id);
+
+            // Clear the attributes of the on-chain metadata extension, if any
+            Base::remove_attributes(e, token_id);
         }
     }
 
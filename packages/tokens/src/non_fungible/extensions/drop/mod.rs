@@ -0,0 +1,229 @@
+mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contractevent, contracttrait, Address, BytesN, Env, Vec};
+pub use storage::{AllowlistLeaf, DropConfig, DropPhase, NFTDropStorageKey};
+
+use crate::non_fungible::{Base, NonFungibleToken};
+
+/// Drop Trait for Non-Fungible Token
+///
+/// The `NonFungibleDrop` trait extends the `NonFungibleToken` trait to sell
+/// newly minted tokens in phased sale windows, configured by a [`DropConfig`]:
+///
+/// 1. **Allowlist** phase: accounts included in a Merkle allowlist can mint up
+///    to the cap of their [`AllowlistLeaf`], at the price of the leaf, over as
+///    many invocations as they wish.
+/// 2. **Public** phase: any account can mint at the public price, up to the
+///    public cap per account.
+///
+/// Tokens are minted with [`crate::non_fungible::Base::sequential_mint`], and
+/// paid for in the SEP-41 payment token of the configuration, which is
+/// transferred straight to the payee.
+///
+/// The allowlist claims are tracked with
+/// `stellar_contract_utils::merkle_distributor::MerkleDistributor`, with a
+/// tree built from [`AllowlistLeaf`]s using SHA-256 and **commutative**
+/// hashing (sorted leaves).
+///
+/// # Notes
+///
+/// * The Merkle root of the allowlist is stored under the `MerkleDistributor`
+///   storage keys, so the contract can't use another `MerkleDistributor` at the
+///   same time.
+/// * The allowlist mints are counted per leaf index, across roots. The root can
+///   therefore only be replaced before the allowlist phase starts.
+/// * The drop logic relies on the `Base` contract type, this trait is therefore
+///   INCOMPATIBLE with the `Enumerable` and `Consecutive` extensions.
+/// * `set_drop_config` and `set_allowlist_root` have no default implementation,
+///   as a reminder for the implementor to provide the authorization logic.
+#[contracttrait]
+pub trait NonFungibleDrop: NonFungibleToken<ContractType = Base> {
+    /// Mints `amount` tokens to `to` during the allowlist phase, and returns
+    /// their identifiers.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The allowlisted account, paying for and receiving the tokens.
+    /// * `leaf` - The allowlist leaf of `to`.
+    /// * `proof` - The Merkle proof of `leaf`.
+    /// * `amount` - The number of tokens to mint.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::allowlist_mint`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[token_id: u32]`
+    ///
+    /// * topics - `["drop_mint", to: Address]`
+    /// * data - `[amount: u32, total_price: i128]`
+    fn allowlist_mint(
+        e: &Env,
+        to: Address,
+        leaf: AllowlistLeaf,
+        proof: Vec<BytesN<32>>,
+        amount: u32,
+    ) -> Vec<u32> {
+        Base::allowlist_mint(e, &to, leaf, proof, amount)
+    }
+
+    /// Mints `amount` tokens to `to` during the public phase, and returns
+    /// their identifiers.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The account paying for and receiving the tokens.
+    /// * `amount` - The number of tokens to mint.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::public_mint`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[token_id: u32]`
+    ///
+    /// * topics - `["drop_mint", to: Address]`
+    /// * data - `[amount: u32, total_price: i128]`
+    fn public_mint(e: &Env, to: Address, amount: u32) -> Vec<u32> {
+        Base::public_mint(e, &to, amount)
+    }
+
+    /// Returns the configuration of the drop.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::non_fungible::NonFungibleTokenError::DropConfigNotSet`] -
+    ///   When the drop is not configured.
+    fn drop_config(e: &Env) -> DropConfig {
+        Base::drop_config(e)
+    }
+
+    /// Returns the current phase of the drop.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::non_fungible::NonFungibleTokenError::DropConfigNotSet`] -
+    ///   When the drop is not configured.
+    fn drop_phase(e: &Env) -> DropPhase {
+        Base::drop_phase(e)
+    }
+
+    /// Returns the number of tokens minted with the allowlist leaf at `index`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `index` - The index of the allowlist leaf.
+    fn allowlist_minted(e: &Env, index: u32) -> u32 {
+        Base::allowlist_minted(e, index)
+    }
+
+    /// Returns the number of tokens minted by `account` during the public
+    /// phase.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The account to query.
+    fn public_minted(e: &Env, account: Address) -> u32 {
+        Base::public_minted(e, &account)
+    }
+
+    /// Sets the configuration of the drop.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `config` - The drop configuration.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::non_fungible::NonFungibleTokenError::InvalidDropConfig`] -
+    ///   When the phases are not ordered or the public price is negative.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["drop_config_set"]`
+    /// * data - `[config: DropConfig]`
+    fn set_drop_config(e: &Env, config: DropConfig, operator: Address);
+
+    /// Sets the Merkle root of the allowlist. Once the allowlist phase has
+    /// started, the root can't be replaced anymore, as the allowlist mints are
+    /// counted per leaf index.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `root` - The Merkle root of the allowlist.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::non_fungible::NonFungibleTokenError::AllowlistRootFrozen`] -
+    ///   When a root is already set and the allowlist phase has started.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["set_root"]`
+    /// * data - `[root: Bytes]`
+    fn set_allowlist_root(e: &Env, root: BytesN<32>, operator: Address);
+}
+
+// ################## EVENTS ##################
+
+/// Event emitted when the drop configuration is set.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DropConfigSet {
+    pub config: DropConfig,
+}
+
+/// Emits an event indicating the drop configuration was set.
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+/// * `config` - The drop configuration.
+pub fn emit_drop_config_set(e: &Env, config: &DropConfig) {
+    DropConfigSet { config: config.clone() }.publish(e);
+}
+
+/// Event emitted when tokens are minted in a drop.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DropMint {
+    #[topic]
+    pub to: Address,
+    pub amount: u32,
+    pub total_price: i128,
+}
+
+/// Emits an event indicating tokens were minted in a drop.
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+/// * `to` - The account receiving the tokens.
+/// * `amount` - The number of minted tokens.
+/// * `total_price` - The price paid for all the tokens.
+pub fn emit_drop_mint(e: &Env, to: &Address, amount: u32, total_price: i128) {
+    DropMint { to: to.clone(), amount, total_price }.publish(e);
+}
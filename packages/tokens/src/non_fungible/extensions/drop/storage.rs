@@ -0,0 +1,356 @@
+use soroban_sdk::{contracttype, panic_with_error, token::TokenClient, Address, BytesN, Env, Vec};
+use stellar_contract_utils::{
+    crypto::sha256::Sha256,
+    merkle_distributor::{
+        CappedLeaf, IndexableLeaf, MerkleDistributor, MerkleDistributorStorageKey,
+    },
+};
+
+use crate::non_fungible::{
+    drop::{emit_drop_config_set, emit_drop_mint},
+    Base, NonFungibleTokenError, BALANCE_EXTEND_AMOUNT, BALANCE_TTL_THRESHOLD,
+};
+
+type AllowlistDistributor = MerkleDistributor<Sha256>;
+
+/// Leaf of the Merkle allowlist: `account` may mint up to `cap` tokens at
+/// `price` each
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AllowlistLeaf {
+    pub index: u32,
+    pub account: Address,
+    pub cap: u32,
+    pub price: i128,
+}
+
+impl IndexableLeaf for AllowlistLeaf {
+    fn index(&self) -> u32 {
+        self.index
+    }
+}
+
+impl CappedLeaf for AllowlistLeaf {
+    fn cap(&self) -> u32 {
+        self.cap
+    }
+}
+
+/// Configuration of a drop
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DropConfig {
+    /// SEP-41 token the tokens are paid in
+    pub payment_token: Address,
+    /// Account receiving the payments
+    pub payee: Address,
+    /// Timestamp at which the allowlist phase starts
+    pub allowlist_start: u64,
+    /// Timestamp at which the allowlist phase ends and the public phase starts
+    pub public_start: u64,
+    /// Timestamp at which the public phase ends
+    pub end: u64,
+    /// Price of a token during the public phase
+    pub public_price: i128,
+    /// Maximum number of tokens an account can mint during the public phase
+    pub public_cap: u32,
+}
+
+/// Phase of a drop, derived from the ledger timestamp
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DropPhase {
+    NotStarted,
+    Allowlist,
+    Public,
+    Ended,
+}
+
+/// Storage keys for the data associated with the drop extension of
+/// `NonFungibleToken`
+#[contracttype]
+pub enum NFTDropStorageKey {
+    Config,
+    PublicMinted(Address),
+}
+
+impl Base {
+    /// Returns the configuration of the drop.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::DropConfigNotSet`] - When the drop is not
+    ///   configured.
+    pub fn drop_config(e: &Env) -> DropConfig {
+        e.storage()
+            .instance()
+            .get(&NFTDropStorageKey::Config)
+            .unwrap_or_else(|| panic_with_error!(e, NonFungibleTokenError::DropConfigNotSet))
+    }
+
+    /// Returns the current phase of the drop.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::DropConfigNotSet`] - When the drop is not
+    ///   configured.
+    pub fn drop_phase(e: &Env) -> DropPhase {
+        let config = Base::drop_config(e);
+        let now = e.ledger().timestamp();
+        if now < config.allowlist_start {
+            DropPhase::NotStarted
+        } else if now < config.public_start {
+            DropPhase::Allowlist
+        } else if now < config.end {
+            DropPhase::Public
+        } else {
+            DropPhase::Ended
+        }
+    }
+
+    /// Returns the number of tokens minted with the allowlist leaf at `index`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `index` - The index of the allowlist leaf.
+    pub fn allowlist_minted(e: &Env, index: u32) -> u32 {
+        AllowlistDistributor::get_claim_count(e, index)
+    }
+
+    /// Returns the number of tokens minted by `account` during the public
+    /// phase.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The account to query.
+    pub fn public_minted(e: &Env, account: &Address) -> u32 {
+        let key = NFTDropStorageKey::PublicMinted(account.clone());
+        if let Some(minted) = e.storage().persistent().get::<_, u32>(&key) {
+            e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+            minted
+        } else {
+            0
+        }
+    }
+
+    /// Mints `amount` tokens to `to` during the allowlist phase, after
+    /// verifying `leaf` against the allowlist and transferring its price for
+    /// every token from `to` to the payee. Returns the identifiers of the
+    /// minted tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The allowlisted account, paying for and receiving the tokens.
+    /// * `leaf` - The allowlist leaf of `to`.
+    /// * `proof` - The Merkle proof of `leaf`.
+    /// * `amount` - The number of tokens to mint.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::DropPhaseInactive`] - When the drop is not in
+    ///   the allowlist phase.
+    /// * [`NonFungibleTokenError::InvalidAllowlistAccount`] - When `leaf`
+    ///   belongs to another account.
+    /// * [`NonFungibleTokenError::MathOverflow`] - When the total price
+    ///   overflows.
+    /// * [`NonFungibleTokenError::DropConfigNotSet`] - When the drop is not
+    ///   configured.
+    /// * refer to `MerkleDistributor::verify_and_add_claims` errors.
+    /// * refer to [`Base::sequential_mint`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[token_id: u32]`
+    ///
+    /// * topics - `["drop_mint", to: Address]`
+    /// * data - `[amount: u32, total_price: i128]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `to` is required.
+    pub fn allowlist_mint(
+        e: &Env,
+        to: &Address,
+        leaf: AllowlistLeaf,
+        proof: Vec<BytesN<32>>,
+        amount: u32,
+    ) -> Vec<u32> {
+        to.require_auth();
+
+        let config = Base::drop_config(e);
+        if Base::drop_phase(e) != DropPhase::Allowlist {
+            panic_with_error!(e, NonFungibleTokenError::DropPhaseInactive);
+        }
+        if leaf.account != *to {
+            panic_with_error!(e, NonFungibleTokenError::InvalidAllowlistAccount);
+        }
+
+        let price = leaf.price;
+        AllowlistDistributor::verify_and_add_claims(e, leaf, proof, amount);
+
+        Base::drop_mint(e, &config, to, amount, price)
+    }
+
+    /// Mints `amount` tokens to `to` during the public phase, after
+    /// transferring the public price for every token from `to` to the payee.
+    /// Returns the identifiers of the minted tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The account paying for and receiving the tokens.
+    /// * `amount` - The number of tokens to mint.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::DropPhaseInactive`] - When the drop is not in
+    ///   the public phase.
+    /// * [`NonFungibleTokenError::InvalidMintAmount`] - When `amount` is zero.
+    /// * [`NonFungibleTokenError::PublicMintCapExceeded`] - When `to` would
+    ///   exceed the public cap.
+    /// * [`NonFungibleTokenError::MathOverflow`] - When the total price
+    ///   overflows.
+    /// * [`NonFungibleTokenError::DropConfigNotSet`] - When the drop is not
+    ///   configured.
+    /// * refer to [`Base::sequential_mint`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[token_id: u32]`
+    ///
+    /// * topics - `["drop_mint", to: Address]`
+    /// * data - `[amount: u32, total_price: i128]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `to` is required.
+    pub fn public_mint(e: &Env, to: &Address, amount: u32) -> Vec<u32> {
+        to.require_auth();
+
+        let config = Base::drop_config(e);
+        if Base::drop_phase(e) != DropPhase::Public {
+            panic_with_error!(e, NonFungibleTokenError::DropPhaseInactive);
+        }
+        if amount == 0 {
+            panic_with_error!(e, NonFungibleTokenError::InvalidMintAmount);
+        }
+
+        let minted = Base::public_minted(e, to)
+            .checked_add(amount)
+            .filter(|minted| *minted <= config.public_cap)
+            .unwrap_or_else(|| panic_with_error!(e, NonFungibleTokenError::PublicMintCapExceeded));
+        e.storage().persistent().set(&NFTDropStorageKey::PublicMinted(to.clone()), &minted);
+
+        Base::drop_mint(e, &config, to, amount, config.public_price)
+    }
+
+    /// Sets the configuration of the drop.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `config` - The drop configuration.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidDropConfig`] - When the phases are not
+    ///   ordered or the public price is negative.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["drop_config_set"]`
+    /// * data - `[config: DropConfig]`
+    ///
+    /// # Notes
+    ///
+    /// **IMPORTANT**: This function lacks authorization controls. Most likely,
+    /// you want to invoke it from a constructor or from another function
+    /// with admin-only authorization.
+    pub fn set_drop_config(e: &Env, config: &DropConfig) {
+        if config.allowlist_start > config.public_start
+            || config.public_start > config.end
+            || config.public_price < 0
+        {
+            panic_with_error!(e, NonFungibleTokenError::InvalidDropConfig);
+        }
+        e.storage().instance().set(&NFTDropStorageKey::Config, config);
+        emit_drop_config_set(e, config);
+    }
+
+    /// Sets the Merkle root of the allowlist.
+    ///
+    /// The number of tokens minted with an allowlist leaf is tracked by leaf
+    /// index, regardless of the root (see [`Base::allowlist_minted`]).
+    /// Therefore, the root can only be replaced until the allowlist phase
+    /// starts, before any allowlist mint, so that the mints made with a leaf
+    /// are never credited to the leaf of another account at the same index.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `root` - The Merkle root of the allowlist.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::AllowlistRootFrozen`] - When a root is
+    ///   already set and the drop has left the [`DropPhase::NotStarted`] phase.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["set_root"]`
+    /// * data - `[root: Bytes]`
+    ///
+    /// # Notes
+    ///
+    /// **IMPORTANT**: This function lacks authorization controls. Most likely,
+    /// you want to invoke it from a constructor or from another function
+    /// with admin-only authorization.
+    ///
+    /// Moving the allowlist phase to the future with [`Base::set_drop_config`]
+    /// after allowlist mints unfreezes the root. A replacement tree must then
+    /// keep every index on the same account.
+    pub fn set_allowlist_root(e: &Env, root: &BytesN<32>) {
+        let root_set = e.storage().instance().has(&MerkleDistributorStorageKey::Root);
+        if root_set
+            && e.storage().instance().has(&NFTDropStorageKey::Config)
+            && Base::drop_phase(e) != DropPhase::NotStarted
+        {
+            panic_with_error!(e, NonFungibleTokenError::AllowlistRootFrozen);
+        }
+        AllowlistDistributor::set_root(e, root.clone());
+    }
+
+    // ################## INTERNAL HELPERS ##################
+
+    /// Transfers `amount * price` from `to` to the payee and mints `amount`
+    /// tokens to `to`.
+    fn drop_mint(e: &Env, config: &DropConfig, to: &Address, amount: u32, price: i128) -> Vec<u32> {
+        let total_price = price
+            .checked_mul(amount as i128)
+            .unwrap_or_else(|| panic_with_error!(e, NonFungibleTokenError::MathOverflow));
+        if total_price > 0 {
+            TokenClient::new(e, &config.payment_token).transfer(to, &config.payee, &total_price);
+        }
+
+        let mut token_ids = Vec::new(e);
+        for _ in 0..amount {
+            token_ids.push_back(Base::sequential_mint(e, to));
+        }
+        emit_drop_mint(e, to, amount, total_price);
+
+        token_ids
+    }
+}
@@ -0,0 +1,249 @@
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger as _},
+    token::{StellarAssetClient, TokenClient},
+    vec,
+    xdr::ToXdr,
+    Address, BytesN, Env, Vec,
+};
+use stellar_contract_utils::crypto::{
+    hashable::commutative_hash_pair, hasher::Hasher, sha256::Sha256,
+};
+
+use crate::non_fungible::{
+    drop::{AllowlistLeaf, DropConfig, DropPhase},
+    Base,
+};
+
+#[contract]
+struct MockContract;
+
+fn hash_leaf(e: &Env, leaf: &AllowlistLeaf) -> BytesN<32> {
+    let mut hasher = Sha256::new(e);
+    hasher.update(leaf.clone().to_xdr(e));
+    hasher.finalize()
+}
+
+/// Builds a two-leaf allowlist, returning its root, the leaf of `account` and
+/// the proof of that leaf.
+fn allowlist(e: &Env, account: &Address) -> (BytesN<32>, AllowlistLeaf, Vec<BytesN<32>>) {
+    let leaf = AllowlistLeaf { index: 0, account: account.clone(), cap: 3, price: 50 };
+    let other = AllowlistLeaf { index: 1, account: Address::generate(e), cap: 1, price: 50 };
+    let (leaf_hash, other_hash) = (hash_leaf(e, &leaf), hash_leaf(e, &other));
+    let root = commutative_hash_pair(&leaf_hash, &other_hash, Sha256::new(e));
+    (root, leaf, vec![e, other_hash])
+}
+
+fn setup(e: &Env, buyer: &Address) -> (Address, Address, DropConfig) {
+    let address = e.register(MockContract, ());
+    let payment_token = e.register_stellar_asset_contract_v2(Address::generate(e)).address();
+    StellarAssetClient::new(e, &payment_token).mint(buyer, &1000);
+    let config = DropConfig {
+        payment_token: payment_token.clone(),
+        payee: Address::generate(e),
+        allowlist_start: 100,
+        public_start: 200,
+        end: 300,
+        public_price: 100,
+        public_cap: 2,
+    };
+    e.as_contract(&address, || Base::set_drop_config(e, &config));
+    (address, payment_token, config)
+}
+
+#[test]
+fn drop_phases_follow_timestamp() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (address, _, _) = setup(&e, &Address::generate(&e));
+
+    e.as_contract(&address, || {
+        assert_eq!(Base::drop_phase(&e), DropPhase::NotStarted);
+        e.ledger().set_timestamp(100);
+        assert_eq!(Base::drop_phase(&e), DropPhase::Allowlist);
+        e.ledger().set_timestamp(200);
+        assert_eq!(Base::drop_phase(&e), DropPhase::Public);
+        e.ledger().set_timestamp(300);
+        assert_eq!(Base::drop_phase(&e), DropPhase::Ended);
+    });
+}
+
+#[test]
+fn allowlist_mint_up_to_cap_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let buyer = Address::generate(&e);
+    let (address, payment_token, config) = setup(&e, &buyer);
+    let (root, leaf, proof) = allowlist(&e, &buyer);
+    e.ledger().set_timestamp(150);
+
+    e.as_contract(&address, || {
+        Base::set_allowlist_root(&e, &root);
+        let token_ids = Base::allowlist_mint(&e, &buyer, leaf.clone(), proof.clone(), 2);
+        assert_eq!(token_ids.len(), 2);
+        assert_eq!(Base::allowlist_minted(&e, 0), 2);
+    });
+    e.as_contract(&address, || {
+        Base::allowlist_mint(&e, &buyer, leaf, proof, 1);
+        assert_eq!(Base::balance(&e, &buyer), 3);
+    });
+
+    let token = TokenClient::new(&e, &payment_token);
+    assert_eq!(token.balance(&config.payee), 150);
+    assert_eq!(token.balance(&buyer), 850);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1303)")]
+fn allowlist_mint_exceeding_cap_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let buyer = Address::generate(&e);
+    let (address, _, _) = setup(&e, &buyer);
+    let (root, leaf, proof) = allowlist(&e, &buyer);
+    e.ledger().set_timestamp(150);
+
+    e.as_contract(&address, || {
+        Base::set_allowlist_root(&e, &root);
+        Base::allowlist_mint(&e, &buyer, leaf.clone(), proof.clone(), 2);
+    });
+    e.as_contract(&address, || {
+        Base::allowlist_mint(&e, &buyer, leaf, proof, 2);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #229)")]
+fn allowlist_mint_with_leaf_of_another_account_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let buyer = Address::generate(&e);
+    let (address, _, _) = setup(&e, &buyer);
+    let (root, leaf, proof) = allowlist(&e, &Address::generate(&e));
+    e.ledger().set_timestamp(150);
+
+    e.as_contract(&address, || {
+        Base::set_allowlist_root(&e, &root);
+        Base::allowlist_mint(&e, &buyer, leaf, proof, 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #228)")]
+fn allowlist_mint_during_public_phase_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let buyer = Address::generate(&e);
+    let (address, _, _) = setup(&e, &buyer);
+    let (root, leaf, proof) = allowlist(&e, &buyer);
+    e.ledger().set_timestamp(200);
+
+    e.as_contract(&address, || {
+        Base::set_allowlist_root(&e, &root);
+        Base::allowlist_mint(&e, &buyer, leaf, proof, 1);
+    });
+}
+
+#[test]
+fn public_mint_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let buyer = Address::generate(&e);
+    let (address, payment_token, config) = setup(&e, &buyer);
+    e.ledger().set_timestamp(250);
+
+    e.as_contract(&address, || {
+        let token_ids = Base::public_mint(&e, &buyer, 2);
+        assert_eq!(Base::owner_of(&e, token_ids.get_unchecked(1)), buyer);
+        assert_eq!(Base::public_minted(&e, &buyer), 2);
+    });
+
+    assert_eq!(TokenClient::new(&e, &payment_token).balance(&config.payee), 200);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #231)")]
+fn public_mint_exceeding_cap_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let buyer = Address::generate(&e);
+    let (address, _, _) = setup(&e, &buyer);
+    e.ledger().set_timestamp(250);
+
+    e.as_contract(&address, || {
+        Base::public_mint(&e, &buyer, 2);
+    });
+    e.as_contract(&address, || {
+        Base::public_mint(&e, &buyer, 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #228)")]
+fn public_mint_after_end_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let buyer = Address::generate(&e);
+    let (address, _, _) = setup(&e, &buyer);
+    e.ledger().set_timestamp(300);
+
+    e.as_contract(&address, || {
+        Base::public_mint(&e, &buyer, 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #227)")]
+fn set_drop_config_with_unordered_phases_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let buyer = Address::generate(&e);
+    let (address, _, config) = setup(&e, &buyer);
+
+    e.as_contract(&address, || {
+        Base::set_drop_config(&e, &DropConfig { public_start: 50, ..config });
+    });
+}
+
+#[test]
+fn replace_allowlist_root_before_allowlist_phase_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let buyer = Address::generate(&e);
+    let (address, _, _) = setup(&e, &buyer);
+    let (root, leaf, proof) = allowlist(&e, &buyer);
+
+    e.as_contract(&address, || {
+        Base::set_allowlist_root(&e, &BytesN::from_array(&e, &[1; 32]));
+        Base::set_allowlist_root(&e, &root);
+    });
+    e.ledger().set_timestamp(150);
+    e.as_contract(&address, || {
+        Base::allowlist_mint(&e, &buyer, leaf, proof, 1);
+        assert_eq!(Base::allowlist_minted(&e, 0), 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #234)")]
+fn replace_allowlist_root_during_allowlist_phase_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let buyer = Address::generate(&e);
+    let (address, _, _) = setup(&e, &buyer);
+    let (root, leaf, proof) = allowlist(&e, &buyer);
+    e.ledger().set_timestamp(150);
+
+    e.as_contract(&address, || {
+        Base::set_allowlist_root(&e, &root);
+        Base::allowlist_mint(&e, &buyer, leaf, proof, 1);
+    });
+    // a regenerated allowlist would credit the mints of index 0 to its new
+    // account
+    e.as_contract(&address, || {
+        let (new_root, _, _) = allowlist(&e, &Address::generate(&e));
+        Base::set_allowlist_root(&e, &new_root);
+    });
+}
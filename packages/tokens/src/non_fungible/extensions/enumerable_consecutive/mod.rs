@@ -0,0 +1,112 @@
+//! # Enumerable Consecutive Extension for Non-Fungible Token
+//!
+//! The `enumerable_consecutive` module combines the cheap batch minting of the
+//! [`crate::non_fungible::consecutive`] extension with the owner enumeration
+//! of the [`crate::non_fungible::enumerable`] extension, which are otherwise
+//! mutually exclusive.
+//!
+//! Ownership is tracked lazily exactly as in `Consecutive`, and on top of it,
+//! every owner has a list of the ranges of token IDs they own. A batch mint
+//! adds a single range to the list of the recipient, regardless of the number
+//! of tokens. The ranges are materialized on transfer and burn: the range
+//! containing the token is shrunk or split, and the token is added to the list
+//! of the recipient as a range of its own.
+//!
+//!   Mint 10 tokens to A, then transfer token 5 to B
+//!   A: [0..=9]          ->  A: [6..=9], [0..=4]
+//!   B: []               ->  B: [5..=5]
+//!
+//! Like the token lists of the `Enumerable` extension, every range is stored
+//! in its own storage entry, indexed by its position in the list of the
+//! owner, and removed by swapping it with the last one. The range containing
+//! a token is found through the end of the range, which is the token holding
+//! its ownership record in `Consecutive`. Hence, the cost of transfers and
+//! burns doesn't depend on the number of ranges of an owner.
+//!
+//! ## Caveats
+//!
+//! - Ranges are never merged, even when adjacent, as the end of every range
+//!   must keep its ownership record.
+//! - Owners are enumerated range by range, as looking up the token at a given
+//!   index would scan the ranges.
+//! - Global enumeration is not provided: token IDs are sequential, so all the
+//!   tokens can be enumerated from `0` to
+//!   [`crate::non_fungible::sequential::next_token_id`], skipping the burned
+//!   ones.
+//!
+//! ## Usage
+//!
+//! - **IMPORTANT**: For minting tokens ONLY the function
+//!   [`EnumerableConsecutive::batch_mint`] must be used. Using other minting
+//!   functions will break the logic of tracking ownership and enumeration.
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contracttrait, Address, Env};
+pub use storage::{
+    EnumerableConsecutive, NFTEnumerableConsecutiveStorageKey, OwnerRangesKey, TokenRange,
+};
+
+use crate::non_fungible::NonFungibleToken;
+
+/// Enumerable Consecutive Trait for Non-Fungible Token
+///
+/// The `NonFungibleEnumerableConsecutive` trait extends the `NonFungibleToken`
+/// trait to enumerate the tokens of an account, for contracts minting with
+/// [`EnumerableConsecutive::batch_mint`].
+///
+/// Enumerating all the tokens of an account is achieved range by range: the
+/// ranges of an owner are indexed from `0` to
+/// [`NonFungibleEnumerableConsecutive::owner_range_count()`]` - 1`, and can be
+/// retrieved with [`NonFungibleEnumerableConsecutive::get_owner_range()`].
+///
+/// # Notes
+///
+/// The `EnumerableConsecutive` contract type provides its own business logic
+/// for creating and destroying tokens, compatible with the
+/// [`crate::non_fungible::extensions::burnable::NonFungibleBurnable`] trait.
+/// It is INCOMPATIBLE with the `Enumerable` and `Consecutive` extensions.
+#[contracttrait]
+pub trait NonFungibleEnumerableConsecutive:
+    NonFungibleToken<ContractType = EnumerableConsecutive>
+{
+    /// Returns the total amount of tokens stored by the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn total_supply(e: &Env) -> u32 {
+        EnumerableConsecutive::total_supply(e)
+    }
+
+    /// Returns the number of ranges of token IDs owned by `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - Account of the tokens' owner.
+    fn owner_range_count(e: &Env, owner: Address) -> u32 {
+        EnumerableConsecutive::owner_range_count(e, &owner)
+    }
+
+    /// Returns the range of token IDs owned by `owner` at a given `index` in
+    /// the owner's local list. Use along with
+    /// [`NonFungibleEnumerableConsecutive::owner_range_count`] to enumerate
+    /// all of `owner`'s tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - Account of the tokens' owner.
+    /// * `index` - Index of the range in the owner's local list.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::non_fungible::NonFungibleTokenError::TokenNotFoundInOwnerList`] - When the index
+    ///   is out of bounds.
+    fn get_owner_range(e: &Env, owner: Address, index: u32) -> TokenRange {
+        EnumerableConsecutive::get_owner_range(e, &owner, index)
+    }
+}
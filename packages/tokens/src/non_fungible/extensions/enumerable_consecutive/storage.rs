@@ -0,0 +1,422 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, String};
+
+use crate::non_fungible::{
+    burnable::emit_burn, consecutive::Consecutive, emit_transfer, overrides::BurnableOverrides,
+    Base, ContractOverrides, NonFungibleTokenError, OWNER_EXTEND_AMOUNT, OWNER_TTL_THRESHOLD,
+};
+
+pub struct EnumerableConsecutive;
+
+impl ContractOverrides for EnumerableConsecutive {
+    fn owner_of(e: &Env, token_id: u32) -> Address {
+        Consecutive::owner_of(e, token_id)
+    }
+
+    fn token_uri(e: &Env, token_id: u32) -> String {
+        Consecutive::token_uri(e, token_id)
+    }
+
+    fn transfer(e: &Env, from: &Address, to: &Address, token_id: u32) {
+        EnumerableConsecutive::transfer(e, from, to, token_id);
+    }
+
+    fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, token_id: u32) {
+        EnumerableConsecutive::transfer_from(e, spender, from, to, token_id);
+    }
+
+    fn approve(
+        e: &Env,
+        approver: &Address,
+        approved: &Address,
+        token_id: u32,
+        live_until_ledger: u32,
+    ) {
+        Consecutive::approve(e, approver, approved, token_id, live_until_ledger);
+    }
+}
+
+impl BurnableOverrides for EnumerableConsecutive {
+    fn burn(e: &Env, from: &Address, token_id: u32) {
+        EnumerableConsecutive::burn(e, from, token_id);
+    }
+
+    fn burn_from(e: &Env, spender: &Address, from: &Address, token_id: u32) {
+        EnumerableConsecutive::burn_from(e, spender, from, token_id);
+    }
+}
+
+/// Range of consecutive token IDs, from `start` to `end` inclusive.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenRange {
+    pub start: u32,
+    pub end: u32,
+}
+
+#[contracttype]
+pub struct OwnerRangesKey {
+    pub owner: Address,
+    pub index: u32,
+}
+
+/// Storage keys for the data associated with the enumerable consecutive
+/// extension of `NonFungibleToken`
+#[contracttype]
+pub enum NFTEnumerableConsecutiveStorageKey {
+    TotalSupply,
+    OwnerRangeCount(Address),
+    OwnerRanges(OwnerRangesKey),
+    OwnerRangesIndex(/* end of the range */ u32),
+}
+
+impl EnumerableConsecutive {
+    // ################## QUERY STATE ##################
+
+    /// Returns the total amount of tokens stored by the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn total_supply(e: &Env) -> u32 {
+        e.storage().instance().get(&NFTEnumerableConsecutiveStorageKey::TotalSupply).unwrap_or(0)
+    }
+
+    /// Returns the number of ranges of token IDs owned by `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - Account of the tokens' owner.
+    pub fn owner_range_count(e: &Env, owner: &Address) -> u32 {
+        let key = NFTEnumerableConsecutiveStorageKey::OwnerRangeCount(owner.clone());
+        if let Some(count) = e.storage().persistent().get::<_, u32>(&key) {
+            e.storage().persistent().extend_ttl(&key, OWNER_TTL_THRESHOLD, OWNER_EXTEND_AMOUNT);
+            count
+        } else {
+            0
+        }
+    }
+
+    /// Returns the range of token IDs owned by `owner` at a given `index` in
+    /// the owner's local list. Use along with
+    /// [`EnumerableConsecutive::owner_range_count`] to enumerate all of
+    /// `owner`'s tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - Account of the tokens' owner.
+    /// * `index` - Index of the range in the owner's local list.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::TokenNotFoundInOwnerList`] - When the index
+    ///   is out of bounds.
+    pub fn get_owner_range(e: &Env, owner: &Address, index: u32) -> TokenRange {
+        let key = NFTEnumerableConsecutiveStorageKey::OwnerRanges(OwnerRangesKey {
+            owner: owner.clone(),
+            index,
+        });
+        let Some(range) = e.storage().persistent().get::<_, TokenRange>(&key) else {
+            panic_with_error!(e, NonFungibleTokenError::TokenNotFoundInOwnerList);
+        };
+        e.storage().persistent().extend_ttl(&key, OWNER_TTL_THRESHOLD, OWNER_EXTEND_AMOUNT);
+
+        range
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Mints a batch of tokens with consecutive IDs and attributes them to
+    /// `to`, adding a single range to its list. This function does NOT
+    /// handle authorization.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The address of the recipient.
+    /// * `amount` - The number of tokens to mint.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Consecutive::batch_mint`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["consecutive_mint", to: Address]`
+    /// * data - `[from_token_id: u32, to_token_id: u32]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: The function intentionally lacks authorization controls.
+    /// You MUST invoke it only from the constructor or implement proper
+    /// authorization in the calling function.
+    pub fn batch_mint(e: &Env, to: &Address, amount: u32) -> u32 {
+        let last_id = Consecutive::batch_mint(e, to, amount);
+        EnumerableConsecutive::add_range(
+            e,
+            to,
+            &TokenRange { start: last_id + 1 - amount, end: last_id },
+        );
+
+        // NOTE: can't overflow, as the supply is bounded by the token IDs.
+        let total_supply = EnumerableConsecutive::total_supply(e) + amount;
+        e.storage().instance().set(&NFTEnumerableConsecutiveStorageKey::TotalSupply, &total_supply);
+
+        last_id
+    }
+
+    /// Destroys the token with `token_id` from `from`, ensuring ownership
+    /// checks, and emits a `burn` event.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The account whose token is destroyed.
+    /// * `token_id` - The identifier of the token to burn.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`EnumerableConsecutive::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[token_id: u32]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn burn(e: &Env, from: &Address, token_id: u32) {
+        from.require_auth();
+        EnumerableConsecutive::update(e, Some(from), None, token_id);
+        emit_burn(e, from, token_id);
+    }
+
+    /// Destroys the token with `token_id` from `from`, ensuring ownership
+    /// and approval checks, and emits a `burn` event.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The account that is allowed to burn the token on behalf of
+    ///   the owner.
+    /// * `from` - The account whose token is destroyed.
+    /// * `token_id` - The identifier of the token to burn.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::check_spender_approval`] errors.
+    /// * refer to [`EnumerableConsecutive::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[token_id: u32]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `spender` is required.
+    pub fn burn_from(e: &Env, spender: &Address, from: &Address, token_id: u32) {
+        spender.require_auth();
+        Base::check_spender_approval(e, spender, from, token_id);
+        EnumerableConsecutive::update(e, Some(from), None, token_id);
+        emit_burn(e, from, token_id);
+    }
+
+    /// Transfers a non-fungible token (NFT), ensuring ownership checks.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment reference.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_id` - The identifier of the token being transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`EnumerableConsecutive::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: u32]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `from` is required.
+    /// * **IMPORTANT**: If the recipient is unable to receive, the NFT may get
+    ///   lost.
+    pub fn transfer(e: &Env, from: &Address, to: &Address, token_id: u32) {
+        from.require_auth();
+        EnumerableConsecutive::update(e, Some(from), Some(to), token_id);
+        emit_transfer(e, from, to, token_id);
+    }
+
+    /// Transfers a non-fungible token (NFT), ensuring ownership and approval
+    /// checks.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment reference.
+    /// * `spender` - The address attempting to transfer the token.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_id` - The identifier of the token being transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::check_spender_approval`] errors.
+    /// * refer to [`EnumerableConsecutive::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: u32]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `spender` is required.
+    /// * **IMPORTANT**: If the recipient is unable to receive, the NFT may get
+    ///   lost.
+    pub fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, token_id: u32) {
+        spender.require_auth();
+        Base::check_spender_approval(e, spender, from, token_id);
+        EnumerableConsecutive::update(e, Some(from), Some(to), token_id);
+        emit_transfer(e, from, to, token_id);
+    }
+
+    /// Low-level function wrapping [`Consecutive::update`], which additionally
+    /// moves `token_id` from the ranges of `from` to the ranges of `to`, and
+    /// decreases the total supply when burning.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address of the current token owner.
+    /// * `to` - The address of the token recipient.
+    /// * `token_id` - The identifier of the token to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Consecutive::update`] errors.
+    /// * [`NonFungibleTokenError::TokenNotFoundInOwnerList`] - If `token_id` is
+    ///   not in the ranges of `from`.
+    ///
+    /// # Notes
+    ///
+    /// Minting is not supported, use [`EnumerableConsecutive::batch_mint`]
+    /// instead.
+    pub fn update(e: &Env, from: Option<&Address>, to: Option<&Address>, token_id: u32) {
+        // The ownership marker of `token_id` is the end of the range containing
+        // it, and has to be resolved before `Consecutive::update` moves it.
+        let range_end = from.map(|_| Consecutive::ownership_marker(e, token_id));
+
+        Consecutive::update(e, from, to, token_id);
+
+        if let (Some(from_address), Some(range_end)) = (from, range_end) {
+            EnumerableConsecutive::remove_token(e, from_address, token_id, range_end);
+        }
+
+        match to {
+            Some(to_address) => EnumerableConsecutive::add_range(
+                e,
+                to_address,
+                &TokenRange { start: token_id, end: token_id },
+            ),
+            None => {
+                // NOTE: can't underflow, as the token existed.
+                let total_supply = EnumerableConsecutive::total_supply(e) - 1;
+                e.storage()
+                    .instance()
+                    .set(&NFTEnumerableConsecutiveStorageKey::TotalSupply, &total_supply);
+            }
+        }
+    }
+
+    // ################## INTERNAL HELPERS ##################
+
+    /// Adds `range` at the end of the ranges of `owner`.
+    fn add_range(e: &Env, owner: &Address, range: &TokenRange) {
+        let index = EnumerableConsecutive::owner_range_count(e, owner);
+        EnumerableConsecutive::set_range(e, owner, index, range);
+        e.storage()
+            .persistent()
+            .set(&NFTEnumerableConsecutiveStorageKey::OwnerRangeCount(owner.clone()), &(index + 1));
+    }
+
+    /// Removes `token_id` from the range of `owner` ending at `range_end`,
+    /// shrinking or splitting it if needed.
+    fn remove_token(e: &Env, owner: &Address, token_id: u32, range_end: u32) {
+        let key = NFTEnumerableConsecutiveStorageKey::OwnerRangesIndex(range_end);
+        let Some(index) = e.storage().persistent().get::<_, u32>(&key) else {
+            panic_with_error!(e, NonFungibleTokenError::TokenNotFoundInOwnerList);
+        };
+        let TokenRange { start, end } = EnumerableConsecutive::get_owner_range(e, owner, index);
+        if token_id < start || token_id > end {
+            panic_with_error!(e, NonFungibleTokenError::TokenNotFoundInOwnerList);
+        }
+
+        if start == end {
+            EnumerableConsecutive::remove_range(e, owner, index, end);
+            return;
+        }
+
+        if token_id == end {
+            e.storage().persistent().remove(&key);
+            EnumerableConsecutive::set_range(e, owner, index, &TokenRange { start, end: end - 1 });
+            return;
+        }
+
+        // The range keeps its end, hence its index entry, and the tokens
+        // before `token_id`, if any, are added as a new range.
+        EnumerableConsecutive::set_range(e, owner, index, &TokenRange { start: token_id + 1, end });
+        if token_id != start {
+            EnumerableConsecutive::add_range(e, owner, &TokenRange { start, end: token_id - 1 });
+        }
+    }
+
+    /// Removes the range of `owner` at `index`, ending at `range_end`, by
+    /// moving the last range of `owner` in its place.
+    fn remove_range(e: &Env, owner: &Address, index: u32, range_end: u32) {
+        // NOTE: can't underflow, as the range to be removed exists.
+        let last_index = EnumerableConsecutive::owner_range_count(e, owner) - 1;
+
+        // Before swap: [A, B, C, D]  (removing `B`, which is at index 1)
+        // After swap:  [A, D, C, D]  (`D` moves to index 1)
+        // After deletion: [A, D, C]  (last item is deleted, effectively removing `B`)
+        if index != last_index {
+            let last_range = EnumerableConsecutive::get_owner_range(e, owner, last_index);
+            EnumerableConsecutive::set_range(e, owner, index, &last_range);
+        }
+
+        e.storage().persistent().remove(&NFTEnumerableConsecutiveStorageKey::OwnerRanges(
+            OwnerRangesKey { owner: owner.clone(), index: last_index },
+        ));
+        e.storage()
+            .persistent()
+            .remove(&NFTEnumerableConsecutiveStorageKey::OwnerRangesIndex(range_end));
+
+        let count_key = NFTEnumerableConsecutiveStorageKey::OwnerRangeCount(owner.clone());
+        if last_index == 0 {
+            e.storage().persistent().remove(&count_key);
+        } else {
+            e.storage().persistent().set(&count_key, &last_index);
+        }
+    }
+
+    /// Stores `range` at `index` in the ranges of `owner`, along with the
+    /// index of the range, keyed by its end.
+    fn set_range(e: &Env, owner: &Address, index: u32, range: &TokenRange) {
+        e.storage().persistent().set(
+            &NFTEnumerableConsecutiveStorageKey::OwnerRanges(OwnerRangesKey {
+                owner: owner.clone(),
+                index,
+            }),
+            range,
+        );
+        e.storage()
+            .persistent()
+            .set(&NFTEnumerableConsecutiveStorageKey::OwnerRangesIndex(range.end), &index);
+    }
+}
@@ -0,0 +1,218 @@
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+
+use crate::non_fungible::{
+    enumerable_consecutive::{EnumerableConsecutive, TokenRange},
+    overrides::BurnableOverrides,
+    Base, ContractOverrides,
+};
+
+#[contract]
+struct MockContract;
+
+fn range(start: u32, end: u32) -> TokenRange {
+    TokenRange { start, end }
+}
+
+/// Enumerates the ranges of `owner`, sorted by their first token.
+fn owner_ranges(e: &Env, owner: &Address) -> std::vec::Vec<TokenRange> {
+    let mut ranges: std::vec::Vec<TokenRange> =
+        (0..EnumerableConsecutive::owner_range_count(e, owner))
+            .map(|i| EnumerableConsecutive::get_owner_range(e, owner, i))
+            .collect();
+    ranges.sort_by_key(|range| range.start);
+    ranges
+}
+
+#[test]
+fn batch_mint_adds_single_range() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let last_id = EnumerableConsecutive::batch_mint(&e, &owner, 1000);
+        assert_eq!(last_id, 999);
+
+        assert_eq!(EnumerableConsecutive::total_supply(&e), 1000);
+        assert_eq!(Base::balance(&e, &owner), 1000);
+        assert_eq!(EnumerableConsecutive::owner_range_count(&e, &owner), 1);
+        assert_eq!(EnumerableConsecutive::get_owner_range(&e, &owner, 0), range(0, 999));
+    });
+}
+
+#[test]
+fn consecutive_batch_mints_add_separate_ranges() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        EnumerableConsecutive::batch_mint(&e, &alice, 5);
+        EnumerableConsecutive::batch_mint(&e, &alice, 5);
+        EnumerableConsecutive::batch_mint(&e, &bob, 5);
+        EnumerableConsecutive::batch_mint(&e, &alice, 5);
+
+        assert_eq!(owner_ranges(&e, &alice), [range(0, 4), range(5, 9), range(15, 19)]);
+        assert_eq!(owner_ranges(&e, &bob), [range(10, 14)]);
+        assert_eq!(EnumerableConsecutive::owner_of(&e, 4), alice);
+        assert_eq!(EnumerableConsecutive::owner_of(&e, 12), bob);
+    });
+}
+
+#[test]
+fn transfer_splits_ranges() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        EnumerableConsecutive::batch_mint(&e, &alice, 10);
+    });
+
+    for token_id in [5, 6, 0, 9] {
+        e.as_contract(&address, || {
+            EnumerableConsecutive::transfer(&e, &alice, &bob, token_id);
+        });
+    }
+
+    e.as_contract(&address, || {
+        assert_eq!(owner_ranges(&e, &alice), [range(1, 4), range(7, 8)]);
+        assert_eq!(owner_ranges(&e, &bob), [range(0, 0), range(5, 5), range(6, 6), range(9, 9)]);
+        assert_eq!(Base::balance(&e, &alice), 6);
+        assert_eq!(Base::balance(&e, &bob), 4);
+        assert_eq!(EnumerableConsecutive::owner_of(&e, 6), bob);
+        assert_eq!(EnumerableConsecutive::owner_of(&e, 7), alice);
+        assert_eq!(EnumerableConsecutive::total_supply(&e), 10);
+    });
+
+    // transferring back a token adds a range of its own
+    e.as_contract(&address, || {
+        EnumerableConsecutive::transfer(&e, &bob, &alice, 5);
+
+        assert_eq!(owner_ranges(&e, &alice), [range(1, 4), range(5, 5), range(7, 8)]);
+        assert_eq!(owner_ranges(&e, &bob), [range(0, 0), range(6, 6), range(9, 9)]);
+    });
+}
+
+#[test]
+fn fragmented_ranges_keep_transfers_working() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let attacker = Address::generate(&e);
+    let victim = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        EnumerableConsecutive::batch_mint(&e, &victim, 1);
+        EnumerableConsecutive::batch_mint(&e, &attacker, 400);
+    });
+
+    // every other token is sent, so that none of the victim's ranges are
+    // adjacent
+    for token_id in (1..=400).step_by(2) {
+        e.as_contract(&address, || {
+            EnumerableConsecutive::transfer(&e, &attacker, &victim, token_id);
+        });
+    }
+
+    e.as_contract(&address, || {
+        assert_eq!(EnumerableConsecutive::owner_range_count(&e, &victim), 201);
+        assert_eq!(EnumerableConsecutive::get_owner_range(&e, &victim, 200), range(399, 399));
+    });
+
+    // the victim can still move its first token, and any of the others
+    e.as_contract(&address, || {
+        EnumerableConsecutive::transfer(&e, &victim, &recipient, 0);
+    });
+    e.as_contract(&address, || {
+        EnumerableConsecutive::transfer(&e, &victim, &recipient, 201);
+
+        assert_eq!(EnumerableConsecutive::owner_range_count(&e, &victim), 199);
+        assert_eq!(Base::balance(&e, &victim), 199);
+        assert_eq!(owner_ranges(&e, &recipient), [range(0, 0), range(201, 201)]);
+    });
+}
+
+#[test]
+fn transfer_from_with_approval_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        EnumerableConsecutive::batch_mint(&e, &alice, 3);
+        <EnumerableConsecutive as ContractOverrides>::approve(&e, &alice, &spender, 1, 1000);
+    });
+
+    e.as_contract(&address, || {
+        EnumerableConsecutive::transfer_from(&e, &spender, &alice, &bob, 1);
+
+        assert_eq!(owner_ranges(&e, &alice), [range(0, 0), range(2, 2)]);
+        assert_eq!(owner_ranges(&e, &bob), [range(1, 1)]);
+    });
+}
+
+#[test]
+fn burn_removes_token() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        EnumerableConsecutive::batch_mint(&e, &owner, 3);
+        <EnumerableConsecutive as BurnableOverrides>::burn(&e, &owner, 1);
+    });
+
+    e.as_contract(&address, || {
+        <EnumerableConsecutive as BurnableOverrides>::burn(&e, &owner, 0);
+
+        assert_eq!(owner_ranges(&e, &owner), [range(2, 2)]);
+        assert_eq!(EnumerableConsecutive::total_supply(&e), 1);
+        assert_eq!(Base::balance(&e, &owner), 1);
+    });
+
+    e.as_contract(&address, || {
+        <EnumerableConsecutive as BurnableOverrides>::burn(&e, &owner, 2);
+
+        assert_eq!(EnumerableConsecutive::owner_range_count(&e, &owner), 0);
+        assert_eq!(EnumerableConsecutive::total_supply(&e), 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #208)")]
+fn get_owner_range_out_of_bounds_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        EnumerableConsecutive::batch_mint(&e, &owner, 3);
+        EnumerableConsecutive::get_owner_range(&e, &owner, 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #201)")]
+fn transfer_not_owned_token_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        EnumerableConsecutive::batch_mint(&e, &alice, 3);
+        EnumerableConsecutive::transfer(&e, &bob, &alice, 1);
+    });
+}
@@ -0,0 +1,192 @@
+mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contractevent, contracttrait, Address, BytesN, Env};
+pub use storage::{MintVoucher, NFTLazyMintStorageKey, VoucherSigner};
+
+use crate::non_fungible::{Base, NonFungibleToken};
+
+/// Lazy Mint Trait for Non-Fungible Token
+///
+/// The `NonFungibleLazyMint` trait extends the `NonFungibleToken` trait to
+/// defer minting until a token is actually bought. Instead of minting every
+/// item upfront, an authorized signer issues off-chain vouchers
+/// ([`MintVoucher`]), and a buyer redeems a voucher on-chain to mint the token,
+/// paying its price to the payee of the voucher.
+///
+/// A voucher specifies:
+/// - the identifier of the token, or `None` to mint the next sequential one,
+/// - optionally, the only account allowed to redeem it,
+/// - the price and the SEP-41 token it is paid in, as well as the payee,
+/// - the timestamp after which it expires,
+/// - a nonce, making each voucher redeemable only once.
+///
+/// Vouchers are signed by the [`VoucherSigner`], either an ed25519 or a
+/// secp256r1 key, over the message built by
+/// [`crate::non_fungible::Base::build_voucher_message`], which binds the
+/// voucher to the network and to the token contract.
+///
+/// # Notes
+///
+/// * Redeeming a voucher for a token that already exists fails, so a minted
+///   token can never be taken from its holder. Vouchers with explicit token
+///   identifiers and sequential ones should still not be mixed, as the
+///   sequential counter is not aware of the explicitly minted identifiers, and
+///   sequential vouchers would fail once it reaches one of them.
+/// * The lazy minting logic relies on the `Base` contract type, this trait is
+///   therefore INCOMPATIBLE with the `Enumerable` and `Consecutive` extensions.
+/// * `set_voucher_signer` and `invalidate_voucher` have no default
+///   implementation, as a reminder for the implementor to provide the
+///   authorization logic.
+#[contracttrait]
+pub trait NonFungibleLazyMint: NonFungibleToken<ContractType = Base> {
+    /// Redeems `voucher` to mint a token to `redeemer`, and returns the
+    /// identifier of the minted token.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `redeemer` - The account redeeming the voucher and receiving the
+    ///   token.
+    /// * `voucher` - The voucher to redeem.
+    /// * `signature` - The signature of the voucher by the voucher signer.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::redeem_voucher`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[token_id: u32]`
+    ///
+    /// * topics - `["voucher_redeemed", redeemer: Address]`
+    /// * data - `[nonce: u64, token_id: u32]`
+    fn redeem_voucher(
+        e: &Env,
+        redeemer: Address,
+        voucher: MintVoucher,
+        signature: BytesN<64>,
+    ) -> u32 {
+        Base::redeem_voucher(e, &redeemer, &voucher, &signature)
+    }
+
+    /// Returns the signer of the vouchers.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::non_fungible::NonFungibleTokenError::VoucherSignerNotSet`] -
+    ///   When the voucher signer is not set.
+    fn voucher_signer(e: &Env) -> VoucherSigner {
+        Base::voucher_signer(e)
+    }
+
+    /// Returns whether the voucher with `nonce` was redeemed or invalidated.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `nonce` - The nonce of the voucher.
+    fn is_voucher_used(e: &Env, nonce: u64) -> bool {
+        Base::is_voucher_used(e, nonce)
+    }
+
+    /// Sets the signer of the vouchers. Vouchers signed by the previous
+    /// signer can no longer be redeemed.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `signer` - The new voucher signer.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["voucher_signer_set"]`
+    /// * data - `[signer: VoucherSigner]`
+    fn set_voucher_signer(e: &Env, signer: VoucherSigner, operator: Address);
+
+    /// Invalidates the voucher with `nonce`, so that it can't be redeemed.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `nonce` - The nonce of the voucher.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::non_fungible::NonFungibleTokenError::VoucherAlreadyUsed`] -
+    ///   When the voucher was already redeemed or invalidated.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["voucher_invalidated", nonce: u64]`
+    /// * data - `[]`
+    fn invalidate_voucher(e: &Env, nonce: u64, operator: Address);
+}
+
+// ################## EVENTS ##################
+
+/// Event emitted when a voucher is redeemed.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoucherRedeemed {
+    #[topic]
+    pub redeemer: Address,
+    pub nonce: u64,
+    pub token_id: u32,
+}
+
+/// Emits an event indicating a voucher was redeemed.
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+/// * `redeemer` - The account which redeemed the voucher.
+/// * `nonce` - The nonce of the voucher.
+/// * `token_id` - The identifier of the minted token.
+pub fn emit_voucher_redeemed(e: &Env, redeemer: &Address, nonce: u64, token_id: u32) {
+    VoucherRedeemed { redeemer: redeemer.clone(), nonce, token_id }.publish(e);
+}
+
+/// Event emitted when the voucher signer is set.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoucherSignerSet {
+    pub signer: VoucherSigner,
+}
+
+/// Emits an event indicating the voucher signer was set.
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+/// * `signer` - The new voucher signer.
+pub fn emit_voucher_signer_set(e: &Env, signer: &VoucherSigner) {
+    VoucherSignerSet { signer: signer.clone() }.publish(e);
+}
+
+/// Event emitted when a voucher is invalidated.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoucherInvalidated {
+    #[topic]
+    pub nonce: u64,
+}
+
+/// Emits an event indicating a voucher was invalidated.
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+/// * `nonce` - The nonce of the voucher.
+pub fn emit_voucher_invalidated(e: &Env, nonce: u64) {
+    VoucherInvalidated { nonce }.publish(e);
+}
@@ -0,0 +1,255 @@
+use soroban_sdk::{
+    contracttype, panic_with_error, token::TokenClient, xdr::ToXdr, Address, Bytes, BytesN, Env,
+};
+
+use crate::non_fungible::{
+    lazy_mint::{emit_voucher_invalidated, emit_voucher_redeemed, emit_voucher_signer_set},
+    sequential::increment_token_id,
+    Base, NFTStorageKey, NonFungibleTokenError, OWNER_EXTEND_AMOUNT, OWNER_TTL_THRESHOLD,
+};
+
+/// Voucher authorizing the lazy minting of a token
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MintVoucher {
+    /// Identifier of the token, or `None` for the next sequential one
+    pub token_id: Option<u32>,
+    /// Only account allowed to redeem the voucher, if any
+    pub recipient: Option<Address>,
+    /// SEP-41 token the price is paid in
+    pub payment_token: Address,
+    /// Price of the token, can be `0`
+    pub price: i128,
+    /// Account receiving the price
+    pub payee: Address,
+    /// Timestamp after which the voucher can't be redeemed
+    pub expiry: u64,
+    /// Unique number preventing the voucher from being redeemed twice
+    pub nonce: u64,
+}
+
+/// Public key of the voucher signer
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VoucherSigner {
+    Ed25519(BytesN<32>),
+    Secp256r1(BytesN<65>),
+}
+
+/// Storage keys for the data associated with the lazy mint extension of
+/// `NonFungibleToken`
+#[contracttype]
+pub enum NFTLazyMintStorageKey {
+    Signer,
+    UsedVoucher(u64),
+}
+
+impl Base {
+    /// Returns the signer of the vouchers.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::VoucherSignerNotSet`] - When the voucher
+    ///   signer is not set.
+    pub fn voucher_signer(e: &Env) -> VoucherSigner {
+        e.storage()
+            .instance()
+            .get(&NFTLazyMintStorageKey::Signer)
+            .unwrap_or_else(|| panic_with_error!(e, NonFungibleTokenError::VoucherSignerNotSet))
+    }
+
+    /// Returns whether the voucher with `nonce` was redeemed or invalidated.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `nonce` - The nonce of the voucher.
+    pub fn is_voucher_used(e: &Env, nonce: u64) -> bool {
+        let key = NFTLazyMintStorageKey::UsedVoucher(nonce);
+        let used = e.storage().persistent().has(&key);
+        if used {
+            e.storage().persistent().extend_ttl(&key, OWNER_TTL_THRESHOLD, OWNER_EXTEND_AMOUNT);
+        }
+        used
+    }
+
+    /// Builds the message signed by the voucher signer.
+    ///
+    /// The message format is: network_id || token_contract || voucher (XDR).
+    /// For secp256r1 signers, the SHA-256 digest of the message is signed.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `voucher` - The voucher.
+    pub fn build_voucher_message(e: &Env, voucher: &MintVoucher) -> Bytes {
+        let mut data = Bytes::from_array(e, &e.ledger().network_id().to_array());
+        data.append(&e.current_contract_address().to_xdr(e));
+        data.append(&voucher.clone().to_xdr(e));
+        data
+    }
+
+    /// Redeems `voucher` to mint a token to `redeemer`, after verifying the
+    /// voucher and its signature, and transferring its price from `redeemer`
+    /// to the payee. Returns the identifier of the minted token.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `redeemer` - The account redeeming the voucher and receiving the
+    ///   token.
+    /// * `voucher` - The voucher to redeem.
+    /// * `signature` - The signature of the voucher by the voucher signer.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::VoucherExpired`] - When the voucher has
+    ///   expired.
+    /// * [`NonFungibleTokenError::InvalidVoucherRecipient`] - When the voucher
+    ///   is restricted to another account.
+    /// * [`NonFungibleTokenError::InvalidVoucherPrice`] - When the price is
+    ///   negative.
+    /// * [`NonFungibleTokenError::VoucherAlreadyUsed`] - When the voucher was
+    ///   already redeemed or invalidated.
+    /// * [`NonFungibleTokenError::VoucherSignerNotSet`] - When the voucher
+    ///   signer is not set.
+    /// * [`NonFungibleTokenError::TokenAlreadyExists`] - When the token to be
+    ///   minted already exists.
+    /// * refer to [`Base::mint`] errors.
+    ///
+    /// The invocation also fails if the signature is invalid.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[token_id: u32]`
+    ///
+    /// * topics - `["voucher_redeemed", redeemer: Address]`
+    /// * data - `[nonce: u64, token_id: u32]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `redeemer` is required.
+    /// * The existence of the token is verified for both explicit and
+    ///   sequential identifiers, as the sequential counter doesn't account for
+    ///   explicit ones. Hence, when a signer mixes both kinds of vouchers, a
+    ///   sequential voucher fails once the counter reaches an explicit
+    ///   identifier already minted, and signers should stick to one kind.
+    pub fn redeem_voucher(
+        e: &Env,
+        redeemer: &Address,
+        voucher: &MintVoucher,
+        signature: &BytesN<64>,
+    ) -> u32 {
+        redeemer.require_auth();
+
+        if e.ledger().timestamp() > voucher.expiry {
+            panic_with_error!(e, NonFungibleTokenError::VoucherExpired);
+        }
+        if voucher.recipient.as_ref().is_some_and(|recipient| recipient != redeemer) {
+            panic_with_error!(e, NonFungibleTokenError::InvalidVoucherRecipient);
+        }
+        if voucher.price < 0 {
+            panic_with_error!(e, NonFungibleTokenError::InvalidVoucherPrice);
+        }
+
+        Base::use_voucher(e, voucher.nonce);
+        Base::verify_voucher_signature(e, voucher, signature);
+
+        if voucher.price > 0 {
+            TokenClient::new(e, &voucher.payment_token).transfer(
+                redeemer,
+                &voucher.payee,
+                &voucher.price,
+            );
+        }
+
+        let token_id = voucher.token_id.unwrap_or_else(|| increment_token_id(e, 1));
+        if e.storage().persistent().has(&NFTStorageKey::Owner(token_id)) {
+            panic_with_error!(e, NonFungibleTokenError::TokenAlreadyExists);
+        }
+        Base::mint(e, redeemer, token_id);
+
+        emit_voucher_redeemed(e, redeemer, voucher.nonce, token_id);
+
+        token_id
+    }
+
+    /// Sets the signer of the vouchers.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `signer` - The new voucher signer.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["voucher_signer_set"]`
+    /// * data - `[signer: VoucherSigner]`
+    ///
+    /// # Notes
+    ///
+    /// **IMPORTANT**: This function lacks authorization controls. Most likely,
+    /// you want to invoke it from a constructor or from another function
+    /// with admin-only authorization.
+    pub fn set_voucher_signer(e: &Env, signer: &VoucherSigner) {
+        e.storage().instance().set(&NFTLazyMintStorageKey::Signer, signer);
+        emit_voucher_signer_set(e, signer);
+    }
+
+    /// Invalidates the voucher with `nonce`, so that it can't be redeemed.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `nonce` - The nonce of the voucher.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::VoucherAlreadyUsed`] - When the voucher was
+    ///   already redeemed or invalidated.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["voucher_invalidated", nonce: u64]`
+    /// * data - `[]`
+    ///
+    /// # Notes
+    ///
+    /// **IMPORTANT**: This function lacks authorization controls. Most likely,
+    /// you want to invoke it from another function with admin-only
+    /// authorization.
+    pub fn invalidate_voucher(e: &Env, nonce: u64) {
+        Base::use_voucher(e, nonce);
+        emit_voucher_invalidated(e, nonce);
+    }
+
+    // ################## INTERNAL HELPERS ##################
+
+    /// Marks the voucher with `nonce` as used, panicking if it already is.
+    fn use_voucher(e: &Env, nonce: u64) {
+        if Base::is_voucher_used(e, nonce) {
+            panic_with_error!(e, NonFungibleTokenError::VoucherAlreadyUsed);
+        }
+        e.storage().persistent().set(&NFTLazyMintStorageKey::UsedVoucher(nonce), &());
+    }
+
+    /// Verifies the signature of `voucher` by the voucher signer, panicking if
+    /// it is invalid.
+    fn verify_voucher_signature(e: &Env, voucher: &MintVoucher, signature: &BytesN<64>) {
+        let message = Base::build_voucher_message(e, voucher);
+        match Base::voucher_signer(e) {
+            VoucherSigner::Ed25519(public_key) => {
+                e.crypto().ed25519_verify(&public_key, &message, signature);
+            }
+            VoucherSigner::Secp256r1(public_key) => {
+                let digest = e.crypto().sha256(&message);
+                e.crypto().secp256r1_verify(&public_key, &digest, signature);
+            }
+        }
+    }
+}
@@ -0,0 +1,300 @@
+extern crate std;
+
+use ed25519_dalek::Signer as Ed25519Signer;
+use p256::{
+    ecdsa::{
+        signature::hazmat::PrehashSigner, Signature as Secp256r1Signature,
+        SigningKey as Secp256r1SigningKey,
+    },
+    elliptic_curve::sec1::ToEncodedPoint,
+    SecretKey as Secp256r1SecretKey,
+};
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger as _},
+    token::{StellarAssetClient, TokenClient},
+    Address, BytesN, Env,
+};
+use stellar_event_assertion::EventAssertion;
+
+use crate::non_fungible::{
+    lazy_mint::{MintVoucher, VoucherSigner},
+    Base,
+};
+
+#[contract]
+struct MockContract;
+
+const ED25519_SECRET_KEY: [u8; 32] = [7; 32];
+const SECP256R1_SECRET_KEY: [u8; 32] = [
+    33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56,
+    57, 58, 59, 60, 61, 62, 63, 64,
+];
+
+fn ed25519_signer(e: &Env) -> VoucherSigner {
+    let signing_key = ed25519_dalek::SigningKey::from_bytes(&ED25519_SECRET_KEY);
+    VoucherSigner::Ed25519(BytesN::from_array(e, signing_key.verifying_key().as_bytes()))
+}
+
+fn sign_ed25519(e: &Env, voucher: &MintVoucher) -> BytesN<64> {
+    let signing_key = ed25519_dalek::SigningKey::from_bytes(&ED25519_SECRET_KEY);
+    let message = Base::build_voucher_message(e, voucher);
+    let message_buf = message.to_buffer::<1024>();
+    let signature = signing_key.sign(message_buf.as_slice());
+    BytesN::from_array(e, &signature.to_bytes())
+}
+
+fn secp256r1_signer(e: &Env) -> VoucherSigner {
+    let secret_key = Secp256r1SecretKey::from_slice(&SECP256R1_SECRET_KEY).unwrap();
+    let pubkey = secret_key.public_key().to_encoded_point(false).to_bytes().to_vec();
+    let mut pubkey_slice = [0u8; 65];
+    pubkey_slice.copy_from_slice(&pubkey);
+    VoucherSigner::Secp256r1(BytesN::from_array(e, &pubkey_slice))
+}
+
+fn sign_secp256r1(e: &Env, voucher: &MintVoucher) -> BytesN<64> {
+    let secret_key = Secp256r1SecretKey::from_slice(&SECP256R1_SECRET_KEY).unwrap();
+    let signing_key = Secp256r1SigningKey::from(&secret_key);
+    let digest = e.crypto().sha256(&Base::build_voucher_message(e, voucher));
+    let signature: Secp256r1Signature = signing_key.sign_prehash(&digest.to_array()).unwrap();
+    let sig_slice = signature.normalize_s().unwrap_or(signature).to_bytes();
+    let mut sig = [0u8; 64];
+    sig.copy_from_slice(&sig_slice);
+    BytesN::from_array(e, &sig)
+}
+
+fn voucher(e: &Env, token_id: Option<u32>, recipient: Option<Address>, nonce: u64) -> MintVoucher {
+    MintVoucher {
+        token_id,
+        recipient,
+        payment_token: Address::generate(e),
+        price: 0,
+        payee: Address::generate(e),
+        expiry: 1000,
+        nonce,
+    }
+}
+
+#[test]
+fn redeem_voucher_ed25519_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let buyer = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_voucher_signer(&e, &ed25519_signer(&e));
+
+        let voucher = voucher(&e, Some(42), Some(buyer.clone()), 1);
+        let signature = sign_ed25519(&e, &voucher);
+        let token_id = Base::redeem_voucher(&e, &buyer, &voucher, &signature);
+
+        assert_eq!(token_id, 42);
+        assert_eq!(Base::owner_of(&e, 42), buyer);
+        assert!(Base::is_voucher_used(&e, 1));
+    });
+
+    let event_assert = EventAssertion::new(&e, address.clone());
+    event_assert.assert_event_count(3);
+}
+
+#[test]
+fn redeem_voucher_secp256r1_sequential_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let buyer = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_voucher_signer(&e, &secp256r1_signer(&e));
+
+        let voucher = voucher(&e, None, None, 7);
+        let signature = sign_secp256r1(&e, &voucher);
+        let token_id = Base::redeem_voucher(&e, &buyer, &voucher, &signature);
+
+        assert_eq!(Base::owner_of(&e, token_id), buyer);
+        assert_eq!(Base::balance(&e, &buyer), 1);
+    });
+}
+
+#[test]
+fn redeem_voucher_transfers_payment() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let buyer = Address::generate(&e);
+    let payee = Address::generate(&e);
+    let payment_token = e.register_stellar_asset_contract_v2(Address::generate(&e)).address();
+    StellarAssetClient::new(&e, &payment_token).mint(&buyer, &500);
+
+    e.as_contract(&address, || {
+        Base::set_voucher_signer(&e, &ed25519_signer(&e));
+
+        let voucher = MintVoucher {
+            payment_token: payment_token.clone(),
+            price: 300,
+            payee: payee.clone(),
+            ..voucher(&e, None, None, 1)
+        };
+        let signature = sign_ed25519(&e, &voucher);
+        Base::redeem_voucher(&e, &buyer, &voucher, &signature);
+    });
+
+    let token = TokenClient::new(&e, &payment_token);
+    assert_eq!(token.balance(&buyer), 200);
+    assert_eq!(token.balance(&payee), 300);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #222)")]
+fn redeem_expired_voucher_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let buyer = Address::generate(&e);
+    e.ledger().set_timestamp(1001);
+
+    e.as_contract(&address, || {
+        Base::set_voucher_signer(&e, &ed25519_signer(&e));
+
+        let voucher = voucher(&e, None, None, 1);
+        let signature = sign_ed25519(&e, &voucher);
+        Base::redeem_voucher(&e, &buyer, &voucher, &signature);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #223)")]
+fn redeem_voucher_twice_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let buyer = Address::generate(&e);
+    let voucher = voucher(&e, None, None, 1);
+
+    e.as_contract(&address, || {
+        Base::set_voucher_signer(&e, &ed25519_signer(&e));
+        let signature = sign_ed25519(&e, &voucher);
+        Base::redeem_voucher(&e, &buyer, &voucher, &signature);
+    });
+
+    e.as_contract(&address, || {
+        let signature = sign_ed25519(&e, &voucher);
+        Base::redeem_voucher(&e, &buyer, &voucher, &signature);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #232)")]
+fn redeem_vouchers_with_clashing_token_id_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let first_buyer = Address::generate(&e);
+    let second_buyer = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_voucher_signer(&e, &ed25519_signer(&e));
+        let voucher = voucher(&e, Some(42), None, 1);
+        let signature = sign_ed25519(&e, &voucher);
+        Base::redeem_voucher(&e, &first_buyer, &voucher, &signature);
+    });
+
+    e.as_contract(&address, || {
+        let voucher = voucher(&e, Some(42), None, 2);
+        let signature = sign_ed25519(&e, &voucher);
+        Base::redeem_voucher(&e, &second_buyer, &voucher, &signature);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #232)")]
+fn redeem_sequential_voucher_clashing_with_explicit_id_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let first_buyer = Address::generate(&e);
+    let second_buyer = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_voucher_signer(&e, &ed25519_signer(&e));
+        let voucher = voucher(&e, Some(0), None, 1);
+        let signature = sign_ed25519(&e, &voucher);
+        Base::redeem_voucher(&e, &first_buyer, &voucher, &signature);
+    });
+
+    e.as_contract(&address, || {
+        // the sequential counter is still at `0`
+        let voucher = voucher(&e, None, None, 2);
+        let signature = sign_ed25519(&e, &voucher);
+        Base::redeem_voucher(&e, &second_buyer, &voucher, &signature);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #223)")]
+fn redeem_invalidated_voucher_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let buyer = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_voucher_signer(&e, &ed25519_signer(&e));
+        Base::invalidate_voucher(&e, 1);
+
+        let voucher = voucher(&e, None, None, 1);
+        let signature = sign_ed25519(&e, &voucher);
+        Base::redeem_voucher(&e, &buyer, &voucher, &signature);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #224)")]
+fn redeem_voucher_wrong_recipient_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let buyer = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_voucher_signer(&e, &ed25519_signer(&e));
+
+        let voucher = voucher(&e, None, Some(Address::generate(&e)), 1);
+        let signature = sign_ed25519(&e, &voucher);
+        Base::redeem_voucher(&e, &buyer, &voucher, &signature);
+    });
+}
+
+#[test]
+#[should_panic]
+fn redeem_tampered_voucher_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let buyer = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_voucher_signer(&e, &ed25519_signer(&e));
+
+        let voucher = voucher(&e, Some(1), None, 1);
+        let signature = sign_ed25519(&e, &voucher);
+        let tampered = MintVoucher { token_id: Some(2), ..voucher };
+        Base::redeem_voucher(&e, &buyer, &tampered, &signature);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #221)")]
+fn redeem_voucher_without_signer_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let buyer = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let voucher = voucher(&e, None, None, 1);
+        let signature = sign_ed25519(&e, &voucher);
+        Base::redeem_voucher(&e, &buyer, &voucher, &signature);
+    });
+}
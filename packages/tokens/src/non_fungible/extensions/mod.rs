@@ -1,5 +1,14 @@
+pub mod batch;
 pub mod burnable;
 pub mod consecutive;
+pub mod drop;
 pub mod enumerable;
+pub mod enumerable_consecutive;
+pub mod lazy_mint;
+pub mod onchain_metadata;
+pub mod rentable;
 pub mod royalties;
+pub mod safe_transfer;
+pub mod soulbound;
+pub mod uri_storage;
 pub mod votes;
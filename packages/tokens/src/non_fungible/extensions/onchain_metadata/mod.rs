@@ -0,0 +1,105 @@
+mod storage;
+use crate::non_fungible::NonFungibleToken;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{Address, Env, Map, String};
+pub use storage::NFTOnChainMetadataStorageKey;
+
+/// On-chain Metadata Trait for Non-Fungible Token
+///
+/// The `NonFungibleOnChainMetadata` trait extends the `NonFungibleToken` trait
+/// to store the attributes (traits) of every token on-chain, as key/value
+/// pairs. The attributes can be queried by other contracts, e.g. for game
+/// logic, and are used to build the JSON metadata document of the token on
+/// the fly, without relying on off-chain storage.
+///
+/// To serve the JSON document as the token URI, override
+/// [`crate::non_fungible::NonFungibleToken::token_uri`]:
+///
+/// ```ignore
+/// fn token_uri(e: &Env, token_id: u32) -> String {
+///     // used to panic if non-existent token_id
+///     let _ = Self::ContractType::owner_of(e, token_id);
+///     Base::token_metadata_json(e, token_id)
+/// }
+/// ```
+///
+/// The document is served base64 encoded, as
+/// `data:application/json;base64,<document>`, and has the following shape,
+/// with the attributes sorted by key:
+///
+/// ```json
+/// {"name":"<name> #<token_id>","attributes":[{"trait_type":"<key>","value":"<value>"}]}
+/// ```
+///
+/// Setting or removing an attribute emits a `metadata_update` event from the
+/// [`crate::non_fungible::uri_storage`] extension, so that marketplaces
+/// refresh the metadata of the token. The attributes are removed when the
+/// token is burned, for both the `Base` and the `Consecutive` contract types.
+///
+/// # Notes
+///
+/// There is no default implementation for this trait on purpose.
+///
+/// Because, there are no default implementation to enforce how the
+/// authorization should be configured. Not providing a default implementation
+/// for this trait is a reminder for the implementor to provide the
+/// authorization logic for this trait.
+pub trait NonFungibleOnChainMetadata: NonFungibleToken {
+    /// Sets the attribute `key` of `token_id` to `value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    /// * `key` - The name of the attribute.
+    /// * `value` - The value of the attribute.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::non_fungible::NonFungibleTokenError::InvalidAttribute`] - If
+    ///   `key` or `value` is too long or not valid UTF-8.
+    /// * [`crate::non_fungible::NonFungibleTokenError::TooManyAttributes`] - If
+    ///   the token already has the maximum number of attributes.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["metadata_update", token_id: u32]`
+    /// * data - `[]`
+    fn set_attribute(e: &Env, token_id: u32, key: String, value: String, operator: Address);
+
+    /// Removes the attribute `key` of `token_id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    /// * `key` - The name of the attribute.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["metadata_update", token_id: u32]`
+    /// * data - `[]`
+    fn remove_attribute(e: &Env, token_id: u32, key: String, operator: Address);
+
+    /// Returns the value of the attribute `key` of `token_id`, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    /// * `key` - The name of the attribute.
+    fn attribute(e: &Env, token_id: u32, key: String) -> Option<String>;
+
+    /// Returns all the attributes of `token_id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    fn attributes(e: &Env, token_id: u32) -> Map<String, String>;
+}
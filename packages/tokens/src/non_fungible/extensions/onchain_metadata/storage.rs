@@ -0,0 +1,298 @@
+use soroban_sdk::{contracttype, panic_with_error, Bytes, Env, Map, String};
+
+use crate::non_fungible::{
+    uri_storage::emit_metadata_update, Base, NonFungibleTokenError, MAX_ATTRIBUTES,
+    MAX_ATTRIBUTE_KEY_LEN, MAX_ATTRIBUTE_VALUE_LEN, MAX_NAME_LEN, MAX_NUM_DIGITS,
+    TOKEN_EXTEND_AMOUNT, TOKEN_TTL_THRESHOLD,
+};
+
+/// Max. length of an escaped JSON string: every byte may be escaped as
+/// `\u00XX`, plus the surrounding quotes.
+const MAX_ESCAPED_LEN: usize = 6 * MAX_ATTRIBUTE_VALUE_LEN + 2;
+
+/// Length of the chunks of the JSON document encoded at once, a multiple of 3
+/// so that only the last chunk is padded.
+const BASE64_CHUNK_LEN: u32 = 192;
+
+const BASE64_ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Storage keys for the data associated with the on-chain metadata extension
+/// of `NonFungibleToken`
+#[contracttype]
+pub enum NFTOnChainMetadataStorageKey {
+    Attributes(u32),
+    /// Instance flag set once an attribute has been set for any token, so
+    /// that collections never using the extension skip the cleanup on burns.
+    Enabled,
+}
+
+impl Base {
+    // ################## QUERY STATE ##################
+
+    /// Returns whether an attribute has ever been set for a token of the
+    /// collection. Reads instance storage only, which is loaded along with
+    /// the contract, so it adds no ledger entry read to burns.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn onchain_metadata_enabled(e: &Env) -> bool {
+        e.storage().instance().has(&NFTOnChainMetadataStorageKey::Enabled)
+    }
+
+    /// Returns all the attributes of `token_id`, sorted by key.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    pub fn attributes(e: &Env, token_id: u32) -> Map<String, String> {
+        let key = NFTOnChainMetadataStorageKey::Attributes(token_id);
+        if let Some(attributes) = e.storage().persistent().get::<_, Map<String, String>>(&key) {
+            e.storage().persistent().extend_ttl(&key, TOKEN_TTL_THRESHOLD, TOKEN_EXTEND_AMOUNT);
+            attributes
+        } else {
+            Map::new(e)
+        }
+    }
+
+    /// Returns the value of the attribute `key` of `token_id`, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    /// * `key` - The name of the attribute.
+    pub fn attribute(e: &Env, token_id: u32, key: &String) -> Option<String> {
+        Base::attributes(e, token_id).get(key.clone())
+    }
+
+    /// Builds the JSON metadata document of `token_id` from its name and
+    /// on-chain attributes, as a base64 encoded `data:` URI.
+    ///
+    /// The document is base64 encoded, as characters such as `#` or `%`, in
+    /// the name or in the attributes, would otherwise be interpreted by URL
+    /// parsers.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::name`] errors.
+    ///
+    /// # Notes
+    ///
+    /// The existence of `token_id` is not checked, so that the function can be
+    /// used with every contract type.
+    pub fn token_metadata_json(e: &Env, token_id: u32) -> String {
+        let mut json = Bytes::from_slice(e, b"{\"name\":");
+
+        // The name and the token id are escaped as a single JSON string.
+        let name = Base::name(e);
+        let (id, digits) = Base::token_id_to_string(e, token_id);
+        let len = name.len() as usize;
+        let mut full_name = [0u8; MAX_NAME_LEN + 2 + MAX_NUM_DIGITS];
+        name.copy_into_slice(&mut full_name[..len]);
+        full_name[len..len + 2].copy_from_slice(b" #");
+        id.copy_into_slice(&mut full_name[len + 2..len + 2 + digits]);
+        append_json_string(e, &mut json, &full_name[..len + 2 + digits]);
+
+        json.extend_from_slice(b",\"attributes\":[");
+        for (i, (key, value)) in Base::attributes(e, token_id).iter().enumerate() {
+            if i > 0 {
+                json.push_back(b',');
+            }
+            json.extend_from_slice(b"{\"trait_type\":");
+            append_soroban_string(e, &mut json, &key);
+            json.extend_from_slice(b",\"value\":");
+            append_soroban_string(e, &mut json, &value);
+            json.push_back(b'}');
+        }
+        json.extend_from_slice(b"]}");
+
+        let mut uri = Bytes::from_slice(e, b"data:application/json;base64,");
+        append_base64(&mut uri, &json);
+        String::from(uri)
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Sets the attribute `key` of `token_id` to `value`, and emits a
+    /// `metadata_update` event.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    /// * `key` - The name of the attribute.
+    /// * `value` - The value of the attribute.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidAttribute`] - If `key` is empty or
+    ///   longer than [`MAX_ATTRIBUTE_KEY_LEN`], if `value` is longer than
+    ///   [`MAX_ATTRIBUTE_VALUE_LEN`], or if either is not valid UTF-8.
+    /// * [`NonFungibleTokenError::TooManyAttributes`] - If adding the attribute
+    ///   would exceed [`MAX_ATTRIBUTES`].
+    ///
+    /// # Events
+    ///
+    /// * topics - `["metadata_update", token_id: u32]`
+    /// * data - `[]`
+    ///
+    /// # Notes
+    ///
+    /// **IMPORTANT**: This function lacks authorization controls. Most likely,
+    /// you want to invoke it from another function with admin-only
+    /// authorization.
+    ///
+    /// The existence of `token_id` is not checked, so that the function can be
+    /// used with every contract type, and the attributes can be set before
+    /// minting. The attributes are removed when the token is burned (see
+    /// [`Base::remove_attributes`]), so attributes set for a burned identifier
+    /// before reminting it are kept.
+    pub fn set_attribute(e: &Env, token_id: u32, key: &String, value: &String) {
+        if key.is_empty() || !is_valid_utf8(key, MAX_ATTRIBUTE_KEY_LEN) {
+            panic_with_error!(e, NonFungibleTokenError::InvalidAttribute);
+        }
+        if !is_valid_utf8(value, MAX_ATTRIBUTE_VALUE_LEN) {
+            panic_with_error!(e, NonFungibleTokenError::InvalidAttribute);
+        }
+
+        let mut attributes = Base::attributes(e, token_id);
+        if !attributes.contains_key(key.clone()) && attributes.len() >= MAX_ATTRIBUTES {
+            panic_with_error!(e, NonFungibleTokenError::TooManyAttributes);
+        }
+        attributes.set(key.clone(), value.clone());
+        e.storage()
+            .persistent()
+            .set(&NFTOnChainMetadataStorageKey::Attributes(token_id), &attributes);
+        e.storage().instance().set(&NFTOnChainMetadataStorageKey::Enabled, &true);
+
+        emit_metadata_update(e, token_id);
+    }
+
+    /// Removes the attribute `key` of `token_id`, and emits a
+    /// `metadata_update` event if the attribute existed.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    /// * `key` - The name of the attribute.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["metadata_update", token_id: u32]`
+    /// * data - `[]`
+    ///
+    /// # Notes
+    ///
+    /// **IMPORTANT**: This function lacks authorization controls. Most likely,
+    /// you want to invoke it from another function with admin-only
+    /// authorization.
+    pub fn remove_attribute(e: &Env, token_id: u32, key: &String) {
+        let mut attributes = Base::attributes(e, token_id);
+        if attributes.remove(key.clone()).is_none() {
+            return;
+        }
+
+        let storage_key = NFTOnChainMetadataStorageKey::Attributes(token_id);
+        if attributes.is_empty() {
+            e.storage().persistent().remove(&storage_key);
+        } else {
+            e.storage().persistent().set(&storage_key, &attributes);
+        }
+
+        emit_metadata_update(e, token_id);
+    }
+
+    /// Low-level function for removing all the attributes of `token_id`,
+    /// without handling authorization. Called by [`Base::update`] and
+    /// [`crate::non_fungible::consecutive::Consecutive::update`] when the token
+    /// is burned, so that a token reminted with the same identifier doesn't
+    /// inherit the attributes of the burned one. Does nothing for collections
+    /// that never set an attribute.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    pub fn remove_attributes(e: &Env, token_id: u32) {
+        if !Base::onchain_metadata_enabled(e) {
+            return;
+        }
+        e.storage().persistent().remove(&NFTOnChainMetadataStorageKey::Attributes(token_id));
+    }
+}
+
+// ################## INTERNAL HELPERS ##################
+
+/// Returns whether `value` is valid UTF-8 and at most `max_len` bytes long.
+fn is_valid_utf8(value: &String, max_len: usize) -> bool {
+    let len = value.len() as usize;
+    if len > max_len {
+        return false;
+    }
+    let mut buf = [0u8; MAX_ATTRIBUTE_VALUE_LEN];
+    value.copy_into_slice(&mut buf[..len]);
+    core::str::from_utf8(&buf[..len]).is_ok()
+}
+
+/// Appends `value`, previously validated by [`Base::set_attribute`], to
+/// `json` as an escaped JSON string.
+fn append_soroban_string(e: &Env, json: &mut Bytes, value: &String) {
+    let len = value.len() as usize;
+    let mut buf = [0u8; MAX_ATTRIBUTE_VALUE_LEN];
+    value.copy_into_slice(&mut buf[..len]);
+    append_json_string(e, json, &buf[..len]);
+}
+
+/// Appends `value` to `json` as an escaped JSON string.
+fn append_json_string(e: &Env, json: &mut Bytes, value: &[u8]) {
+    let Ok(value) = core::str::from_utf8(value) else {
+        panic_with_error!(e, NonFungibleTokenError::InvalidAttribute);
+    };
+    let mut buf = [0u8; MAX_ESCAPED_LEN];
+    let Ok(written) = serde_json_core::to_slice(value, &mut buf) else {
+        panic_with_error!(e, NonFungibleTokenError::InvalidAttribute);
+    };
+    json.extend_from_slice(&buf[..written]);
+}
+
+/// Appends the base64 encoding (standard alphabet, with padding) of `data` to
+/// `out`.
+fn append_base64(out: &mut Bytes, data: &Bytes) {
+    let mut src = [0u8; BASE64_CHUNK_LEN as usize];
+    let mut dst = [0u8; BASE64_CHUNK_LEN as usize / 3 * 4];
+    let len = data.len();
+    let mut start = 0;
+    while start < len {
+        let end = len.min(start + BASE64_CHUNK_LEN);
+        let n = (end - start) as usize;
+        data.slice(start..end).copy_into_slice(&mut src[..n]);
+        let written = base64_encode(&mut dst, &src[..n]);
+        out.extend_from_slice(&dst[..written]);
+        start = end;
+    }
+}
+
+/// Writes the base64 encoding of `src` into `dst`, and returns the number of
+/// bytes written.
+fn base64_encode(dst: &mut [u8], src: &[u8]) -> usize {
+    let mut di = 0;
+    for chunk in src.chunks(3) {
+        let val = (chunk[0] as usize) << 16
+            | (*chunk.get(1).unwrap_or(&0) as usize) << 8
+            | *chunk.get(2).unwrap_or(&0) as usize;
+        dst[di] = BASE64_ALPHABET[val >> 18 & 0x3F];
+        dst[di + 1] = BASE64_ALPHABET[val >> 12 & 0x3F];
+        dst[di + 2] = if chunk.len() > 1 { BASE64_ALPHABET[val >> 6 & 0x3F] } else { b'=' };
+        dst[di + 3] = if chunk.len() > 2 { BASE64_ALPHABET[val & 0x3F] } else { b'=' };
+        di += 4;
+    }
+    di
+}
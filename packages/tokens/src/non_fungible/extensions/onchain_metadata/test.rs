@@ -0,0 +1,220 @@
+extern crate std;
+
+use base64::Engine;
+use serde_json::{json, Value};
+use soroban_sdk::{contract, testutils::Address as _, Address, Env, String};
+use stellar_event_assertion::EventAssertion;
+
+use crate::non_fungible::{
+    consecutive::Consecutive, Base, MAX_ATTRIBUTES, MAX_ATTRIBUTE_VALUE_LEN,
+};
+
+#[contract]
+struct MockContract;
+
+fn setup_env() -> (Env, Address) {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    e.as_contract(&address, || {
+        Base::set_metadata(
+            &e,
+            String::from_str(&e, "https://example.com/"),
+            String::from_str(&e, "Heroes"),
+            String::from_str(&e, "HRO"),
+        );
+    });
+    (e, address)
+}
+
+/// Parses a URI returned by `Base::token_metadata_json` back to JSON, as
+/// marketplaces do.
+fn parse_metadata_uri(uri: &String) -> Value {
+    let mut buf = std::vec![0u8; uri.len() as usize];
+    uri.copy_into_slice(&mut buf);
+    let uri = std::string::String::from_utf8(buf).unwrap();
+    let body = uri.strip_prefix("data:application/json;base64,").unwrap();
+    let json = base64::engine::general_purpose::STANDARD.decode(body).unwrap();
+    serde_json::from_slice(&json).unwrap()
+}
+
+#[test]
+fn set_and_remove_attribute_works() {
+    let (e, address) = setup_env();
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        let key = String::from_str(&e, "level");
+
+        Base::set_attribute(&e, token_id, &key, &String::from_str(&e, "1"));
+        Base::set_attribute(&e, token_id, &key, &String::from_str(&e, "2"));
+        assert_eq!(Base::attribute(&e, token_id, &key), Some(String::from_str(&e, "2")));
+        assert_eq!(Base::attributes(&e, token_id).len(), 1);
+
+        Base::remove_attribute(&e, token_id, &key);
+        assert_eq!(Base::attribute(&e, token_id, &key), None);
+
+        // Removing a missing attribute is a no-op.
+        Base::remove_attribute(&e, token_id, &key);
+
+        // 1 `mint` and 3 `metadata_update` events
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(4);
+    });
+}
+
+#[test]
+fn burn_removes_attributes() {
+    let (e, address) = setup_env();
+    e.mock_all_auths();
+    let owner = Address::generate(&e);
+    let key = String::from_str(&e, "level");
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1);
+        Base::set_attribute(&e, 1, &key, &String::from_str(&e, "99"));
+
+        Base::burn(&e, &owner, 1);
+        assert!(Base::attributes(&e, 1).is_empty());
+    });
+
+    e.as_contract(&address, || {
+        // the reminted token doesn't inherit the traits of the burned one
+        Base::mint(&e, &owner, 1);
+        assert_eq!(Base::attribute(&e, 1, &key), None);
+    });
+}
+
+#[test]
+fn burn_removes_attributes_with_consecutive() {
+    let (e, address) = setup_env();
+    e.mock_all_auths();
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &owner, 2);
+        Base::set_attribute(&e, 1, &String::from_str(&e, "level"), &String::from_str(&e, "99"));
+
+        Consecutive::burn(&e, &owner, 1);
+        assert!(Base::attributes(&e, 1).is_empty());
+    });
+}
+
+#[test]
+fn token_metadata_json_works() {
+    let (e, address) = setup_env();
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::set_attribute(
+            &e,
+            token_id,
+            &String::from_str(&e, "weapon"),
+            &String::from_str(&e, "sword \"excalibur\""),
+        );
+        Base::set_attribute(
+            &e,
+            token_id,
+            &String::from_str(&e, "class"),
+            &String::from_str(&e, "knight"),
+        );
+
+        let expected = json!({
+            "name": "Heroes #0",
+            "attributes": [
+                { "trait_type": "class", "value": "knight" },
+                { "trait_type": "weapon", "value": "sword \"excalibur\"" },
+            ],
+        });
+        assert_eq!(parse_metadata_uri(&Base::token_metadata_json(&e, token_id)), expected);
+    });
+}
+
+#[test]
+fn token_metadata_json_without_attributes_works() {
+    let (e, address) = setup_env();
+
+    e.as_contract(&address, || {
+        let expected = json!({ "name": "Heroes #42", "attributes": [] });
+        assert_eq!(parse_metadata_uri(&Base::token_metadata_json(&e, 42)), expected);
+    });
+}
+
+#[test]
+fn token_metadata_json_with_url_characters_works() {
+    let (e, address) = setup_env();
+
+    e.as_contract(&address, || {
+        Base::set_attribute(
+            &e,
+            7,
+            &String::from_str(&e, "bonus #1"),
+            &String::from_str(&e, "100% épée?x=1&y=2"),
+        );
+
+        let uri = Base::token_metadata_json(&e, 7);
+        let expected = json!({
+            "name": "Heroes #7",
+            "attributes": [{ "trait_type": "bonus #1", "value": "100% épée?x=1&y=2" }],
+        });
+        assert_eq!(parse_metadata_uri(&uri), expected);
+    });
+}
+
+#[test]
+fn token_metadata_json_with_max_attributes_works() {
+    let (e, address) = setup_env();
+
+    e.as_contract(&address, || {
+        let value = std::string::String::from("v").repeat(MAX_ATTRIBUTE_VALUE_LEN);
+        for i in 0..MAX_ATTRIBUTES {
+            let key = std::format!("key{i}");
+            Base::set_attribute(&e, 0, &String::from_str(&e, &key), &String::from_str(&e, &value));
+        }
+
+        let json = parse_metadata_uri(&Base::token_metadata_json(&e, 0));
+        let attributes = json["attributes"].as_array().unwrap();
+        assert_eq!(attributes.len(), MAX_ATTRIBUTES as usize);
+        assert_eq!(attributes[9], json!({ "trait_type": "key9", "value": value }));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #217)")]
+fn too_many_attributes_panics() {
+    let (e, address) = setup_env();
+
+    e.as_contract(&address, || {
+        for i in 0..=MAX_ATTRIBUTES {
+            let key = std::format!("key{i}");
+            Base::set_attribute(&e, 0, &String::from_str(&e, &key), &String::from_str(&e, "v"));
+        }
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #218)")]
+fn attribute_value_too_long_panics() {
+    let (e, address) = setup_env();
+
+    e.as_contract(&address, || {
+        let value = std::string::String::from("v").repeat(MAX_ATTRIBUTE_VALUE_LEN + 1);
+        Base::set_attribute(&e, 0, &String::from_str(&e, "key"), &String::from_str(&e, &value));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #218)")]
+fn attribute_invalid_utf8_panics() {
+    let (e, address) = setup_env();
+
+    e.as_contract(&address, || {
+        Base::set_attribute(
+            &e,
+            0,
+            &String::from_str(&e, "key"),
+            &String::from_bytes(&e, &[0xff, 0xfe]),
+        );
+    });
+}
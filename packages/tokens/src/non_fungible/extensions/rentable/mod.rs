@@ -0,0 +1,104 @@
+mod storage;
+use crate::non_fungible::{Base, NonFungibleToken};
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contractevent, contracttrait, Address, Env};
+pub use storage::{NFTRentableStorageKey, UserInfo};
+
+/// Rentable Trait for Non-Fungible Token (ERC4907)
+///
+/// The `NonFungibleRentable` trait extends the `NonFungibleToken` trait to
+/// provide a `user` role, which can be granted for a limited time, separately
+/// from the ownership. The user has no control over the token, it is up to the
+/// applications (e.g. games, metaverses) to grant usage rights to the current
+/// user.
+///
+/// The user role is set by the owner of the token, or by an account approved
+/// for it, and expires at the ledger timestamp `expires`. The user role is
+/// cleared automatically when the token is transferred or burned.
+///
+/// `storage.rs` file of this module provides the `NonFungibleRentable` trait
+/// implementation for the `Base` contract type.
+///
+/// # Notes
+///
+/// The user role is cleared by [`Base::update`].
+/// Therefore, this trait is compatible with the `Base` and `Enumerable`
+/// contract types, but INCOMPATIBLE with the `Consecutive` extension.
+#[contracttrait]
+pub trait NonFungibleRentable: NonFungibleToken {
+    /// Sets the `user` of `token_id` until the ledger timestamp `expires`.
+    /// Setting `expires` to `0` removes the user.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `operator` - The owner of the token, or an account approved for it.
+    /// * `token_id` - The identifier of the token.
+    /// * `user` - The address of the new user.
+    /// * `expires` - The ledger timestamp at which the user role expires.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::non_fungible::NonFungibleTokenError::NonExistentToken`] - If
+    ///   the token does not exist.
+    /// * [`crate::non_fungible::NonFungibleTokenError::InsufficientApproval`] -
+    ///   If `operator` is neither the owner nor approved for the token.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["update_user", token_id: u32]`
+    /// * data - `[user: Option<Address>, expires: u64]`
+    fn set_user(e: &Env, operator: Address, token_id: u32, user: Address, expires: u64) {
+        Base::set_user(e, &operator, token_id, &user, expires);
+    }
+
+    /// Returns the current user of `token_id`, or `None` if there is no user
+    /// or the user role has expired.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    fn user_of(e: &Env, token_id: u32) -> Option<Address> {
+        Base::user_of(e, token_id)
+    }
+
+    /// Returns the ledger timestamp at which the user role of `token_id`
+    /// expires, or `0` if there is no user.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    fn user_expires(e: &Env, token_id: u32) -> u64 {
+        Base::user_expires(e, token_id)
+    }
+}
+
+// ################## EVENTS ##################
+
+/// Event emitted when the user of a token is set or cleared.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpdateUser {
+    #[topic]
+    pub token_id: u32,
+    pub user: Option<Address>,
+    pub expires: u64,
+}
+
+/// Emits an event indicating the user of a token was set, or cleared when
+/// `user` is `None`.
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+/// * `token_id` - The token identifier.
+/// * `user` - The new user, if any.
+/// * `expires` - The ledger timestamp at which the user role expires.
+pub fn emit_update_user(e: &Env, token_id: u32, user: Option<&Address>, expires: u64) {
+    UpdateUser { token_id, user: user.cloned(), expires }.publish(e);
+}
@@ -0,0 +1,143 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::non_fungible::{
+    rentable::emit_update_user, Base, TOKEN_EXTEND_AMOUNT, TOKEN_TTL_THRESHOLD,
+};
+
+/// Storage container for the user of a token and the ledger timestamp at which
+/// the user role expires.
+#[contracttype]
+pub struct UserInfo {
+    pub user: Address,
+    pub expires: u64,
+}
+
+/// Storage keys for the data associated with the rentable extension of
+/// `NonFungibleToken`
+#[contracttype]
+pub enum NFTRentableStorageKey {
+    User(u32),
+    /// Instance flag set once a user has been assigned to any token, so
+    /// that collections never using the extension skip the cleanup in
+    /// [`Base::update`].
+    Enabled,
+}
+
+impl Base {
+    // ################## QUERY STATE ##################
+
+    /// Returns the user information of `token_id`, including expired ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    pub fn user_info(e: &Env, token_id: u32) -> Option<UserInfo> {
+        let key = NFTRentableStorageKey::User(token_id);
+        let info = e.storage().persistent().get::<_, UserInfo>(&key);
+        if info.is_some() {
+            e.storage().persistent().extend_ttl(&key, TOKEN_TTL_THRESHOLD, TOKEN_EXTEND_AMOUNT);
+        }
+        info
+    }
+
+    /// Returns the current user of `token_id`, or `None` if there is no user
+    /// or the user role has expired.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    pub fn user_of(e: &Env, token_id: u32) -> Option<Address> {
+        Base::user_info(e, token_id)
+            .filter(|info| info.expires >= e.ledger().timestamp())
+            .map(|info| info.user)
+    }
+
+    /// Returns the ledger timestamp at which the user role of `token_id`
+    /// expires, or `0` if there is no user.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    pub fn user_expires(e: &Env, token_id: u32) -> u64 {
+        Base::user_info(e, token_id).map_or(0, |info| info.expires)
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Sets the `user` of `token_id` until the ledger timestamp `expires`.
+    /// Setting `expires` to `0` removes the user.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `operator` - The owner of the token, or an account approved for it.
+    /// * `token_id` - The identifier of the token.
+    /// * `user` - The address of the new user.
+    /// * `expires` - The ledger timestamp at which the user role expires.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::owner_of`] errors.
+    /// * refer to [`Base::check_spender_approval`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["update_user", token_id: u32]`
+    /// * data - `[user: Option<Address>, expires: u64]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `operator` is required.
+    pub fn set_user(e: &Env, operator: &Address, token_id: u32, user: &Address, expires: u64) {
+        operator.require_auth();
+        let owner = Base::owner_of(e, token_id);
+        Base::check_spender_approval(e, operator, &owner, token_id);
+
+        if expires == 0 {
+            Base::remove_user(e, token_id);
+            return;
+        }
+
+        let info = UserInfo { user: user.clone(), expires };
+        e.storage().persistent().set(&NFTRentableStorageKey::User(token_id), &info);
+        e.storage().instance().set(&NFTRentableStorageKey::Enabled, &true);
+
+        emit_update_user(e, token_id, Some(user), expires);
+    }
+
+    /// Low-level function for removing the user of `token_id`, without
+    /// handling authorization. Emits an event only if a user was set. Called
+    /// by [`Base::update`] whenever the token is transferred or burned, once
+    /// a user has been assigned to any token of the collection.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["update_user", token_id: u32]`
+    /// * data - `[user: None, expires: 0]`
+    pub fn remove_user(e: &Env, token_id: u32) {
+        let key = NFTRentableStorageKey::User(token_id);
+        if e.storage().persistent().has(&key) {
+            e.storage().persistent().remove(&key);
+            emit_update_user(e, token_id, None, 0);
+        }
+    }
+
+    /// Returns whether a user has ever been assigned to a token of the
+    /// collection. Reads instance storage only, which is loaded along with
+    /// the contract, so it adds no ledger entry read to transfers.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn rentable_enabled(e: &Env) -> bool {
+        e.storage().instance().has(&NFTRentableStorageKey::Enabled)
+    }
+}
@@ -0,0 +1,210 @@
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+use stellar_event_assertion::EventAssertion;
+
+use crate::non_fungible::{enumerable::Enumerable, Base};
+
+#[contract]
+struct MockContract;
+
+fn setup_env() -> (Env, Address) {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+    let address = e.register(MockContract, ());
+    (e, address)
+}
+
+#[test]
+fn set_user_works() {
+    let (e, address) = setup_env();
+    let owner = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        assert_eq!(Base::user_of(&e, token_id), None);
+        assert_eq!(Base::user_expires(&e, token_id), 0);
+
+        Base::set_user(&e, &owner, token_id, &user, 2000);
+
+        assert_eq!(Base::user_of(&e, token_id), Some(user.clone()));
+        assert_eq!(Base::user_expires(&e, token_id), 2000);
+        assert_eq!(Base::owner_of(&e, token_id), owner);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+    });
+}
+
+#[test]
+fn user_expires_with_time() {
+    let (e, address) = setup_env();
+    let owner = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token_id = e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::set_user(&e, &owner, token_id, &user, 2000);
+        token_id
+    });
+
+    e.ledger().set_timestamp(2000);
+    e.as_contract(&address, || {
+        assert_eq!(Base::user_of(&e, token_id), Some(user.clone()));
+    });
+
+    e.ledger().set_timestamp(2001);
+    e.as_contract(&address, || {
+        assert_eq!(Base::user_of(&e, token_id), None);
+        assert_eq!(Base::user_expires(&e, token_id), 2000);
+    });
+}
+
+#[test]
+fn set_user_by_approved_works() {
+    let (e, address) = setup_env();
+    let owner = Address::generate(&e);
+    let approved = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token_id = e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::approve(&e, &owner, &approved, token_id, 1000);
+        token_id
+    });
+
+    e.as_contract(&address, || {
+        Base::set_user(&e, &approved, token_id, &user, 2000);
+        assert_eq!(Base::user_of(&e, token_id), Some(user.clone()));
+    });
+}
+
+#[test]
+fn set_user_with_zero_expires_removes_user() {
+    let (e, address) = setup_env();
+    let owner = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token_id = e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::set_user(&e, &owner, token_id, &user, 2000);
+        token_id
+    });
+
+    e.as_contract(&address, || {
+        Base::set_user(&e, &owner, token_id, &user, 0);
+        assert_eq!(Base::user_of(&e, token_id), None);
+        assert_eq!(Base::user_expires(&e, token_id), 0);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+    });
+}
+
+#[test]
+fn transfer_clears_user() {
+    let (e, address) = setup_env();
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token_id = e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::set_user(&e, &owner, token_id, &user, 2000);
+        token_id
+    });
+
+    e.as_contract(&address, || {
+        Base::transfer(&e, &owner, &recipient, token_id);
+
+        assert_eq!(Base::user_of(&e, token_id), None);
+        assert_eq!(Base::user_expires(&e, token_id), 0);
+
+        // `update_user` and `transfer` events
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+    });
+}
+
+#[test]
+fn enumerable_transfer_clears_user() {
+    let (e, address) = setup_env();
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token_id = e.as_contract(&address, || {
+        let token_id = Enumerable::sequential_mint(&e, &owner);
+        Base::set_user(&e, &owner, token_id, &user, 2000);
+        token_id
+    });
+
+    e.as_contract(&address, || {
+        Enumerable::transfer(&e, &owner, &recipient, token_id);
+        assert_eq!(Base::user_of(&e, token_id), None);
+    });
+}
+
+#[test]
+fn transfer_without_users_skips_cleanup() {
+    let (e, address) = setup_env();
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        assert!(!Base::rentable_enabled(&e));
+
+        Base::transfer(&e, &owner, &recipient, token_id);
+        assert_eq!(Base::balance(&e, &recipient), 1);
+        assert!(!Base::rentable_enabled(&e));
+    });
+}
+
+#[test]
+fn burn_clears_user() {
+    let (e, address) = setup_env();
+    let owner = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token_id = e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::set_user(&e, &owner, token_id, &user, 2000);
+        token_id
+    });
+
+    e.as_contract(&address, || {
+        Base::burn(&e, &owner, token_id);
+        assert!(Base::user_info(&e, token_id).is_none());
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #202)")]
+fn set_user_without_approval_panics() {
+    let (e, address) = setup_env();
+    let owner = Address::generate(&e);
+    let stranger = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::set_user(&e, &stranger, token_id, &stranger, 2000);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #200)")]
+fn set_user_non_existent_token_panics() {
+    let (e, address) = setup_env();
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_user(&e, &owner, 1, &owner, 2000);
+    });
+}
@@ -4,7 +4,8 @@ use crate::non_fungible::NonFungibleToken;
 #[cfg(test)]
 mod test;
 
-use soroban_sdk::{contractevent, Address, Env};
+use soroban_sdk::{contractevent, Address, Env, Vec};
+pub use storage::{NFTRoyaltiesStorageKey, RoyaltyInfo};
 
 /// Royalties Trait for Non-Fungible Token (ERC2981)
 ///
@@ -21,6 +22,9 @@ use soroban_sdk::{contractevent, Address, Env};
 /// - Remove per-token royalties to fall-back to the global royalty set for the
 ///   contract
 ///
+/// Royalties split among multiple receivers are supported by the
+/// [`NonFungibleRoyaltySplits`] trait.
+///
 /// `storage.rs` file of this module provides the `NonFungibleRoyalties` trait
 /// implementation.
 ///
@@ -129,6 +133,117 @@ pub trait NonFungibleRoyalties: NonFungibleToken {
     fn royalty_info(e: &Env, token_id: u32, sale_price: i128) -> (Address, i128);
 }
 
+/// Royalty Splits Trait for Non-Fungible Token
+///
+/// The `NonFungibleRoyaltySplits` trait extends the `NonFungibleRoyalties`
+/// trait to split the royalty of the collection, or of a specific token, among
+/// multiple receivers, each with their own basis points (e.g. for artist
+/// collectives).
+///
+/// The sum of the basis points of all the receivers is bounded by the royalty
+/// cap, which applies to single receivers as well, and can be set with
+/// [`crate::non_fungible::Base::set_royalty_cap`].
+///
+/// For split royalties, [`NonFungibleRoyalties::royalty_info`] returns only
+/// the first receiver with its own share, so that single-receiver marketplaces
+/// never pay the shares of the other receivers to the first one.
+/// [`NonFungibleRoyaltySplits::royalty_splits`] is the only API returning the
+/// full royalty, and marketplaces have to use it to pay all the receivers.
+///
+/// # Notes
+///
+/// There is no default implementation for this trait on purpose.
+///
+/// Because, there are no default implementation to enforce how the
+/// authorization should be configured. Not providing a default implementation
+/// for this trait is a reminder for the implementor to provide the
+/// authorization logic for this trait.
+pub trait NonFungibleRoyaltySplits: NonFungibleRoyalties {
+    /// Sets the global default royalty for the entire collection, split among
+    /// multiple receivers.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `splits` - The receivers with their individual royalty in basis
+    ///   points.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::non_fungible::NonFungibleTokenError::InvalidRoyaltySplits`] -
+    ///   If `splits` is empty or has more than [`MAX_ROYALTY_RECEIVERS`]
+    ///   receivers.
+    /// * [`crate::non_fungible::NonFungibleTokenError::InvalidRoyaltyAmount`] -
+    ///   If the sum of the royalties is higher than the royalty cap.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["set_default_royalty_splits"]`
+    /// * data - `[splits: Vec<RoyaltyInfo>]`
+    fn set_default_royalty_splits(e: &Env, splits: Vec<RoyaltyInfo>, operator: Address);
+
+    /// Sets the royalty of a specific token, split among multiple receivers.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    /// * `splits` - The receivers with their individual royalty in basis
+    ///   points.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::non_fungible::NonFungibleTokenError::InvalidRoyaltySplits`] -
+    ///   If `splits` is empty or has more than [`MAX_ROYALTY_RECEIVERS`]
+    ///   receivers.
+    /// * [`crate::non_fungible::NonFungibleTokenError::InvalidRoyaltyAmount`] -
+    ///   If the sum of the royalties is higher than the royalty cap.
+    /// * [`crate::non_fungible::NonFungibleTokenError::NonExistentToken`] - If
+    ///   the token does not exist.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["set_token_royalty_splits", token_id: u32]`
+    /// * data - `[splits: Vec<RoyaltyInfo>]`
+    fn set_token_royalty_splits(
+        e: &Env,
+        token_id: u32,
+        splits: Vec<RoyaltyInfo>,
+        operator: Address,
+    );
+
+    /// Returns the list of `(Address, i128)` tuples containing each receiver
+    /// address and its royalty amount. The list is empty when no royalty is
+    /// set.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    /// * `sale_price` - The sale price for which royalties are being
+    ///   calculated.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::non_fungible::NonFungibleTokenError::NonExistentToken`] - If
+    ///   the token does not exist.
+    fn royalty_splits(e: &Env, token_id: u32, sale_price: i128) -> Vec<(Address, i128)>;
+
+    /// Returns the maximum total royalty, in basis points.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn royalty_cap(e: &Env) -> u32;
+}
+
+// ################## CONSTANTS ##################
+
+/// Maximum number of receivers a royalty can be split among.
+pub const MAX_ROYALTY_RECEIVERS: u32 = 10;
+
 // ################## EVENTS ##################
 
 /// Event emitted when default royalty is set.
@@ -191,3 +306,57 @@ pub struct RemoveTokenRoyalty {
 pub fn emit_remove_token_royalty(e: &Env, token_id: u32) {
     RemoveTokenRoyalty { token_id }.publish(e);
 }
+
+/// Event emitted when default royalty splits are set.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SetDefaultRoyaltySplits {
+    pub splits: Vec<RoyaltyInfo>,
+}
+
+/// Emits an event indicating that default royalty splits have been set.
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+/// * `splits` - The royalty receivers with their basis points.
+pub fn emit_set_default_royalty_splits(e: &Env, splits: &Vec<RoyaltyInfo>) {
+    SetDefaultRoyaltySplits { splits: splits.clone() }.publish(e);
+}
+
+/// Event emitted when token royalty splits are set.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SetTokenRoyaltySplits {
+    #[topic]
+    pub token_id: u32,
+    pub splits: Vec<RoyaltyInfo>,
+}
+
+/// Emits an event indicating that token royalty splits have been set.
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+/// * `token_id` - The token identifier.
+/// * `splits` - The royalty receivers with their basis points.
+pub fn emit_set_token_royalty_splits(e: &Env, token_id: u32, splits: &Vec<RoyaltyInfo>) {
+    SetTokenRoyaltySplits { token_id, splits: splits.clone() }.publish(e);
+}
+
+/// Event emitted when the royalty cap is set.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SetRoyaltyCap {
+    pub basis_points: u32,
+}
+
+/// Emits an event indicating that the royalty cap has been set.
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+/// * `basis_points` - The royalty cap in basis points.
+pub fn emit_set_royalty_cap(e: &Env, basis_points: u32) {
+    SetRoyaltyCap { basis_points }.publish(e);
+}
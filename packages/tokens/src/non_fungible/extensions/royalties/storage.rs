@@ -1,12 +1,19 @@
-use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Vec};
 
 use crate::non_fungible::{
-    royalties::{emit_set_default_royalty, emit_set_token_royalty},
+    royalties::{
+        emit_set_default_royalty, emit_set_default_royalty_splits, emit_set_royalty_cap,
+        emit_set_token_royalty, emit_set_token_royalty_splits, MAX_ROYALTY_RECEIVERS,
+    },
     Base, NonFungibleTokenError, OWNER_EXTEND_AMOUNT, OWNER_TTL_THRESHOLD,
 };
 
+/// Maximum royalty, in basis points, when no cap is set.
+const MAX_BASIS_POINTS: u32 = 10000;
+
 /// Storage container for royalty information
 #[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RoyaltyInfo {
     pub receiver: Address,
     pub basis_points: u32,
@@ -17,6 +24,9 @@ pub struct RoyaltyInfo {
 pub enum NFTRoyaltiesStorageKey {
     DefaultRoyalty,
     TokenRoyalty(u32),
+    DefaultRoyaltySplits,
+    TokenRoyaltySplits(u32),
+    RoyaltyCap,
 }
 
 impl Base {
@@ -39,7 +49,8 @@ impl Base {
     /// # Errors
     ///
     /// * [`NonFungibleTokenError::InvalidRoyaltyAmount`] - If the royalty
-    ///   amount is higher than 10_000 (100%) basis points.
+    ///   amount is higher than the royalty cap (10_000 basis points, i.e. 100%,
+    ///   by default).
     ///
     /// # Notes
     ///
@@ -48,14 +59,15 @@ impl Base {
     /// with admin-only authorization.
     pub fn set_default_royalty(e: &Env, receiver: &Address, basis_points: u32) {
         // check if basis points is valid
-        if basis_points > 10000 {
+        if basis_points > Base::royalty_cap(e) {
             panic_with_error!(e, NonFungibleTokenError::InvalidRoyaltyAmount);
         }
 
-        // Store the default royalty information
+        // Store the default royalty information, replacing any splits
         let key = NFTRoyaltiesStorageKey::DefaultRoyalty;
         let royalty_info = RoyaltyInfo { receiver: receiver.clone(), basis_points };
         e.storage().instance().set(&key, &royalty_info);
+        e.storage().instance().remove(&NFTRoyaltiesStorageKey::DefaultRoyaltySplits);
 
         emit_set_default_royalty(e, receiver, basis_points);
     }
@@ -80,7 +92,8 @@ impl Base {
     /// # Errors
     ///
     /// * [`NonFungibleTokenError::InvalidRoyaltyAmount`] - If the royalty
-    ///   amount is higher than 10_000 (100%) basis points.
+    ///   amount is higher than the royalty cap (10_000 basis points, i.e. 100%,
+    ///   by default).
     /// * refer to [`Base::owner_of`] errors.
     ///
     /// # Notes
@@ -90,17 +103,18 @@ impl Base {
     /// with admin-only authorization.
     pub fn set_token_royalty(e: &Env, token_id: u32, receiver: &Address, basis_points: u32) {
         // check if basis points is valid
-        if basis_points > 10000 {
+        if basis_points > Base::royalty_cap(e) {
             panic_with_error!(e, NonFungibleTokenError::InvalidRoyaltyAmount);
         }
 
         // Verify token exists by checking owner
         let _ = Base::owner_of(e, token_id);
 
-        // Store the token royalty information
+        // Store the token royalty information, replacing any splits
         let key = NFTRoyaltiesStorageKey::TokenRoyalty(token_id);
         let royalty_info = RoyaltyInfo { receiver: receiver.clone(), basis_points };
         e.storage().persistent().set(&key, &royalty_info);
+        e.storage().persistent().remove(&NFTRoyaltiesStorageKey::TokenRoyaltySplits(token_id));
 
         emit_set_token_royalty(e, receiver, token_id, basis_points);
     }
@@ -131,18 +145,22 @@ impl Base {
         // Verify token exists by checking owner
         let _ = Base::owner_of(e, token_id);
 
-        // Remove the token royalty information
-        let key = NFTRoyaltiesStorageKey::TokenRoyalty(token_id);
-        e.storage().persistent().remove(&key);
+        // Remove the token royalty information, including splits
+        e.storage().persistent().remove(&NFTRoyaltiesStorageKey::TokenRoyalty(token_id));
+        e.storage().persistent().remove(&NFTRoyaltiesStorageKey::TokenRoyaltySplits(token_id));
 
         super::emit_remove_token_royalty(e, token_id);
     }
 
-    /// Returns `(Address, u32)` - A tuple containing the receiver address and
+    /// Returns `(Address, i128)` - A tuple containing the receiver address and
     /// the royalty amount. If there is no token-specific royalty set, it
     /// returns the default royalty. If there is no default royalty set, it
     /// returns the contract address and zero royalty.
     ///
+    /// When the royalty is split among multiple receivers, only the first
+    /// receiver is returned, with its own share of the royalty. Marketplaces
+    /// have to use [`Base::royalty_splits`] to pay all the receivers.
+    ///
     /// # Arguments
     ///
     /// * `e` - Access to the Soroban environment.
@@ -156,9 +174,197 @@ impl Base {
     ///   exist.
     /// * refer to [`Base::owner_of`] errors.
     pub fn royalty_info(e: &Env, token_id: u32, sale_price: i128) -> (Address, i128) {
+        // No royalty set, return zero royalty
+        Base::royalty_splits(e, token_id, sale_price)
+            .first()
+            .unwrap_or_else(|| (e.current_contract_address(), 0))
+    }
+
+    /// Returns the list of `(Address, i128)` tuples containing each receiver
+    /// address and its royalty amount. If there is no token-specific royalty
+    /// set, it returns the default royalty. If there is no default royalty
+    /// set, it returns an empty list.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    /// * `sale_price` - The sale price for which royalties are being
+    ///   calculated.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::NonExistentToken`] - If the token does not
+    ///   exist.
+    /// * refer to [`Base::owner_of`] errors.
+    pub fn royalty_splits(e: &Env, token_id: u32, sale_price: i128) -> Vec<(Address, i128)> {
         // Verify token exists by checking owner
         let _ = Base::owner_of(e, token_id);
 
+        let mut amounts = Vec::new(e);
+        for royalty_info in Base::royalty_receivers(e, token_id).iter() {
+            let royalty_amount = sale_price * royalty_info.basis_points as i128 / 10000;
+            amounts.push_back((royalty_info.receiver, royalty_amount));
+        }
+        amounts
+    }
+
+    /// Returns the maximum total royalty, in basis points, that can be set
+    /// for the default royalty or any token. Defaults to 10_000 (100%).
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn royalty_cap(e: &Env) -> u32 {
+        e.storage().instance().get(&NFTRoyaltiesStorageKey::RoyaltyCap).unwrap_or(MAX_BASIS_POINTS)
+    }
+
+    /// Sets the maximum total royalty, in basis points, that can be set for
+    /// the default royalty or any token.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `basis_points` - The royalty cap in basis points (100 = 1%, 10000 =
+    ///   100%).
+    ///
+    /// # Events
+    ///
+    /// * topics - `["set_royalty_cap"]`
+    /// * data - `[basis_points: u32]`
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidRoyaltyAmount`] - If the cap is higher
+    ///   than 10_000 (100%) basis points.
+    ///
+    /// # Notes
+    ///
+    /// * The cap is enforced when royalties are set. Lowering it does not
+    ///   affect the royalties already set, so it is best set in the
+    ///   constructor.
+    /// * **IMPORTANT**: This function lacks authorization controls. Most
+    ///   likely, you want to invoke it from a constructor or from another
+    ///   function with admin-only authorization.
+    pub fn set_royalty_cap(e: &Env, basis_points: u32) {
+        if basis_points > MAX_BASIS_POINTS {
+            panic_with_error!(e, NonFungibleTokenError::InvalidRoyaltyAmount);
+        }
+
+        e.storage().instance().set(&NFTRoyaltiesStorageKey::RoyaltyCap, &basis_points);
+
+        emit_set_royalty_cap(e, basis_points);
+    }
+
+    /// Sets the global default royalty for the entire collection, split among
+    /// multiple receivers. This will be used for all tokens that don't have
+    /// specific royalty information.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `splits` - The receivers with their individual royalty in basis
+    ///   points.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["set_default_royalty_splits"]`
+    /// * data - `[splits: Vec<RoyaltyInfo>]`
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::validate_royalty_splits`] errors.
+    ///
+    /// # Notes
+    ///
+    /// **IMPORTANT**: This function lacks authorization controls. Most likely,
+    /// you want to invoke it from a constructor or from another function
+    /// with admin-only authorization.
+    pub fn set_default_royalty_splits(e: &Env, splits: &Vec<RoyaltyInfo>) {
+        Base::validate_royalty_splits(e, splits);
+
+        // Store the default royalty splits, replacing any single receiver
+        e.storage().instance().set(&NFTRoyaltiesStorageKey::DefaultRoyaltySplits, splits);
+        e.storage().instance().remove(&NFTRoyaltiesStorageKey::DefaultRoyalty);
+
+        emit_set_default_royalty_splits(e, splits);
+    }
+
+    /// Sets the royalty of a specific token, split among multiple receivers.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    /// * `splits` - The receivers with their individual royalty in basis
+    ///   points.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["set_token_royalty_splits", token_id: u32]`
+    /// * data - `[splits: Vec<RoyaltyInfo>]`
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::validate_royalty_splits`] errors.
+    /// * refer to [`Base::owner_of`] errors.
+    ///
+    /// # Notes
+    ///
+    /// **IMPORTANT**: This function lacks authorization controls. Most likely,
+    /// you want to invoke it from a constructor or from another function
+    /// with admin-only authorization.
+    pub fn set_token_royalty_splits(e: &Env, token_id: u32, splits: &Vec<RoyaltyInfo>) {
+        Base::validate_royalty_splits(e, splits);
+
+        // Verify token exists by checking owner
+        let _ = Base::owner_of(e, token_id);
+
+        // Store the token royalty splits, replacing any single receiver
+        e.storage().persistent().set(&NFTRoyaltiesStorageKey::TokenRoyaltySplits(token_id), splits);
+        e.storage().persistent().remove(&NFTRoyaltiesStorageKey::TokenRoyalty(token_id));
+
+        emit_set_token_royalty_splits(e, token_id, splits);
+    }
+
+    /// Validates royalty splits against the maximum number of receivers and
+    /// the royalty cap.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `splits` - The receivers with their individual royalty in basis
+    ///   points.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidRoyaltySplits`] - If `splits` is empty
+    ///   or has more than [`MAX_ROYALTY_RECEIVERS`] receivers.
+    /// * [`NonFungibleTokenError::InvalidRoyaltyAmount`] - If the sum of the
+    ///   royalties is higher than the royalty cap.
+    pub fn validate_royalty_splits(e: &Env, splits: &Vec<RoyaltyInfo>) {
+        if splits.is_empty() || splits.len() > MAX_ROYALTY_RECEIVERS {
+            panic_with_error!(e, NonFungibleTokenError::InvalidRoyaltySplits);
+        }
+
+        // NOTE: can't overflow, as there are at most `MAX_ROYALTY_RECEIVERS`
+        // entries, each checked against the cap.
+        let mut total_basis_points: u32 = 0;
+        let cap = Base::royalty_cap(e);
+        for split in splits.iter() {
+            if split.basis_points > cap {
+                panic_with_error!(e, NonFungibleTokenError::InvalidRoyaltyAmount);
+            }
+            total_basis_points += split.basis_points;
+        }
+        if total_basis_points > cap {
+            panic_with_error!(e, NonFungibleTokenError::InvalidRoyaltyAmount);
+        }
+    }
+
+    /// Returns the royalty receivers of `token_id`, falling back to the
+    /// default royalty, or an empty list if no royalty is set.
+    fn royalty_receivers(e: &Env, token_id: u32) -> Vec<RoyaltyInfo> {
         // Check if there's a specific royalty for this token
         let token_key = NFTRoyaltiesStorageKey::TokenRoyalty(token_id);
         if let Some(royalty_info) = e.storage().persistent().get::<_, RoyaltyInfo>(&token_key) {
@@ -167,18 +373,26 @@ impl Base {
                 OWNER_TTL_THRESHOLD,
                 OWNER_EXTEND_AMOUNT,
             );
-            let royalty_amount = sale_price * royalty_info.basis_points as i128 / 10000;
-            return (royalty_info.receiver, royalty_amount);
+            return Vec::from_array(e, [royalty_info]);
+        }
+        let splits_key = NFTRoyaltiesStorageKey::TokenRoyaltySplits(token_id);
+        if let Some(splits) = e.storage().persistent().get::<_, Vec<RoyaltyInfo>>(&splits_key) {
+            e.storage().persistent().extend_ttl(
+                &splits_key,
+                OWNER_TTL_THRESHOLD,
+                OWNER_EXTEND_AMOUNT,
+            );
+            return splits;
         }
 
         // Fall back to default royalty if no token-specific royalty is set
         let default_key = NFTRoyaltiesStorageKey::DefaultRoyalty;
         if let Some(royalty_info) = e.storage().instance().get::<_, RoyaltyInfo>(&default_key) {
-            let royalty_amount = sale_price * royalty_info.basis_points as i128 / 10000;
-            return (royalty_info.receiver, royalty_amount);
+            return Vec::from_array(e, [royalty_info]);
         }
-
-        // No royalty set, return zero royalty
-        (e.current_contract_address(), 0)
+        e.storage()
+            .instance()
+            .get(&NFTRoyaltiesStorageKey::DefaultRoyaltySplits)
+            .unwrap_or_else(|| Vec::new(e))
     }
 }
@@ -1,8 +1,11 @@
 extern crate std;
 
-use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+use soroban_sdk::{contract, testutils::Address as _, vec, Address, Env};
 
-use crate::non_fungible::{extensions::enumerable::Enumerable, Base};
+use crate::non_fungible::{
+    extensions::{enumerable::Enumerable, royalties::RoyaltyInfo},
+    Base,
+};
 
 #[contract]
 struct MockContract;
@@ -218,3 +221,128 @@ fn test_remove_token_royalty_no_default() {
         assert_eq!(royalty_amount, 0);
     });
 }
+
+#[test]
+fn test_set_default_royalty_splits() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let artist = Address::generate(&e);
+    let collective = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Enumerable::sequential_mint(&e, &Address::generate(&e));
+
+        Base::set_default_royalty_splits(
+            &e,
+            &vec![
+                &e,
+                RoyaltyInfo { receiver: artist.clone(), basis_points: 300 },
+                RoyaltyInfo { receiver: collective.clone(), basis_points: 200 },
+            ],
+        );
+
+        let splits = Base::royalty_splits(&e, token_id, 1000);
+        assert_eq!(splits, vec![&e, (artist.clone(), 30), (collective.clone(), 20)]);
+
+        // single-receiver query returns the first receiver with its own share
+        let (royalty_receiver, royalty_amount) = Base::royalty_info(&e, token_id, 1000);
+        assert_eq!(royalty_receiver, artist);
+        assert_eq!(royalty_amount, 30);
+    });
+}
+
+#[test]
+fn test_token_royalty_splits_override_default() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let default_receiver = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Enumerable::sequential_mint(&e, &Address::generate(&e));
+        Base::set_default_royalty(&e, &default_receiver, 1000);
+
+        Base::set_token_royalty_splits(
+            &e,
+            token_id,
+            &vec![
+                &e,
+                RoyaltyInfo { receiver: alice.clone(), basis_points: 100 },
+                RoyaltyInfo { receiver: bob.clone(), basis_points: 100 },
+            ],
+        );
+        assert_eq!(
+            Base::royalty_splits(&e, token_id, 1000),
+            vec![&e, (alice.clone(), 10), (bob.clone(), 10)]
+        );
+
+        // a single receiver replaces the splits
+        Base::set_token_royalty(&e, token_id, &alice, 500);
+        assert_eq!(Base::royalty_splits(&e, token_id, 1000), vec![&e, (alice.clone(), 50)]);
+
+        // removing the token royalty falls back to the default
+        Base::remove_token_royalty(&e, token_id);
+        assert_eq!(
+            Base::royalty_splits(&e, token_id, 1000),
+            vec![&e, (default_receiver.clone(), 100)]
+        );
+    });
+}
+
+#[test]
+fn test_no_royalty_splits_set() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        let token_id = Enumerable::sequential_mint(&e, &Address::generate(&e));
+        assert!(Base::royalty_splits(&e, token_id, 1000).is_empty());
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #212)")]
+fn test_royalty_splits_exceeding_cap() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Base::set_royalty_cap(&e, 1000);
+        assert_eq!(Base::royalty_cap(&e), 1000);
+
+        Base::set_default_royalty_splits(
+            &e,
+            &vec![
+                &e,
+                RoyaltyInfo { receiver: Address::generate(&e), basis_points: 600 },
+                RoyaltyInfo { receiver: Address::generate(&e), basis_points: 500 },
+            ],
+        );
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #212)")]
+fn test_royalty_exceeding_cap() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Base::set_royalty_cap(&e, 1000);
+        Base::set_default_royalty(&e, &Address::generate(&e), 1001);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #220)")]
+fn test_empty_royalty_splits() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Base::set_default_royalty_splits(&e, &vec![&e]);
+    });
+}
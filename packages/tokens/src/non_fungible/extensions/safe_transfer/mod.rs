@@ -0,0 +1,136 @@
+mod storage;
+use crate::non_fungible::{Base, ContractOverrides, NonFungibleToken};
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contractclient, contracttrait, Address, Bytes, Env};
+
+/// Safe Transfer Trait for Non-Fungible Token
+///
+/// The `NonFungibleSafeTransfer` trait extends the `NonFungibleToken` trait to
+/// provide transfers that make sure a contract recipient is able to handle
+/// the token, similarly to `safeTransferFrom` of ERC721.
+///
+/// When the recipient is a contract, it must implement the
+/// [`NonFungibleReceiver`] trait, and its `on_nft_received` hook must return
+/// `true`. Otherwise, the whole transfer is reverted. No check is performed
+/// when the recipient is an account.
+///
+/// The transfer itself is delegated to the `ContractType` associated type, so
+/// the trait is compatible with all the contract types (eg. `Enumerable`,
+/// `Consecutive`):
+///
+/// ```ignore
+/// impl NonFungibleSafeTransfer for ExampleContract {
+///     fn safe_transfer(e: &Env, from: Address, to: Address, token_id: u32, data: Bytes) {
+///         Self::ContractType::transfer(e, &from, &to, token_id);
+///         Base::check_on_nft_received(e, &from, &from, &to, token_id, &data);
+///     }
+///
+///     /* and so on */
+/// }
+/// ```
+///
+/// # Notes
+///
+/// The hook is invoked after the token has been transferred. As Soroban does
+/// not allow re-entrancy, the recipient can't call back into the token
+/// contract from `on_nft_received` (eg. to query `owner_of`), and has to rely
+/// on the arguments of the hook instead.
+#[contracttrait]
+pub trait NonFungibleSafeTransfer: NonFungibleToken {
+    /// Transfers `token_id` token from `from` to `to`, and invokes
+    /// `on_nft_received` on `to` if it is a contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `token_id` - Token id as a number.
+    /// * `data` - Additional data with no specified format, forwarded to the
+    ///   hook.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`crate::non_fungible::NonFungibleToken::transfer`] errors.
+    /// * refer to [`Base::check_on_nft_received`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: u32]`
+    fn safe_transfer(e: &Env, from: Address, to: Address, token_id: u32, data: Bytes) {
+        Self::ContractType::transfer(e, &from, &to, token_id);
+        Base::check_on_nft_received(e, &from, &from, &to, token_id, &data);
+    }
+
+    /// Transfers `token_id` token from `from` to `to` by using `spender`s
+    /// approval, and invokes `on_nft_received` on `to` if it is a contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorizing the transfer.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `token_id` - Token id as a number.
+    /// * `data` - Additional data with no specified format, forwarded to the
+    ///   hook.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`crate::non_fungible::NonFungibleToken::transfer_from`]
+    ///   errors.
+    /// * refer to [`Base::check_on_nft_received`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: u32]`
+    fn safe_transfer_from(
+        e: &Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        token_id: u32,
+        data: Bytes,
+    ) {
+        Self::ContractType::transfer_from(e, &spender, &from, &to, token_id);
+        Base::check_on_nft_received(e, &spender, &from, &to, token_id, &data);
+    }
+}
+
+/// Receiver Trait for Non-Fungible Token
+///
+/// The `NonFungibleReceiver` trait has to be implemented by the contracts
+/// that want to receive tokens through
+/// [`NonFungibleSafeTransfer::safe_transfer`] and
+/// [`NonFungibleSafeTransfer::safe_transfer_from`].
+///
+/// The calling contract is the token contract, which can be used to restrict
+/// the accepted collections (eg. by comparing it against an allowlist stored
+/// by the receiver).
+#[contractclient(name = "NonFungibleReceiverClient")]
+pub trait NonFungibleReceiver {
+    /// Handles the receipt of a token. Returns `true` to accept the token,
+    /// the transfer is reverted otherwise. Panicking also reverts the
+    /// transfer.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `operator` - The address which initiated the transfer, either `from`
+    ///   or the approved spender.
+    /// * `from` - The previous owner of the token.
+    /// * `token_id` - The identifier of the received token.
+    /// * `data` - Additional data with no specified format.
+    fn on_nft_received(
+        e: &Env,
+        operator: Address,
+        from: Address,
+        token_id: u32,
+        data: Bytes,
+    ) -> bool;
+}
@@ -0,0 +1,47 @@
+use soroban_sdk::{panic_with_error, Address, Bytes, Env, Executable};
+
+use crate::non_fungible::{safe_transfer::NonFungibleReceiverClient, Base, NonFungibleTokenError};
+
+impl Base {
+    /// Invokes `on_nft_received` on `to` if it is a contract, and ensures the
+    /// token was accepted. Does nothing when `to` is an account, or an address
+    /// that does not exist in the ledger.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `operator` - The address which initiated the transfer.
+    /// * `from` - The previous owner of the token.
+    /// * `to` - The recipient of the token.
+    /// * `token_id` - The identifier of the transferred token.
+    /// * `data` - Additional data forwarded to the hook.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidReceiver`] - If the hook does not
+    ///   return `true`.
+    ///
+    /// # Notes
+    ///
+    /// If `to` is a contract which does not implement the
+    /// [`crate::non_fungible::safe_transfer::NonFungibleReceiver`] trait, the
+    /// invocation fails and the whole transaction is reverted.
+    pub fn check_on_nft_received(
+        e: &Env,
+        operator: &Address,
+        from: &Address,
+        to: &Address,
+        token_id: u32,
+        data: &Bytes,
+    ) {
+        if !matches!(to.executable(), Some(Executable::Wasm(_) | Executable::StellarAsset)) {
+            return;
+        }
+
+        let accepted =
+            NonFungibleReceiverClient::new(e, to).on_nft_received(operator, from, &token_id, data);
+        if !accepted {
+            panic_with_error!(e, NonFungibleTokenError::InvalidReceiver);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+extern crate std;
+
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Bytes, Env};
+
+use crate::non_fungible::Base;
+
+#[contract]
+struct MockContract;
+
+#[contract]
+struct AcceptingReceiver;
+
+#[contractimpl]
+impl AcceptingReceiver {
+    pub fn on_nft_received(
+        e: &Env,
+        _operator: Address,
+        _from: Address,
+        _token_id: u32,
+        data: Bytes,
+    ) -> bool {
+        // accepts everything but an explicit `0x00` rejection marker
+        data != Bytes::from_array(e, &[0])
+    }
+}
+
+#[contract]
+struct NonReceiver;
+
+#[contractimpl]
+impl NonReceiver {
+    pub fn hello(_e: &Env) {}
+}
+
+fn transfer_and_check(
+    e: &Env,
+    address: &Address,
+    owner: &Address,
+    to: &Address,
+    token_id: u32,
+    data: &Bytes,
+) {
+    e.as_contract(address, || {
+        Base::transfer(e, owner, to, token_id);
+        Base::check_on_nft_received(e, owner, owner, to, token_id, data);
+    });
+}
+
+#[test]
+fn safe_transfer_to_account_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    let token_id = e.as_contract(&address, || Base::sequential_mint(&e, &owner));
+    transfer_and_check(&e, &address, &owner, &recipient, token_id, &Bytes::new(&e));
+
+    e.as_contract(&address, || {
+        assert_eq!(Base::owner_of(&e, token_id), recipient);
+    });
+}
+
+#[test]
+fn safe_transfer_to_receiver_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let receiver = e.register(AcceptingReceiver, ());
+    let owner = Address::generate(&e);
+
+    let token_id = e.as_contract(&address, || Base::sequential_mint(&e, &owner));
+    transfer_and_check(&e, &address, &owner, &receiver, token_id, &Bytes::from_array(&e, &[1]));
+
+    e.as_contract(&address, || {
+        assert_eq!(Base::owner_of(&e, token_id), receiver);
+    });
+}
+
+#[test]
+fn safe_transfer_from_to_receiver_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let receiver = e.register(AcceptingReceiver, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    let token_id = e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::approve(&e, &owner, &spender, token_id, 1000);
+        token_id
+    });
+
+    e.as_contract(&address, || {
+        Base::transfer_from(&e, &spender, &owner, &receiver, token_id);
+        Base::check_on_nft_received(&e, &spender, &owner, &receiver, token_id, &Bytes::new(&e));
+        assert_eq!(Base::owner_of(&e, token_id), receiver);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #219)")]
+fn safe_transfer_rejected_by_receiver_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let receiver = e.register(AcceptingReceiver, ());
+    let owner = Address::generate(&e);
+
+    let token_id = e.as_contract(&address, || Base::sequential_mint(&e, &owner));
+    transfer_and_check(&e, &address, &owner, &receiver, token_id, &Bytes::from_array(&e, &[0]));
+}
+
+#[test]
+#[should_panic]
+fn safe_transfer_to_non_receiver_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let non_receiver = e.register(NonReceiver, ());
+    let owner = Address::generate(&e);
+
+    let token_id = e.as_contract(&address, || Base::sequential_mint(&e, &owner));
+    transfer_and_check(&e, &address, &owner, &non_receiver, token_id, &Bytes::new(&e));
+}
@@ -0,0 +1,53 @@
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{Address, Env};
+pub use storage::Soulbound;
+
+use crate::non_fungible::NonFungibleToken;
+
+/// Soulbound Trait for Non-Fungible Token
+///
+/// The `NonFungibleSoulbound` trait extends the `NonFungibleToken` trait for
+/// non-transferable tokens, e.g. credentials, certificates or KYC badges.
+/// Tokens are bound to the account they are minted to: `transfer`,
+/// `transfer_from`, `approve` and `approve_for_all` are overridden by the
+/// [`Soulbound`] contract type, and fail at runtime with
+/// [`crate::non_fungible::NonFungibleTokenError::NonTransferable`].
+///
+/// Tokens can still leave circulation in two ways:
+/// - the holder burns them, with the
+///   [`crate::non_fungible::extensions::burnable::NonFungibleBurnable`] trait,
+/// - the issuer revokes them, with [`NonFungibleSoulbound::revoke`].
+///
+/// # Notes
+///
+/// There is no default implementation for this trait on purpose.
+///
+/// Because, there are no default implementation to enforce how the
+/// authorization should be configured. Not providing a default implementation
+/// for this trait is a reminder for the implementor to provide the
+/// authorization logic for this trait.
+pub trait NonFungibleSoulbound: NonFungibleToken<ContractType = Soulbound> {
+    /// Revokes the token with `token_id`, destroying it regardless of its
+    /// holder.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token to revoke.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::non_fungible::NonFungibleTokenError::NonExistentToken`] -
+    ///   When attempting to revoke a token that does not exist.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[token_id: u32]`
+    fn revoke(e: &Env, token_id: u32, operator: Address);
+}
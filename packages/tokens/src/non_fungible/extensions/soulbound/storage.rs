@@ -0,0 +1,68 @@
+use soroban_sdk::{panic_with_error, Address, Env};
+
+use crate::non_fungible::{
+    burnable::emit_burn, overrides::BurnableOverrides, Base, ContractOverrides,
+    NonFungibleTokenError,
+};
+
+pub struct Soulbound;
+
+impl ContractOverrides for Soulbound {
+    fn transfer(e: &Env, _from: &Address, _to: &Address, _token_id: u32) {
+        panic_with_error!(e, NonFungibleTokenError::NonTransferable);
+    }
+
+    fn transfer_from(e: &Env, _spender: &Address, _from: &Address, _to: &Address, _token_id: u32) {
+        panic_with_error!(e, NonFungibleTokenError::NonTransferable);
+    }
+
+    fn approve(
+        e: &Env,
+        _approver: &Address,
+        _approved: &Address,
+        _token_id: u32,
+        _live_until_ledger: u32,
+    ) {
+        panic_with_error!(e, NonFungibleTokenError::NonTransferable);
+    }
+
+    fn approve_for_all(e: &Env, _owner: &Address, _operator: &Address, _live_until_ledger: u32) {
+        panic_with_error!(e, NonFungibleTokenError::NonTransferable);
+    }
+}
+
+// Holders can burn their own tokens. As approvals can't be granted,
+// `burn_from` only succeeds when `spender` is the holder.
+impl BurnableOverrides for Soulbound {}
+
+impl Soulbound {
+    /// Destroys the token with `token_id` regardless of its holder, and emits
+    /// a `burn` event.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token to revoke.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::owner_of`] errors.
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[token_id: u32]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only the issuer can revoke tokens.
+    pub fn revoke(e: &Env, token_id: u32) {
+        let holder = Base::owner_of(e, token_id);
+        Base::update(e, Some(&holder), None, token_id);
+        emit_burn(e, &holder, token_id);
+    }
+}
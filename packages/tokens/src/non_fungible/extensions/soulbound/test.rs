@@ -0,0 +1,115 @@
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+use stellar_event_assertion::EventAssertion;
+
+use crate::non_fungible::{
+    overrides::BurnableOverrides, soulbound::Soulbound, Base, ContractOverrides,
+};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn mint_and_revoke_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let holder = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &holder);
+        assert_eq!(<Soulbound as ContractOverrides>::owner_of(&e, token_id), holder);
+
+        Soulbound::revoke(&e, token_id);
+        assert_eq!(Base::balance(&e, &holder), 0);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+        event_assert.assert_non_fungible_mint(&holder, token_id);
+        event_assert.assert_non_fungible_burn(&holder, token_id);
+    });
+}
+
+#[test]
+fn holder_burn_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let holder = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &holder);
+        <Soulbound as BurnableOverrides>::burn(&e, &holder, token_id);
+        assert_eq!(Base::balance(&e, &holder), 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #215)")]
+fn transfer_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let holder = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &holder);
+        <Soulbound as ContractOverrides>::transfer(&e, &holder, &recipient, token_id);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #215)")]
+fn transfer_from_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let holder = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &holder);
+        <Soulbound as ContractOverrides>::transfer_from(&e, &holder, &holder, &recipient, token_id);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #215)")]
+fn approve_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let holder = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &holder);
+        <Soulbound as ContractOverrides>::approve(&e, &holder, &spender, token_id, 1000);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #215)")]
+fn approve_for_all_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let holder = Address::generate(&e);
+    let operator = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        <Soulbound as ContractOverrides>::approve_for_all(&e, &holder, &operator, 1000);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #200)")]
+fn revoke_non_existent_token_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Soulbound::revoke(&e, 1);
+    });
+}
@@ -0,0 +1,98 @@
+mod storage;
+use crate::non_fungible::NonFungibleToken;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contractevent, Address, Env, String};
+pub use storage::NFTUriStorageKey;
+
+/// URI Storage Trait for Non-Fungible Token
+///
+/// The `NonFungibleUriStorage` trait extends the `NonFungibleToken` trait to
+/// allow setting an individual URI per token, instead of composing it from
+/// the base URI and the `token_id`.
+///
+/// Once a URI is set for a token,
+/// [`crate::non_fungible::NonFungibleToken::token_uri`] returns it as is, for
+/// both the `Base` and the `Consecutive` contract types. Tokens without an
+/// individual URI keep using the base URI. The individual URI is removed when
+/// the token is burned.
+///
+/// This extension also provides the `MetadataUpdate` and
+/// `BatchMetadataUpdate` events (ERC4906), which notify marketplaces and
+/// indexers that the metadata of one or more tokens has to be refreshed.
+/// [`crate::non_fungible::Base::set_token_uri`] emits `MetadataUpdate`
+/// automatically. [`emit_batch_metadata_update`] can be used when the base URI
+/// changes, or when the metadata changes off-chain.
+///
+/// # Notes
+///
+/// There is no default implementation for this trait on purpose.
+///
+/// Because, there are no default implementation to enforce how the
+/// authorization should be configured. Not providing a default implementation
+/// for this trait is a reminder for the implementor to provide the
+/// authorization logic for this trait.
+pub trait NonFungibleUriStorage: NonFungibleToken {
+    /// Sets the URI of `token_id`, overriding the one composed from the base
+    /// URI.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    /// * `uri` - The URI of the token.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::non_fungible::NonFungibleTokenError::TokenUriMaxLenExceeded`]
+    ///   - If the length of `uri` exceeds the maximum allowed.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["metadata_update", token_id: u32]`
+    /// * data - `[]`
+    fn set_token_uri(e: &Env, token_id: u32, uri: String, operator: Address);
+}
+
+// ################## EVENTS ##################
+
+/// Event emitted when the metadata of a token changes.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetadataUpdate {
+    #[topic]
+    pub token_id: u32,
+}
+
+/// Emits an event indicating the metadata of `token_id` changed.
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+/// * `token_id` - The token identifier.
+pub fn emit_metadata_update(e: &Env, token_id: u32) {
+    MetadataUpdate { token_id }.publish(e);
+}
+
+/// Event emitted when the metadata of a range of tokens changes.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchMetadataUpdate {
+    pub from_token_id: u32,
+    pub to_token_id: u32,
+}
+
+/// Emits an event indicating the metadata of the tokens from `from_token_id`
+/// to `to_token_id` (inclusive) changed.
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+/// * `from_token_id` - The first token identifier of the range.
+/// * `to_token_id` - The last token identifier of the range.
+pub fn emit_batch_metadata_update(e: &Env, from_token_id: u32, to_token_id: u32) {
+    BatchMetadataUpdate { from_token_id, to_token_id }.publish(e);
+}
@@ -0,0 +1,103 @@
+use soroban_sdk::{contracttype, panic_with_error, Env, String};
+
+use crate::non_fungible::{
+    uri_storage::emit_metadata_update, Base, NonFungibleTokenError, MAX_TOKEN_URI_LEN,
+    TOKEN_EXTEND_AMOUNT, TOKEN_TTL_THRESHOLD,
+};
+
+/// Storage keys for the data associated with the URI storage extension of
+/// `NonFungibleToken`
+#[contracttype]
+pub enum NFTUriStorageKey {
+    TokenUri(u32),
+    /// Instance flag set once an individual URI has been set for any token,
+    /// so that collections never using the extension skip the lookups.
+    Enabled,
+}
+
+impl Base {
+    /// Returns whether an individual URI has ever been set for a token of the
+    /// collection. Reads instance storage only, which is loaded along with
+    /// the contract, so it adds no ledger entry read.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn uri_storage_enabled(e: &Env) -> bool {
+        e.storage().instance().has(&NFTUriStorageKey::Enabled)
+    }
+
+    /// Returns the individual URI of `token_id`, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    pub fn stored_token_uri(e: &Env, token_id: u32) -> Option<String> {
+        if !Base::uri_storage_enabled(e) {
+            return None;
+        }
+        let key = NFTUriStorageKey::TokenUri(token_id);
+        let uri = e.storage().persistent().get::<_, String>(&key);
+        if uri.is_some() {
+            e.storage().persistent().extend_ttl(&key, TOKEN_TTL_THRESHOLD, TOKEN_EXTEND_AMOUNT);
+        }
+        uri
+    }
+
+    /// Sets the URI of `token_id`, overriding the one composed from the base
+    /// URI, and emits a `metadata_update` event.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    /// * `uri` - The URI of the token.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::TokenUriMaxLenExceeded`] - If the length of
+    ///   `uri` exceeds [`MAX_TOKEN_URI_LEN`].
+    ///
+    /// # Events
+    ///
+    /// * topics - `["metadata_update", token_id: u32]`
+    /// * data - `[]`
+    ///
+    /// # Notes
+    ///
+    /// **IMPORTANT**: This function lacks authorization controls. Most likely,
+    /// you want to invoke it from another function with admin-only
+    /// authorization.
+    ///
+    /// The existence of `token_id` is not checked, so that the function can be
+    /// used with every contract type, and the URI can be set before minting.
+    pub fn set_token_uri(e: &Env, token_id: u32, uri: &String) {
+        if uri.len() as usize > MAX_TOKEN_URI_LEN {
+            panic_with_error!(e, NonFungibleTokenError::TokenUriMaxLenExceeded);
+        }
+
+        e.storage().persistent().set(&NFTUriStorageKey::TokenUri(token_id), uri);
+        e.storage().instance().set(&NFTUriStorageKey::Enabled, &true);
+
+        emit_metadata_update(e, token_id);
+    }
+
+    /// Low-level function removing the individual URI of `token_id`, if any,
+    /// without handling authorization. Called by [`Base::update`] and
+    /// [`crate::non_fungible::consecutive::Consecutive::update`] when the token
+    /// is burned, so that a token reminted with the same identifier doesn't
+    /// inherit the URI of the burned one. Does nothing for collections that
+    /// never set an individual URI.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    pub fn remove_token_uri(e: &Env, token_id: u32) {
+        if !Base::uri_storage_enabled(e) {
+            return;
+        }
+        e.storage().persistent().remove(&NFTUriStorageKey::TokenUri(token_id));
+    }
+}
@@ -0,0 +1,174 @@
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, Address, Env, String};
+use stellar_event_assertion::EventAssertion;
+
+use crate::non_fungible::{
+    consecutive::Consecutive,
+    uri_storage::{emit_batch_metadata_update, emit_metadata_update},
+    Base, MAX_TOKEN_URI_LEN,
+};
+
+#[contract]
+struct MockContract;
+
+fn set_metadata(e: &Env) {
+    Base::set_metadata(
+        e,
+        String::from_str(e, "https://example.com/"),
+        String::from_str(e, "Name"),
+        String::from_str(e, "SYM"),
+    );
+}
+
+#[test]
+fn set_token_uri_overrides_base_uri() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_metadata(&e);
+        let first = Base::sequential_mint(&e, &owner);
+        let second = Base::sequential_mint(&e, &owner);
+
+        let uri = String::from_str(&e, "ipfs://custom");
+        Base::set_token_uri(&e, first, &uri);
+
+        assert_eq!(Base::token_uri(&e, first), uri);
+        assert_eq!(Base::stored_token_uri(&e, second), None);
+        assert_eq!(Base::token_uri(&e, second), String::from_str(&e, "https://example.com/1"));
+
+        // 2 `mint` events and 1 `metadata_update`
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(3);
+    });
+}
+
+#[test]
+fn set_token_uri_without_base_uri_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        let uri = String::from_str(&e, "ipfs://custom");
+        Base::set_token_uri(&e, token_id, &uri);
+
+        assert_eq!(Base::token_uri(&e, token_id), uri);
+    });
+}
+
+#[test]
+fn set_token_uri_works_with_consecutive() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_metadata(&e);
+        Consecutive::batch_mint(&e, &owner, 3);
+
+        let uri = String::from_str(&e, "ipfs://custom");
+        Base::set_token_uri(&e, 1, &uri);
+
+        assert_eq!(Consecutive::token_uri(&e, 1), uri);
+        assert_eq!(Consecutive::token_uri(&e, 2), String::from_str(&e, "https://example.com/2"));
+    });
+}
+
+#[test]
+fn burn_removes_token_uri() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_metadata(&e);
+        Base::mint(&e, &owner, 1);
+        Base::set_token_uri(&e, 1, &String::from_str(&e, "ipfs://custom"));
+
+        Base::burn(&e, &owner, 1);
+        assert_eq!(Base::stored_token_uri(&e, 1), None);
+    });
+
+    e.as_contract(&address, || {
+        // the reminted token uses the base URI again
+        Base::mint(&e, &owner, 1);
+        assert_eq!(Base::token_uri(&e, 1), String::from_str(&e, "https://example.com/1"));
+    });
+}
+
+#[test]
+fn burn_removes_token_uri_with_consecutive() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &owner, 2);
+        Base::set_token_uri(&e, 1, &String::from_str(&e, "ipfs://custom"));
+
+        Consecutive::burn(&e, &owner, 1);
+        assert_eq!(Base::stored_token_uri(&e, 1), None);
+    });
+}
+
+#[test]
+fn token_uri_without_individual_uris_skips_lookup() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_metadata(&e);
+        Base::mint(&e, &owner, 1);
+        assert!(!Base::uri_storage_enabled(&e));
+        assert_eq!(Base::token_uri(&e, 1), String::from_str(&e, "https://example.com/1"));
+
+        Base::burn(&e, &owner, 1);
+        assert!(!Base::uri_storage_enabled(&e));
+    });
+}
+
+#[test]
+fn metadata_update_events_work() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        emit_metadata_update(&e, 1);
+        emit_batch_metadata_update(&e, 0, 99);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #200)")]
+fn token_uri_of_non_existent_token_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Base::set_token_uri(&e, 1, &String::from_str(&e, "ipfs://custom"));
+        Base::token_uri(&e, 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #216)")]
+fn set_token_uri_too_long_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        let uri = std::string::String::from("a").repeat(MAX_TOKEN_URI_LEN + 1);
+        Base::set_token_uri(&e, 1, &String::from_str(&e, &uri));
+    });
+}
@@ -37,8 +37,26 @@
 //! - *Burnable* enables token holders to destroy their non-fungible tokens.
 //! - *Enumerable* allows for enumeration of all the token IDs in the contract
 //!   as well as all the token IDs owned by each account.
+//! - *Batch* transfers and approves multiple tokens in a single invocation.
 //! - *Consecutive* is useful for efficiently minting multiple tokens in a
 //!   single transaction.
+//! - *Enumerable Consecutive* combines the batch minting of *Consecutive* with
+//!   the enumeration of the tokens owned by each account.
+//! - *Lazy Mint* defers minting until a buyer redeems a voucher signed
+//!   off-chain by an authorized signer.
+//! - *Drop* sells sequentially minted tokens in phased sale windows: an
+//!   allowlist phase with per-account caps proven by a Merkle tree, then a
+//!   public phase.
+//! - *On-chain Metadata* stores the attributes of every token on-chain, and
+//!   builds the JSON metadata document from them.
+//! - *Rentable* grants a time-limited `user` role, separately from the
+//!   ownership (ERC-4907).
+//! - *Safe Transfer* makes sure contract recipients are able to handle the
+//!   received tokens.
+//! - *Soulbound* makes tokens non-transferable, while still allowing holders to
+//!   burn them and issuers to revoke them.
+//! - *URI Storage* allows setting an individual URI per token, and signaling
+//!   metadata refreshes (ERC-4906).
 //!
 //! ## Compatibility and Compliance
 //!
@@ -49,8 +67,8 @@
 //!   Token interface, and also it is a simpler (thus, cheaper and faster)
 //!   version of `transferFrom()`, which may become handy depending on the
 //!   context.
-//! - `safeTransfer` mechanism is not present in the base module, (will be
-//!   provided as an extension)
+//! - `safeTransfer` mechanism is not present in the base module, it is provided
+//!   by the *Safe Transfer* extension instead.
 //! - `name()`, `symbol()` and `token_uri()` functionalities are made available
 //!   to be consistent with fungible tokens as well.
 //!
@@ -72,7 +90,10 @@ mod utils;
 #[cfg(test)]
 mod test;
 
-pub use extensions::{burnable, consecutive, enumerable, royalties, votes};
+pub use extensions::{
+    batch, burnable, consecutive, drop, enumerable, enumerable_consecutive, lazy_mint,
+    onchain_metadata, rentable, royalties, safe_transfer, soulbound, uri_storage, votes,
+};
 pub use overrides::{Base, ContractOverrides};
 // ################## TRAIT ##################
 use soroban_sdk::{contracterror, contractevent, contracttrait, Address, Env, String};
@@ -111,6 +132,16 @@ pub use utils::sequential;
 ///   [`crate::non_fungible::extensions::burnable::NonFungibleBurnable`]) and
 ///   [`crate::non_fungible::extensions::enumerable::NonFungibleEnumerable`]
 ///   trait.
+/// * [`crate::non_fungible::extensions::enumerable_consecutive::EnumerableConsecutive`]
+///   (enabling the compatibility and overrides for
+///   [`crate::non_fungible::extensions::enumerable_consecutive::NonFungibleEnumerableConsecutive`])
+///   trait, compatible with
+///   [`crate::non_fungible::extensions::burnable::NonFungibleBurnable`].
+/// * [`crate::non_fungible::extensions::soulbound::Soulbound`] (enabling the
+///   compatibility and overrides for
+///   [`crate::non_fungible::extensions::soulbound::NonFungibleSoulbound`])
+///   trait, compatible with
+///   [`crate::non_fungible::extensions::burnable::NonFungibleBurnable`].
 ///
 /// You can find the default implementations of this trait for `Base`,
 /// `Enumerable`, and `Consecutive`, by navigating to:
@@ -380,12 +411,57 @@ pub enum NonFungibleTokenError {
     UnsetMetadata = 210,
     /// Indicates the length of the base URI exceeds the maximum allowed.
     BaseUriMaxLenExceeded = 211,
-    /// Indicates the royalty amount is higher than 10_000 (100%) basis points.
+    /// Indicates the royalty amount is higher than the royalty cap (10_000
+    /// basis points, i.e. 100%, by default).
     InvalidRoyaltyAmount = 212,
     /// Indicates the length of the name exceeds the maximum allowed.
     NameMaxLenExceeded = 213,
     /// Indicates the length of the symbol exceeds the maximum allowed.
     SymbolMaxLenExceeded = 214,
+    /// Indicates an attempt to transfer or approve a soulbound token.
+    NonTransferable = 215,
+    /// Indicates the length of a token URI exceeds the maximum allowed.
+    TokenUriMaxLenExceeded = 216,
+    /// Indicates the maximum number of attributes of a token is exceeded.
+    TooManyAttributes = 217,
+    /// Indicates an attribute key or value that is too long or not valid UTF-8.
+    InvalidAttribute = 218,
+    /// Indicates a contract recipient did not accept a safe transfer.
+    InvalidReceiver = 219,
+    /// Indicates royalty splits that are empty or exceed the maximum number of
+    /// receivers.
+    InvalidRoyaltySplits = 220,
+    /// Indicates the voucher signer is not set.
+    VoucherSignerNotSet = 221,
+    /// Indicates an attempt to redeem an expired voucher.
+    VoucherExpired = 222,
+    /// Indicates a voucher that was already redeemed or invalidated.
+    VoucherAlreadyUsed = 223,
+    /// Indicates an attempt to redeem a voucher restricted to another account.
+    InvalidVoucherRecipient = 224,
+    /// Indicates a voucher with a negative price.
+    InvalidVoucherPrice = 225,
+    /// Indicates the drop is not configured.
+    DropConfigNotSet = 226,
+    /// Indicates a drop configuration with unordered phases or a negative
+    /// price.
+    InvalidDropConfig = 227,
+    /// Indicates an attempt to mint outside of the matching drop phase.
+    DropPhaseInactive = 228,
+    /// Indicates an attempt to mint with the allowlist leaf of another
+    /// account.
+    InvalidAllowlistAccount = 229,
+    /// Indicates an attempt to mint zero tokens.
+    InvalidMintAmount = 230,
+    /// Indicates an attempt to mint more tokens than the public cap allows.
+    PublicMintCapExceeded = 231,
+    /// Indicates an attempt to mint a token that already exists.
+    TokenAlreadyExists = 232,
+    /// Indicates a collection name that is not valid UTF-8.
+    InvalidName = 233,
+    /// Indicates an attempt to replace the allowlist root once the allowlist
+    /// phase has started.
+    AllowlistRootFrozen = 234,
 }
 
 // ################## CONSTANTS ##################
@@ -404,6 +480,14 @@ pub const TOKEN_TTL_THRESHOLD: u32 = TOKEN_EXTEND_AMOUNT - DAY_IN_LEDGERS;
 pub const MAX_NUM_DIGITS: usize = 10;
 /// Max. allowed length for a base uri.
 pub const MAX_BASE_URI_LEN: usize = 200;
+/// Max. allowed length for an individual token uri.
+pub const MAX_TOKEN_URI_LEN: usize = 200;
+/// Max. number of on-chain attributes per token.
+pub const MAX_ATTRIBUTES: u32 = 10;
+/// Max. allowed length for an on-chain attribute key.
+pub const MAX_ATTRIBUTE_KEY_LEN: usize = 32;
+/// Max. allowed length for an on-chain attribute value.
+pub const MAX_ATTRIBUTE_VALUE_LEN: usize = 64;
 /// Max. allowed length for a name.
 pub const MAX_NAME_LEN: usize = 40;
 /// Max. allowed length for a symbol.
@@ -179,7 +179,9 @@ impl Base {
         Base::get_metadata(e).base_uri
     }
 
-    /// Returns the URI for a specific `token_id`.
+    /// Returns the URI for a specific `token_id`: the individual URI set with
+    /// [`Base::set_token_uri`] if any, otherwise the one composed from the
+    /// base URI.
     ///
     /// # Arguments
     ///
@@ -193,6 +195,9 @@ impl Base {
     pub fn token_uri(e: &Env, token_id: u32) -> String {
         // used to panic if non-existent token_id
         let _ = Base::owner_of(e, token_id);
+        if let Some(uri) = Base::stored_token_uri(e, token_id) {
+            return uri;
+        }
         let base_uri = Base::base_uri(e);
         Base::compose_uri_for_token(e, base_uri, token_id)
     }
@@ -389,7 +394,7 @@ impl Base {
 
     /// Low-level function for handling transfers, mints and burns of an NFT,
     /// without handling authorization. Updates ownership records, adjusts
-    /// balances, and clears existing approvals.
+    /// balances, and clears existing approvals and user role.
     ///
     /// # Arguments
     ///
@@ -419,6 +424,11 @@ impl Base {
             // Clear any existing approval
             let approval_key = NFTStorageKey::Approval(token_id);
             e.storage().temporary().remove(&approval_key);
+
+            // Clear the user role of the rentable extension, if it is in use
+            if Base::rentable_enabled(e) {
+                Base::remove_user(e, token_id);
+            }
         } else {
             // nothing to do for the `None` case, since we don't track
             // `total_supply`
@@ -432,6 +442,12 @@ impl Base {
         } else {
             // Burning: `to` is None
             e.storage().persistent().remove(&NFTStorageKey::Owner(token_id));
+
+            // Clear the individual URI of the uri storage extension, if any
+            Base::remove_token_uri(e, token_id);
+
+            // Clear the attributes of the on-chain metadata extension, if any
+            Base::remove_attributes(e, token_id);
         }
     }
 
@@ -571,6 +587,13 @@ impl Base {
     ///
     /// * [`NonFungibleTokenError::BaseUriMaxLenExceeded`] - If the length of
     ///   `base_uri` exceeds the maximum allowed.
+    /// * [`NonFungibleTokenError::NameMaxLenExceeded`] - If the length of
+    ///   `name` exceeds the maximum allowed.
+    /// * [`NonFungibleTokenError::InvalidName`] - If `name` is not valid UTF-8,
+    ///   as it is embedded in the JSON metadata of the on-chain metadata
+    ///   extension.
+    /// * [`NonFungibleTokenError::SymbolMaxLenExceeded`] - If the length of
+    ///   `symbol` exceeds the maximum allowed.
     ///
     /// # Notes
     ///
@@ -582,9 +605,15 @@ impl Base {
             panic_with_error!(e, NonFungibleTokenError::BaseUriMaxLenExceeded)
         }
 
-        if name.len() as usize > MAX_NAME_LEN {
+        let name_len = name.len() as usize;
+        if name_len > MAX_NAME_LEN {
             panic_with_error!(e, NonFungibleTokenError::NameMaxLenExceeded)
         }
+        let mut buf = [0u8; MAX_NAME_LEN];
+        name.copy_into_slice(&mut buf[..name_len]);
+        if core::str::from_utf8(&buf[..name_len]).is_err() {
+            panic_with_error!(e, NonFungibleTokenError::InvalidName)
+        }
 
         if symbol.len() as usize > MAX_SYMBOL_LEN {
             panic_with_error!(e, NonFungibleTokenError::SymbolMaxLenExceeded)
@@ -598,7 +627,7 @@ impl Base {
 
     /// Converts `u32` to `String` and returns it alongside the
     /// number of digits.
-    fn token_id_to_string(e: &Env, value: u32) -> (String, usize) {
+    pub(crate) fn token_id_to_string(e: &Env, value: u32) -> (String, usize) {
         if value == 0 {
             return (String::from_str(e, "0"), 1);
         }
@@ -93,6 +93,21 @@ fn set_metadata_should_panic_when_name_exceeds_max_length() {
     });
 }
 
+#[test]
+#[should_panic(expected = "Error(Contract, #233)")]
+fn set_metadata_should_panic_when_name_is_not_utf8() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        let base_uri = String::from_str(&e, "https://example.com/");
+        let invalid_name = String::from_bytes(&e, &[b'a', 0xff, 0xfe]);
+        let symbol = String::from_str(&e, "TEST");
+
+        Base::set_metadata(&e, base_uri, invalid_name, symbol);
+    });
+}
+
 #[test]
 #[should_panic(expected = "Error(Contract, #214)")]
 fn set_metadata_should_panic_when_symbol_exceeds_max_length() {
@@ -0,0 +1,288 @@
+//! # Payment Splitter Module
+//!
+//! Splits the SEP-41 tokens received by a contract among a group of payees,
+//! proportionally to the number of shares each payee holds. It is typically
+//! used as the receiver of NFT royalties, protocol fees or any other revenue
+//! that has to be shared.
+//!
+//! Payments are pull-based: tokens are simply transferred to the contract,
+//! and each payee claims their due amount with [`release`], which requires no
+//! authorization since the funds can only go to the payee. The amount due is
+//! computed with the `mul_div` helpers from `stellar_contract_utils::math`,
+//! rounding down, so that the sum of the amounts due never exceeds the
+//! balance of the contract.
+//!
+//! Tokens must be registered with [`add_token`] before they can be released.
+//! Registering the tokens allows the shares to be updated with
+//! [`set_shares`] at any time: before changing the shares, the amounts due
+//! for every registered token are settled with the old shares, and only the
+//! payments received afterwards are split according to the new shares. The
+//! cost of a share update grows with the number of payees and registered
+//! tokens, which are bounded by [`MAX_PAYEES`] and [`MAX_TOKENS`].
+//!
+//! The module doesn't make any assumption on the authorization model of the
+//! contract. [`set_shares`] and [`add_token`] are meant to be called from
+//! functions restricted with the help of the `access` package, e.g. `Ownable`
+//! or `AccessControl`.
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contracterror, contractevent, contracttrait, Address, Env, Vec};
+pub use storage::{
+    add_token, payees, releasable, release, released, set_shares, shares, tokens, total_released,
+    total_shares, PaymentSplitterStorageKey,
+};
+
+/// Payment Splitter Trait
+///
+/// The `PaymentSplitter` trait exposes the functionalities of the payment
+/// splitter module as contract endpoints. Query functions and
+/// [`PaymentSplitter::release`] have default implementations, while
+/// [`PaymentSplitter::set_shares`] and [`PaymentSplitter::add_token`] must be
+/// implemented, because the authorization logic is left to the implementor.
+///
+/// ```ignore
+/// #[contractimpl(contracttrait)]
+/// impl PaymentSplitter for ExampleContract {
+///     #[only_owner]
+///     fn set_shares(e: &Env, account: Address, shares: i128) {
+///         payment_splitter::set_shares(e, &account, shares);
+///     }
+///
+///     #[only_owner]
+///     fn add_token(e: &Env, token: Address) {
+///         payment_splitter::add_token(e, &token);
+///     }
+/// }
+/// ```
+#[contracttrait]
+pub trait PaymentSplitter {
+    /// Returns the sum of the shares of all payees.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn total_shares(e: &Env) -> i128 {
+        total_shares(e)
+    }
+
+    /// Returns the shares held by `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address of the payee.
+    fn shares(e: &Env, account: Address) -> i128 {
+        shares(e, &account)
+    }
+
+    /// Returns the list of payees holding shares.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn payees(e: &Env) -> Vec<Address> {
+        payees(e)
+    }
+
+    /// Returns the list of registered tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn tokens(e: &Env) -> Vec<Address> {
+        tokens(e)
+    }
+
+    /// Returns the total amount of `token` already released.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token` - The address of the token.
+    fn total_released(e: &Env, token: Address) -> i128 {
+        total_released(e, &token)
+    }
+
+    /// Returns the amount of `token` already released to `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token` - The address of the token.
+    /// * `account` - The address of the payee.
+    fn released(e: &Env, token: Address, account: Address) -> i128 {
+        released(e, &token, &account)
+    }
+
+    /// Returns the amount of `token` that can be released to `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token` - The address of the token.
+    /// * `account` - The address of the payee.
+    ///
+    /// # Errors
+    ///
+    /// * [`PaymentSplitterError::TokenNotRegistered`] - When `token` is not
+    ///   registered.
+    fn releasable(e: &Env, token: Address, account: Address) -> i128 {
+        releasable(e, &token, &account)
+    }
+
+    /// Transfers the amount of `token` due to `account` and returns it.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token` - The address of the token.
+    /// * `account` - The address of the payee.
+    ///
+    /// # Errors
+    ///
+    /// * [`PaymentSplitterError::TokenNotRegistered`] - When `token` is not
+    ///   registered.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["payment_released", token: Address, account: Address]`
+    /// * data - `[amount: i128]`
+    fn release(e: &Env, token: Address, account: Address) -> i128 {
+        release(e, &token, &account)
+    }
+
+    /// Sets the shares of `account`, adding it as a payee if needed, or
+    /// removing it when `shares` is `0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address of the payee.
+    /// * `shares` - The new shares of the payee.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`set_shares`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["payee_shares_set", account: Address]`
+    /// * data - `[shares: i128]`
+    fn set_shares(e: &Env, account: Address, shares: i128);
+
+    /// Registers `token`, so that the payments received in it can be
+    /// released.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token` - The address of the token.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`add_token`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["token_added", token: Address]`
+    /// * data - `[]`
+    fn add_token(e: &Env, token: Address);
+}
+
+// ################## ERRORS ##################
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PaymentSplitterError {
+    /// Indicates negative shares.
+    InvalidShares = 600,
+    /// Indicates the maximum number of payees is exceeded.
+    TooManyPayees = 601,
+    /// Indicates a token that was not registered.
+    TokenNotRegistered = 602,
+    /// Indicates a token that is already registered.
+    TokenAlreadyRegistered = 603,
+    /// Indicates the maximum number of registered tokens is exceeded.
+    TooManyTokens = 604,
+    /// Indicates overflow due to mathematical operations.
+    MathOverflow = 605,
+}
+
+// ################## CONSTANTS ##################
+
+/// Maximum number of payees holding shares at the same time.
+pub const MAX_PAYEES: u32 = 10;
+
+/// Maximum number of registered tokens.
+pub const MAX_TOKENS: u32 = 5;
+
+const DAY_IN_LEDGERS: u32 = 17280;
+pub const PAYMENT_SPLITTER_EXTEND_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const PAYMENT_SPLITTER_TTL_THRESHOLD: u32 = PAYMENT_SPLITTER_EXTEND_AMOUNT - DAY_IN_LEDGERS;
+
+// ################## EVENTS ##################
+
+/// Event emitted when the shares of a payee are set.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayeeSharesSet {
+    #[topic]
+    pub account: Address,
+    pub shares: i128,
+}
+
+/// Emits an event indicating the shares of a payee were set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The address of the payee.
+/// * `shares` - The new shares of the payee.
+pub fn emit_payee_shares_set(e: &Env, account: &Address, shares: i128) {
+    PayeeSharesSet { account: account.clone(), shares }.publish(e);
+}
+
+/// Event emitted when a token is registered.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenAdded {
+    #[topic]
+    pub token: Address,
+}
+
+/// Emits an event indicating a token was registered.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `token` - The address of the token.
+pub fn emit_token_added(e: &Env, token: &Address) {
+    TokenAdded { token: token.clone() }.publish(e);
+}
+
+/// Event emitted when a payment is released to a payee.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentReleased {
+    #[topic]
+    pub token: Address,
+    #[topic]
+    pub account: Address,
+    pub amount: i128,
+}
+
+/// Emits an event indicating a payment was released to a payee.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `token` - The address of the token.
+/// * `account` - The address of the payee.
+/// * `amount` - The amount released.
+pub fn emit_payment_released(e: &Env, token: &Address, account: &Address, amount: i128) {
+    PaymentReleased { token: token.clone(), account: account.clone(), amount }.publish(e);
+}
@@ -0,0 +1,330 @@
+use soroban_sdk::{contracttype, panic_with_error, token::TokenClient, Address, Env, Vec};
+use stellar_contract_utils::math::{mul_div_i128, Rounding};
+
+use crate::payment_splitter::{
+    emit_payee_shares_set, emit_payment_released, emit_token_added, PaymentSplitterError,
+    MAX_PAYEES, MAX_TOKENS, PAYMENT_SPLITTER_EXTEND_AMOUNT, PAYMENT_SPLITTER_TTL_THRESHOLD,
+};
+
+/// Storage keys for the data associated with the payment splitter module
+#[contracttype]
+pub enum PaymentSplitterStorageKey {
+    /// Sum of the shares of all payees
+    TotalShares,
+    /// List of the payees holding shares
+    Payees,
+    /// List of the registered tokens
+    Tokens,
+    /// Shares of a payee
+    Shares(Address),
+    /// Total amount of a token received as of the last share update
+    Baseline(Address),
+    /// Amount of a token due to a payee, on top of the amount accrued since
+    /// the last share update (token, payee)
+    Offset(Address, Address),
+    /// Amount of a token released to a payee (token, payee)
+    Released(Address, Address),
+    /// Total amount of a token released
+    TotalReleased(Address),
+}
+
+// ################## QUERY STATE ##################
+
+/// Returns the sum of the shares of all payees.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+pub fn total_shares(e: &Env) -> i128 {
+    e.storage().instance().get(&PaymentSplitterStorageKey::TotalShares).unwrap_or(0)
+}
+
+/// Returns the shares held by `account`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `account` - The address of the payee.
+pub fn shares(e: &Env, account: &Address) -> i128 {
+    get_amount(e, &PaymentSplitterStorageKey::Shares(account.clone()))
+}
+
+/// Returns the list of payees holding shares.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+pub fn payees(e: &Env) -> Vec<Address> {
+    e.storage().instance().get(&PaymentSplitterStorageKey::Payees).unwrap_or_else(|| Vec::new(e))
+}
+
+/// Returns the list of registered tokens.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+pub fn tokens(e: &Env) -> Vec<Address> {
+    e.storage().instance().get(&PaymentSplitterStorageKey::Tokens).unwrap_or_else(|| Vec::new(e))
+}
+
+/// Returns the total amount of `token` already released.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `token` - The address of the token.
+pub fn total_released(e: &Env, token: &Address) -> i128 {
+    get_amount(e, &PaymentSplitterStorageKey::TotalReleased(token.clone()))
+}
+
+/// Returns the amount of `token` already released to `account`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `token` - The address of the token.
+/// * `account` - The address of the payee.
+pub fn released(e: &Env, token: &Address, account: &Address) -> i128 {
+    get_amount(e, &PaymentSplitterStorageKey::Released(token.clone(), account.clone()))
+}
+
+/// Returns the amount of `token` that can be released to `account`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `token` - The address of the token.
+/// * `account` - The address of the payee.
+///
+/// # Errors
+///
+/// * [`PaymentSplitterError::TokenNotRegistered`] - When `token` is not
+///   registered.
+pub fn releasable(e: &Env, token: &Address, account: &Address) -> i128 {
+    if !tokens(e).contains(token) {
+        panic_with_error!(e, PaymentSplitterError::TokenNotRegistered);
+    }
+    pending(e, token, account, total_received(e, token))
+}
+
+// ################## CHANGE STATE ##################
+
+/// Transfers the amount of `token` due to `account` and returns it.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `token` - The address of the token.
+/// * `account` - The address of the payee.
+///
+/// # Errors
+///
+/// * refer to [`releasable`] errors.
+///
+/// # Events
+///
+/// * topics - `["payment_released", token: Address, account: Address]`
+/// * data - `[amount: i128]`
+///
+/// # Notes
+///
+/// No authorization is required, as the tokens can only be transferred to the
+/// payee. No event is emitted when there is nothing to release.
+pub fn release(e: &Env, token: &Address, account: &Address) -> i128 {
+    let amount = releasable(e, token, account);
+    if amount == 0 {
+        return 0;
+    }
+
+    // NOTE: none of these can overflow, as they are bounded by the total
+    // amount received.
+    let offset_key = PaymentSplitterStorageKey::Offset(token.clone(), account.clone());
+    let offset = get_amount(e, &offset_key);
+    e.storage().persistent().set(&offset_key, &(offset - amount));
+
+    let released_key = PaymentSplitterStorageKey::Released(token.clone(), account.clone());
+    e.storage().persistent().set(&released_key, &(released(e, token, account) + amount));
+
+    let total_released_key = PaymentSplitterStorageKey::TotalReleased(token.clone());
+    e.storage().persistent().set(&total_released_key, &(total_released(e, token) + amount));
+
+    TokenClient::new(e, token).transfer(&e.current_contract_address(), account, &amount);
+
+    emit_payment_released(e, token, account, amount);
+
+    amount
+}
+
+/// Sets the shares of `account`, adding it as a payee if needed, or removing
+/// it when `new_shares` is `0`.
+///
+/// Before the shares are updated, the amounts due to every payee are settled
+/// for every registered token, so that the payments received so far are
+/// split according to the old shares.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `account` - The address of the payee.
+/// * `new_shares` - The new shares of the payee.
+///
+/// # Errors
+///
+/// * [`PaymentSplitterError::InvalidShares`] - When `new_shares < 0`.
+/// * [`PaymentSplitterError::TooManyPayees`] - When adding a payee would exceed
+///   [`MAX_PAYEES`].
+/// * [`PaymentSplitterError::MathOverflow`] - When the total shares overflow.
+///
+/// # Events
+///
+/// * topics - `["payee_shares_set", account: Address]`
+/// * data - `[shares: i128]`
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate
+/// access controls to ensure that only authorized accounts can update the
+/// shares.
+pub fn set_shares(e: &Env, account: &Address, new_shares: i128) {
+    if new_shares < 0 {
+        panic_with_error!(e, PaymentSplitterError::InvalidShares);
+    }
+
+    let old_shares = shares(e, account);
+    if old_shares == new_shares {
+        return;
+    }
+
+    let mut payees = payees(e);
+    if old_shares == 0 && payees.len() >= MAX_PAYEES {
+        panic_with_error!(e, PaymentSplitterError::TooManyPayees);
+    }
+
+    settle(e, &payees);
+
+    // NOTE: can't underflow, because `old_shares <= total_shares`.
+    let Some(new_total_shares) = (total_shares(e) - old_shares).checked_add(new_shares) else {
+        panic_with_error!(e, PaymentSplitterError::MathOverflow);
+    };
+    e.storage().instance().set(&PaymentSplitterStorageKey::TotalShares, &new_total_shares);
+
+    let key = PaymentSplitterStorageKey::Shares(account.clone());
+    if new_shares == 0 {
+        e.storage().persistent().remove(&key);
+        if let Some(index) = payees.first_index_of(account) {
+            payees.remove(index);
+        }
+    } else {
+        e.storage().persistent().set(&key, &new_shares);
+        if old_shares == 0 {
+            payees.push_back(account.clone());
+        }
+    }
+    e.storage().instance().set(&PaymentSplitterStorageKey::Payees, &payees);
+
+    emit_payee_shares_set(e, account, new_shares);
+}
+
+/// Registers `token`, so that the payments received in it can be released.
+/// Any balance of `token` already held by the contract is split according to
+/// the current shares.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `token` - The address of the token.
+///
+/// # Errors
+///
+/// * [`PaymentSplitterError::TokenAlreadyRegistered`] - When `token` is already
+///   registered.
+/// * [`PaymentSplitterError::TooManyTokens`] - When registering the token would
+///   exceed [`MAX_TOKENS`].
+///
+/// # Events
+///
+/// * topics - `["token_added", token: Address]`
+/// * data - `[]`
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate
+/// access controls to ensure that only authorized accounts can register
+/// tokens.
+pub fn add_token(e: &Env, token: &Address) {
+    let mut tokens = tokens(e);
+    if tokens.contains(token) {
+        panic_with_error!(e, PaymentSplitterError::TokenAlreadyRegistered);
+    }
+    if tokens.len() >= MAX_TOKENS {
+        panic_with_error!(e, PaymentSplitterError::TooManyTokens);
+    }
+
+    tokens.push_back(token.clone());
+    e.storage().instance().set(&PaymentSplitterStorageKey::Tokens, &tokens);
+
+    emit_token_added(e, token);
+}
+
+// ################## INTERNAL HELPERS ##################
+
+/// Returns the amount stored under `key`, or `0` if missing, extending the
+/// TTL of the entry.
+fn get_amount(e: &Env, key: &PaymentSplitterStorageKey) -> i128 {
+    if let Some(amount) = e.storage().persistent().get::<_, i128>(key) {
+        e.storage().persistent().extend_ttl(
+            key,
+            PAYMENT_SPLITTER_TTL_THRESHOLD,
+            PAYMENT_SPLITTER_EXTEND_AMOUNT,
+        );
+        amount
+    } else {
+        0
+    }
+}
+
+/// Returns the total amount of `token` received by the contract, i.e. its
+/// current balance and the amount already released.
+fn total_received(e: &Env, token: &Address) -> i128 {
+    TokenClient::new(e, token).balance(&e.current_contract_address()) + total_released(e, token)
+}
+
+/// Returns the amount of `token` due to `account`, given the total amount
+/// `received` by the contract.
+fn pending(e: &Env, token: &Address, account: &Address, received: i128) -> i128 {
+    let offset = get_amount(e, &PaymentSplitterStorageKey::Offset(token.clone(), account.clone()));
+
+    let total_shares = total_shares(e);
+    let accrued = if total_shares == 0 {
+        0
+    } else {
+        let baseline = get_amount(e, &PaymentSplitterStorageKey::Baseline(token.clone()));
+        mul_div_i128(e, received - baseline, shares(e, account), total_shares, Rounding::Floor)
+    };
+
+    // NOTE: the result can only be negative if the balance of the contract
+    // decreased without going through `release`.
+    (accrued + offset).max(0)
+}
+
+/// Records the amounts due to `payees` for every registered token, and resets
+/// the accrual so that only payments received from now on are split with the
+/// updated shares.
+fn settle(e: &Env, payees: &Vec<Address>) {
+    for token in tokens(e).iter() {
+        let received = total_received(e, &token);
+        for payee in payees.iter() {
+            let due = pending(e, &token, &payee, received);
+            e.storage()
+                .persistent()
+                .set(&PaymentSplitterStorageKey::Offset(token.clone(), payee.clone()), &due);
+        }
+        e.storage()
+            .persistent()
+            .set(&PaymentSplitterStorageKey::Baseline(token.clone()), &received);
+    }
+}
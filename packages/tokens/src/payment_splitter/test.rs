@@ -0,0 +1,249 @@
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, StellarAssetContract},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env,
+};
+use stellar_event_assertion::EventAssertion;
+
+use crate::payment_splitter::{
+    add_token, payees, releasable, release, released, set_shares, shares, tokens, total_released,
+    total_shares, MAX_PAYEES, MAX_TOKENS,
+};
+
+#[contract]
+struct MockContract;
+
+fn setup_env() -> (Env, Address, Address) {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_address = e.register(MockContract, ());
+    let issuer = Address::generate(&e);
+    let sac: StellarAssetContract = e.register_stellar_asset_contract_v2(issuer);
+    let token = sac.address();
+    e.as_contract(&contract_address, || add_token(&e, &token));
+    (e, contract_address, token)
+}
+
+fn pay(e: &Env, token: &Address, to: &Address, amount: i128) {
+    StellarAssetClient::new(e, token).mint(to, &amount);
+}
+
+#[test]
+fn set_shares_manages_payees() {
+    let (e, contract_address, _) = setup_env();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        set_shares(&e, &alice, 30);
+        set_shares(&e, &bob, 70);
+
+        assert_eq!(shares(&e, &alice), 30);
+        assert_eq!(total_shares(&e), 100);
+        assert_eq!(payees(&e), vec![&e, alice.clone(), bob.clone()]);
+
+        set_shares(&e, &alice, 0);
+        assert_eq!(total_shares(&e), 70);
+        assert_eq!(payees(&e), vec![&e, bob.clone()]);
+
+        let event_assert = EventAssertion::new(&e, contract_address.clone());
+        event_assert.assert_event_count(3);
+    });
+}
+
+#[test]
+fn release_splits_by_shares() {
+    let (e, contract_address, token) = setup_env();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        set_shares(&e, &alice, 1);
+        set_shares(&e, &bob, 3);
+    });
+    pay(&e, &token, &contract_address, 1000);
+
+    e.as_contract(&contract_address, || {
+        assert_eq!(releasable(&e, &token, &alice), 250);
+        assert_eq!(releasable(&e, &token, &bob), 750);
+
+        assert_eq!(release(&e, &token, &alice), 250);
+        assert_eq!(released(&e, &token, &alice), 250);
+        assert_eq!(total_released(&e, &token), 250);
+        assert_eq!(releasable(&e, &token, &alice), 0);
+        assert_eq!(releasable(&e, &token, &bob), 750);
+    });
+
+    // New payments accrue on top of the previous ones.
+    pay(&e, &token, &contract_address, 400);
+
+    e.as_contract(&contract_address, || {
+        assert_eq!(release(&e, &token, &alice), 100);
+        assert_eq!(release(&e, &token, &bob), 1050);
+        assert_eq!(release(&e, &token, &bob), 0);
+    });
+
+    let client = TokenClient::new(&e, &token);
+    assert_eq!(client.balance(&alice), 350);
+    assert_eq!(client.balance(&bob), 1050);
+    assert_eq!(client.balance(&contract_address), 0);
+}
+
+#[test]
+fn release_rounds_down() {
+    let (e, contract_address, token) = setup_env();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let carol = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        set_shares(&e, &alice, 1);
+        set_shares(&e, &bob, 1);
+        set_shares(&e, &carol, 1);
+    });
+    pay(&e, &token, &contract_address, 100);
+
+    e.as_contract(&contract_address, || {
+        assert_eq!(release(&e, &token, &alice), 33);
+        assert_eq!(release(&e, &token, &bob), 33);
+        assert_eq!(release(&e, &token, &carol), 33);
+    });
+
+    assert_eq!(TokenClient::new(&e, &token).balance(&contract_address), 1);
+}
+
+#[test]
+fn multiple_tokens() {
+    let (e, contract_address, token) = setup_env();
+    let issuer = Address::generate(&e);
+    let other_token = e.register_stellar_asset_contract_v2(issuer).address();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        add_token(&e, &other_token);
+        set_shares(&e, &alice, 1);
+        set_shares(&e, &bob, 1);
+    });
+    pay(&e, &token, &contract_address, 100);
+    pay(&e, &other_token, &contract_address, 60);
+
+    e.as_contract(&contract_address, || {
+        assert_eq!(tokens(&e), vec![&e, token.clone(), other_token.clone()]);
+        assert_eq!(release(&e, &token, &alice), 50);
+        assert_eq!(release(&e, &other_token, &alice), 30);
+        assert_eq!(releasable(&e, &other_token, &bob), 30);
+    });
+}
+
+#[test]
+fn share_update_settles_previous_payments() {
+    let (e, contract_address, token) = setup_env();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        set_shares(&e, &alice, 1);
+        set_shares(&e, &bob, 1);
+    });
+    pay(&e, &token, &contract_address, 100);
+
+    e.as_contract(&contract_address, || {
+        assert_eq!(release(&e, &token, &alice), 50);
+
+        // Payments received so far are split 50/50, the next ones 25/75.
+        set_shares(&e, &bob, 3);
+        assert_eq!(releasable(&e, &token, &alice), 0);
+        assert_eq!(releasable(&e, &token, &bob), 50);
+    });
+    pay(&e, &token, &contract_address, 200);
+
+    e.as_contract(&contract_address, || {
+        assert_eq!(releasable(&e, &token, &alice), 50);
+        assert_eq!(releasable(&e, &token, &bob), 200);
+    });
+}
+
+#[test]
+fn removed_payee_keeps_amount_due() {
+    let (e, contract_address, token) = setup_env();
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        set_shares(&e, &alice, 1);
+        set_shares(&e, &bob, 1);
+    });
+    pay(&e, &token, &contract_address, 100);
+
+    e.as_contract(&contract_address, || {
+        set_shares(&e, &alice, 0);
+    });
+    pay(&e, &token, &contract_address, 100);
+
+    e.as_contract(&contract_address, || {
+        assert_eq!(release(&e, &token, &alice), 50);
+        assert_eq!(release(&e, &token, &bob), 150);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #600)")]
+fn set_negative_shares_panics() {
+    let (e, contract_address, _) = setup_env();
+    let alice = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        set_shares(&e, &alice, -1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #601)")]
+fn too_many_payees_panics() {
+    let (e, contract_address, _) = setup_env();
+
+    e.as_contract(&contract_address, || {
+        for _ in 0..=MAX_PAYEES {
+            set_shares(&e, &Address::generate(&e), 1);
+        }
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #602)")]
+fn release_unregistered_token_panics() {
+    let (e, contract_address, _) = setup_env();
+    let alice = Address::generate(&e);
+    let unknown = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        set_shares(&e, &alice, 1);
+        release(&e, &unknown, &alice);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #603)")]
+fn add_token_twice_panics() {
+    let (e, contract_address, token) = setup_env();
+
+    e.as_contract(&contract_address, || {
+        add_token(&e, &token);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #604)")]
+fn too_many_tokens_panics() {
+    let (e, contract_address, _) = setup_env();
+
+    e.as_contract(&contract_address, || {
+        for _ in 0..MAX_TOKENS {
+            add_token(&e, &Address::generate(&e));
+        }
+    });
+}
@@ -0,0 +1,466 @@
+mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contractevent, contracttrait, Address, Env};
+pub use storage::{AsyncVault, AsyncVaultStorageKey, ClaimableRequest};
+
+use crate::vault::{FungibleVault, Vault};
+
+/// Asynchronous Vault Trait for Fungible Token
+///
+/// The `FungibleAsyncVault` trait extends the `FungibleVault` trait with
+/// ERC-7540 style asynchronous deposit and redeem flows, for vaults whose
+/// underlying strategy can't settle immediately (e.g. tokenized funds with
+/// T+N settlement, illiquid or real-world assets).
+///
+/// Every request goes through three states:
+///
+/// 1. **Pending**: the account submits a request with
+///    [`FungibleAsyncVault::request_deposit`] or
+///    [`FungibleAsyncVault::request_redeem`]. The assets (resp. shares) are
+///    transferred to the vault right away, but no shares (resp. assets) are
+///    issued yet.
+/// 2. **Claimable**: the vault operator fulfills the request with
+///    [`FungibleAsyncVault::fulfill_deposit`] or
+///    [`FungibleAsyncVault::fulfill_redeem`], which fixes the exchange rate at
+///    the share price of the fulfillment.
+/// 3. **Claimed**: the controller of the request claims the shares (resp.
+///    assets) with [`FungibleAsyncVault::claim_deposit`] or
+///    [`FungibleAsyncVault::claim_redeem`], which emit the usual `deposit`
+///    (resp. `withdraw`) events.
+///
+/// Requests are tracked per controller: successive requests of a controller
+/// are aggregated, which corresponds to ERC-7540 requests with an identifier
+/// of `0`. Requests can be fulfilled and claimed partially.
+///
+/// The assets of pending deposit requests and of claimable redeem requests
+/// are held by the vault without backing any shares. They are tracked as
+/// reserved assets (see [`Vault::reserved_assets`]), and excluded from the
+/// total assets by the [`AsyncVault`] contract type, so that they don't affect
+/// the share price. The contract must therefore use [`AsyncVault`] as its
+/// `ContractType`, or a custom type implementing
+/// [`crate::vault::VaultOverrides::total_assets`] likewise when combining
+/// extensions.
+///
+/// # Notes
+///
+/// * The synchronous `deposit`, `mint`, `withdraw` and `redeem` flows of
+///   [`FungibleVault`] remain available. Vaults which must be purely
+///   asynchronous should override them, and `max_*` accordingly, to reject any
+///   invocation.
+/// * Deposit requests are subject to the deposit limits of the contract type
+///   (see [`crate::vault::limits::FungibleVaultLimits`]): `request_deposit`
+///   checks `max_deposit` for the controller, and `claim_deposit` checks that
+///   the receiver is accepted by `can_deposit`.
+/// * `fulfill_deposit` and `fulfill_redeem` have no default implementation, as
+///   a reminder for the implementor to provide the authorization logic.
+#[contracttrait]
+pub trait FungibleAsyncVault: FungibleVault {
+    /// Submits a request to deposit `assets` on behalf of `controller`,
+    /// transferring them from `owner` to the vault.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `assets` - The amount of underlying assets to deposit.
+    /// * `controller` - The address controlling the request.
+    /// * `owner` - The address providing the underlying assets.
+    /// * `operator` - The address submitting the request.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::request_deposit`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["deposit_request", controller: Address, owner: Address]`
+    /// * data - `[operator: Address, assets: i128]`
+    fn request_deposit(
+        e: &Env,
+        assets: i128,
+        controller: Address,
+        owner: Address,
+        operator: Address,
+    ) {
+        Vault::request_deposit::<Self::ContractType>(e, assets, &controller, &owner, &operator);
+    }
+
+    /// Submits a request to redeem `shares` on behalf of `controller`,
+    /// transferring them from `owner` to the vault.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `shares` - The amount of vault shares to redeem.
+    /// * `controller` - The address controlling the request.
+    /// * `owner` - The address owning the vault shares.
+    /// * `operator` - The address submitting the request.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::request_redeem`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["redeem_request", controller: Address, owner: Address]`
+    /// * data - `[operator: Address, shares: i128]`
+    fn request_redeem(
+        e: &Env,
+        shares: i128,
+        controller: Address,
+        owner: Address,
+        operator: Address,
+    ) {
+        Vault::request_redeem(e, shares, &controller, &owner, &operator);
+    }
+
+    /// Claims `assets` of the claimable deposit request of `controller`,
+    /// transferring the corresponding shares to `receiver`. Returns the amount
+    /// of shares.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `assets` - The amount of claimable assets to claim.
+    /// * `receiver` - The address receiving the vault shares.
+    /// * `controller` - The address controlling the request.
+    /// * `operator` - The address claiming the request.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::claim_deposit`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["deposit", operator: Address, from: Address, receiver:
+    ///   Address]`
+    /// * data - `[assets: i128, shares: i128]`
+    fn claim_deposit(
+        e: &Env,
+        assets: i128,
+        receiver: Address,
+        controller: Address,
+        operator: Address,
+    ) -> i128 {
+        Vault::claim_deposit::<Self::ContractType>(e, assets, &receiver, &controller, &operator)
+    }
+
+    /// Claims `shares` of the claimable redeem request of `controller`,
+    /// transferring the corresponding assets to `receiver`. Returns the amount
+    /// of assets.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `shares` - The amount of claimable shares to claim.
+    /// * `receiver` - The address receiving the underlying assets.
+    /// * `controller` - The address controlling the request.
+    /// * `operator` - The address claiming the request.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::claim_redeem`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["withdraw", operator: Address, receiver: Address, owner:
+    ///   Address]`
+    /// * data - `[assets: i128, shares: i128]`
+    fn claim_redeem(
+        e: &Env,
+        shares: i128,
+        receiver: Address,
+        controller: Address,
+        operator: Address,
+    ) -> i128 {
+        Vault::claim_redeem::<Self::ContractType>(e, shares, &receiver, &controller, &operator)
+    }
+
+    /// Returns the amount of assets of the pending deposit request of
+    /// `controller`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `controller` - The address controlling the request.
+    fn pending_deposit_request(e: &Env, controller: Address) -> i128 {
+        Vault::pending_deposit_request(e, &controller)
+    }
+
+    /// Returns the amount of assets of the claimable deposit request of
+    /// `controller`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `controller` - The address controlling the request.
+    fn claimable_deposit_request(e: &Env, controller: Address) -> i128 {
+        Vault::claimable_deposit_request(e, &controller).assets
+    }
+
+    /// Returns the amount of shares of the pending redeem request of
+    /// `controller`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `controller` - The address controlling the request.
+    fn pending_redeem_request(e: &Env, controller: Address) -> i128 {
+        Vault::pending_redeem_request(e, &controller)
+    }
+
+    /// Returns the amount of shares of the claimable redeem request of
+    /// `controller`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `controller` - The address controlling the request.
+    fn claimable_redeem_request(e: &Env, controller: Address) -> i128 {
+        Vault::claimable_redeem_request(e, &controller).shares
+    }
+
+    /// Returns whether `operator` may manage the requests of `controller`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `controller` - The address controlling the requests.
+    /// * `operator` - The address to check.
+    fn is_operator(e: &Env, controller: Address, operator: Address) -> bool {
+        Vault::is_operator(e, &controller, &operator)
+    }
+
+    /// Grants or revokes the permission of `operator` to manage the requests
+    /// of `controller`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `controller` - The address controlling the requests.
+    /// * `operator` - The address to grant or revoke.
+    /// * `approved` - Whether the permission is granted.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["operator_set", controller: Address, operator: Address]`
+    /// * data - `[approved: bool]`
+    fn set_operator(e: &Env, controller: Address, operator: Address, approved: bool) {
+        Vault::set_operator(e, &controller, &operator, approved);
+    }
+
+    /// Fulfills `assets` of the pending deposit request of `controller`,
+    /// making them claimable at the current share price, net of the entry fee.
+    /// Returns the amount of shares.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `controller` - The address controlling the request.
+    /// * `assets` - The amount of pending assets to fulfill.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::fulfill_deposit`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["deposit_fulfilled", controller: Address]`
+    /// * data - `[assets: i128, shares: i128]`
+    ///
+    /// # Notes
+    ///
+    /// We recommend using [`Vault::fulfill_deposit`] with `Self::ContractType`
+    /// when implementing this function.
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// The base implementation doesn't check who fulfills the request.
+    /// Fulfillment fixes the exchange rate of the request, and the implementor
+    /// must restrict this function to the vault operator.
+    fn fulfill_deposit(e: &Env, controller: Address, assets: i128, operator: Address) -> i128;
+
+    /// Fulfills `shares` of the pending redeem request of `controller`,
+    /// making them claimable at the current share price, net of the exit fee.
+    /// Returns the amount of assets.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `controller` - The address controlling the request.
+    /// * `shares` - The amount of pending shares to fulfill.
+    /// * `operator` - The address authorizing the invocation.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::fulfill_redeem`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["redeem_fulfilled", controller: Address]`
+    /// * data - `[assets: i128, shares: i128]`
+    ///
+    /// # Notes
+    ///
+    /// We recommend using [`Vault::fulfill_redeem`] with `Self::ContractType`
+    /// when implementing this function.
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// The base implementation doesn't check who fulfills the request.
+    /// Fulfillment fixes the exchange rate of the request, and the implementor
+    /// must restrict this function to the vault operator, who is also
+    /// responsible for the vault holding enough underlying assets to
+    /// reserve them.
+    fn fulfill_redeem(e: &Env, controller: Address, shares: i128, operator: Address) -> i128;
+}
+
+// ################## EVENTS ##################
+
+/// Event emitted when a deposit request is submitted.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositRequest {
+    #[topic]
+    pub controller: Address,
+    #[topic]
+    pub owner: Address,
+    pub operator: Address,
+    pub assets: i128,
+}
+
+/// Emits an event indicating a deposit request was submitted.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `controller` - The address controlling the request.
+/// * `owner` - The address providing the underlying assets.
+/// * `operator` - The address submitting the request.
+/// * `assets` - The amount of underlying assets to deposit.
+pub fn emit_deposit_request(
+    e: &Env,
+    controller: &Address,
+    owner: &Address,
+    operator: &Address,
+    assets: i128,
+) {
+    DepositRequest {
+        controller: controller.clone(),
+        owner: owner.clone(),
+        operator: operator.clone(),
+        assets,
+    }
+    .publish(e);
+}
+
+/// Event emitted when a redeem request is submitted.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RedeemRequest {
+    #[topic]
+    pub controller: Address,
+    #[topic]
+    pub owner: Address,
+    pub operator: Address,
+    pub shares: i128,
+}
+
+/// Emits an event indicating a redeem request was submitted.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `controller` - The address controlling the request.
+/// * `owner` - The address owning the vault shares.
+/// * `operator` - The address submitting the request.
+/// * `shares` - The amount of vault shares to redeem.
+pub fn emit_redeem_request(
+    e: &Env,
+    controller: &Address,
+    owner: &Address,
+    operator: &Address,
+    shares: i128,
+) {
+    RedeemRequest {
+        controller: controller.clone(),
+        owner: owner.clone(),
+        operator: operator.clone(),
+        shares,
+    }
+    .publish(e);
+}
+
+/// Event emitted when a deposit request is fulfilled.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositFulfilled {
+    #[topic]
+    pub controller: Address,
+    pub assets: i128,
+    pub shares: i128,
+}
+
+/// Emits an event indicating a deposit request was fulfilled.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `controller` - The address controlling the request.
+/// * `assets` - The amount of fulfilled assets.
+/// * `shares` - The amount of shares the assets were converted to.
+pub fn emit_deposit_fulfilled(e: &Env, controller: &Address, assets: i128, shares: i128) {
+    DepositFulfilled { controller: controller.clone(), assets, shares }.publish(e);
+}
+
+/// Event emitted when a redeem request is fulfilled.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RedeemFulfilled {
+    #[topic]
+    pub controller: Address,
+    pub assets: i128,
+    pub shares: i128,
+}
+
+/// Emits an event indicating a redeem request was fulfilled.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `controller` - The address controlling the request.
+/// * `assets` - The amount of assets the shares were converted to.
+/// * `shares` - The amount of fulfilled shares.
+pub fn emit_redeem_fulfilled(e: &Env, controller: &Address, assets: i128, shares: i128) {
+    RedeemFulfilled { controller: controller.clone(), assets, shares }.publish(e);
+}
+
+/// Event emitted when an operator is granted or revoked.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OperatorSet {
+    #[topic]
+    pub controller: Address,
+    #[topic]
+    pub operator: Address,
+    pub approved: bool,
+}
+
+/// Emits an event indicating an operator was granted or revoked.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `controller` - The address controlling the requests.
+/// * `operator` - The address granted or revoked.
+/// * `approved` - Whether the permission is granted.
+pub fn emit_operator_set(e: &Env, controller: &Address, operator: &Address, approved: bool) {
+    OperatorSet { controller: controller.clone(), operator: operator.clone(), approved }.publish(e);
+}
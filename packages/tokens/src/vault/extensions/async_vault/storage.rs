@@ -0,0 +1,646 @@
+use soroban_sdk::{contracttype, panic_with_error, token, Address, Env, IntoVal, TryFromVal, Val};
+use stellar_contract_utils::math::{mul_div_i128, Rounding};
+
+use crate::{
+    fungible::{Base, ContractOverrides, BALANCE_EXTEND_AMOUNT, BALANCE_TTL_THRESHOLD},
+    vault::{
+        async_vault::{
+            emit_deposit_fulfilled, emit_deposit_request, emit_operator_set, emit_redeem_fulfilled,
+            emit_redeem_request,
+        },
+        emit_deposit, emit_withdraw,
+        fees::{fee_on_raw, fee_on_total, pay_fee},
+        Vault, VaultOverrides, VaultTokenError,
+    },
+};
+
+/// Contract type of the asynchronous vault extension, excluding the reserved
+/// assets (see [`Vault::reserved_assets`]) from the total assets of the vault.
+pub struct AsyncVault;
+
+impl ContractOverrides for AsyncVault {
+    fn decimals(e: &Env) -> u32 {
+        Vault::decimals(e)
+    }
+}
+
+impl VaultOverrides for AsyncVault {
+    fn total_assets(e: &Env) -> i128 {
+        Vault::total_assets(e)
+            .checked_sub(Vault::reserved_assets(e))
+            .unwrap_or_else(|| panic_with_error!(e, VaultTokenError::MathOverflow))
+    }
+}
+
+/// Assets and shares of a claimable request
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ClaimableRequest {
+    pub assets: i128,
+    pub shares: i128,
+}
+
+/// Storage keys for the data associated with the asynchronous vault extension
+#[contracttype]
+pub enum AsyncVaultStorageKey {
+    /// Assets of the pending deposit request of a controller
+    PendingDeposit(Address),
+    /// Assets and shares of the claimable deposit request of a controller
+    ClaimableDeposit(Address),
+    /// Shares of the pending redeem request of a controller
+    PendingRedeem(Address),
+    /// Assets and shares of the claimable redeem request of a controller
+    ClaimableRedeem(Address),
+    /// Whether an operator may manage the requests of a controller
+    Operator(Address, Address),
+    /// Assets of all the pending deposit requests
+    TotalPendingDeposits,
+    /// Assets held by the vault that don't back any shares
+    ReservedAssets,
+}
+
+impl Vault {
+    // ################## QUERY STATE ##################
+
+    /// Returns the amount of assets of the pending deposit request of
+    /// `controller`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `controller` - The address controlling the request.
+    pub fn pending_deposit_request(e: &Env, controller: &Address) -> i128 {
+        read_or_default(e, &AsyncVaultStorageKey::PendingDeposit(controller.clone()))
+    }
+
+    /// Returns the amount of assets of all the pending deposit requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn total_pending_deposits(e: &Env) -> i128 {
+        e.storage().instance().get(&AsyncVaultStorageKey::TotalPendingDeposits).unwrap_or(0)
+    }
+
+    /// Returns the amount of underlying assets held by the vault that don't
+    /// back any shares, and are therefore excluded from the total assets of
+    /// [`AsyncVault`]: the assets of pending deposit requests and of
+    /// claimable redeem requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn reserved_assets(e: &Env) -> i128 {
+        e.storage().instance().get(&AsyncVaultStorageKey::ReservedAssets).unwrap_or(0)
+    }
+
+    /// Returns the assets and shares of the claimable deposit request of
+    /// `controller`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `controller` - The address controlling the request.
+    pub fn claimable_deposit_request(e: &Env, controller: &Address) -> ClaimableRequest {
+        read_or_default(e, &AsyncVaultStorageKey::ClaimableDeposit(controller.clone()))
+    }
+
+    /// Returns the amount of shares of the pending redeem request of
+    /// `controller`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `controller` - The address controlling the request.
+    pub fn pending_redeem_request(e: &Env, controller: &Address) -> i128 {
+        read_or_default(e, &AsyncVaultStorageKey::PendingRedeem(controller.clone()))
+    }
+
+    /// Returns the assets and shares of the claimable redeem request of
+    /// `controller`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `controller` - The address controlling the request.
+    pub fn claimable_redeem_request(e: &Env, controller: &Address) -> ClaimableRequest {
+        read_or_default(e, &AsyncVaultStorageKey::ClaimableRedeem(controller.clone()))
+    }
+
+    /// Returns whether `operator` may manage the requests of `controller`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `controller` - The address controlling the requests.
+    /// * `operator` - The address to check.
+    pub fn is_operator(e: &Env, controller: &Address, operator: &Address) -> bool {
+        read_or_default(e, &AsyncVaultStorageKey::Operator(controller.clone(), operator.clone()))
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Submits a request to deposit `assets` on behalf of `controller`,
+    /// transferring them from `owner` to the vault. The assets are reserved
+    /// until the request is fulfilled.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `assets` - The amount of underlying assets to deposit.
+    /// * `controller` - The address controlling the request.
+    /// * `owner` - The address providing the underlying assets.
+    /// * `operator` - The address submitting the request.
+    ///
+    /// # Errors
+    ///
+    /// * [`VaultTokenError::VaultInvalidAssetsAmount`] - When `assets <= 0`.
+    /// * [`VaultTokenError::VaultExceededMaxDeposit`] - When `assets` exceeds
+    ///   [`VaultOverrides::max_deposit`] for `controller`.
+    /// * [`VaultTokenError::MathOverflow`] - When mathematical operations
+    ///   result in overflow.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["deposit_request", controller: Address, owner: Address]`
+    /// * data - `[operator: Address, assets: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `operator` is required. When `operator` is not
+    /// `owner`, the assets are transferred with the allowance of `operator`
+    /// on the underlying asset.
+    pub fn request_deposit<T: VaultOverrides>(
+        e: &Env,
+        assets: i128,
+        controller: &Address,
+        owner: &Address,
+        operator: &Address,
+    ) {
+        operator.require_auth();
+
+        if assets <= 0 {
+            panic_with_error!(e, VaultTokenError::VaultInvalidAssetsAmount);
+        }
+        // The deposit limits apply to the controller, as the eventual receiver
+        // of the shares is only known when claiming.
+        if assets > T::max_deposit(e, controller.clone()) {
+            panic_with_error!(e, VaultTokenError::VaultExceededMaxDeposit);
+        }
+
+        let token_client = token::Client::new(e, &T::query_asset(e));
+        if operator == owner {
+            token_client.transfer(owner, e.current_contract_address(), &assets);
+        } else {
+            token_client.transfer_from(operator, owner, &e.current_contract_address(), &assets);
+        }
+
+        Self::set_reserved_assets(e, checked_add(e, Self::reserved_assets(e), assets));
+        e.storage().instance().set(
+            &AsyncVaultStorageKey::TotalPendingDeposits,
+            &checked_add(e, Self::total_pending_deposits(e), assets),
+        );
+        let key = AsyncVaultStorageKey::PendingDeposit(controller.clone());
+        let pending = Self::pending_deposit_request(e, controller);
+        e.storage().persistent().set(&key, &checked_add(e, pending, assets));
+
+        emit_deposit_request(e, controller, owner, operator, assets);
+    }
+
+    /// Submits a request to redeem `shares` on behalf of `controller`,
+    /// transferring them from `owner` to the vault. The shares are held by
+    /// the vault until the request is fulfilled.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `shares` - The amount of vault shares to redeem.
+    /// * `controller` - The address controlling the request.
+    /// * `owner` - The address owning the vault shares.
+    /// * `operator` - The address submitting the request.
+    ///
+    /// # Errors
+    ///
+    /// * [`VaultTokenError::VaultInvalidSharesAmount`] - When `shares <= 0`.
+    /// * [`VaultTokenError::MathOverflow`] - When mathematical operations
+    ///   result in overflow.
+    /// * refer to [`Base::spend_allowance`] and [`Base::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["redeem_request", controller: Address, owner: Address]`
+    /// * data - `[operator: Address, shares: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `operator` is required. When `operator` is neither
+    /// `owner` nor one of its operators, the allowance of `operator` on the
+    /// shares of `owner` is spent.
+    pub fn request_redeem(
+        e: &Env,
+        shares: i128,
+        controller: &Address,
+        owner: &Address,
+        operator: &Address,
+    ) {
+        operator.require_auth();
+
+        if shares <= 0 {
+            panic_with_error!(e, VaultTokenError::VaultInvalidSharesAmount);
+        }
+        if operator != owner && !Self::is_operator(e, owner, operator) {
+            Base::spend_allowance(e, owner, operator, shares);
+        }
+        Base::update(e, Some(owner), Some(&e.current_contract_address()), shares);
+
+        let key = AsyncVaultStorageKey::PendingRedeem(controller.clone());
+        let pending = Self::pending_redeem_request(e, controller);
+        e.storage().persistent().set(&key, &checked_add(e, pending, shares));
+
+        emit_redeem_request(e, controller, owner, operator, shares);
+    }
+
+    /// Fulfills `assets` of the pending deposit request of `controller`: the
+    /// assets are converted to shares at the current share price, and the
+    /// shares are minted to the vault until they are claimed. Returns the
+    /// amount of shares.
+    ///
+    /// The entry fee, if any, is charged as by the `deposit` flow of `T`: the
+    /// shares are computed with [`VaultOverrides::preview_deposit`], and the
+    /// fee is transferred to the fee recipient.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `controller` - The address controlling the request.
+    /// * `assets` - The amount of pending assets to fulfill.
+    ///
+    /// # Errors
+    ///
+    /// * [`VaultTokenError::VaultInvalidAssetsAmount`] - When `assets <= 0`.
+    /// * [`VaultTokenError::VaultExceededPendingRequest`] - When `assets`
+    ///   exceeds the pending deposit request.
+    /// * [`VaultTokenError::MathOverflow`] - When mathematical operations
+    ///   result in overflow.
+    /// * also refer to [`VaultOverrides::accrue_fees`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["deposit_fulfilled", controller: Address]`
+    /// * data - `[assets: i128, shares: i128]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// Fulfillment fixes the exchange rate of the request and must be
+    /// restricted to the vault operator.
+    pub fn fulfill_deposit<T: VaultOverrides>(e: &Env, controller: &Address, assets: i128) -> i128 {
+        if assets <= 0 {
+            panic_with_error!(e, VaultTokenError::VaultInvalidAssetsAmount);
+        }
+        let pending = Self::pending_deposit_request(e, controller);
+        if assets > pending {
+            panic_with_error!(e, VaultTokenError::VaultExceededPendingRequest);
+        }
+
+        // Shares are computed while the assets are still reserved, i.e. excluded
+        // from the total assets.
+        let total_assets = T::total_assets(e);
+        T::accrue_fees(e, total_assets);
+        let shares = T::preview_deposit_with_total_assets(e, assets, total_assets);
+        Self::set_reserved_assets(e, Self::reserved_assets(e) - assets);
+        e.storage().instance().set(
+            &AsyncVaultStorageKey::TotalPendingDeposits,
+            &(Self::total_pending_deposits(e) - assets),
+        );
+        Base::update(e, None, Some(&e.current_contract_address()), shares);
+
+        if let Some(config) = T::fee_config(e) {
+            let fee = fee_on_total(e, assets, config.entry_fee);
+            pay_fee(e, &T::query_asset(e), &config.recipient, fee);
+        }
+
+        let key = AsyncVaultStorageKey::PendingDeposit(controller.clone());
+        e.storage().persistent().set(&key, &(pending - assets));
+        let mut claimable = Self::claimable_deposit_request(e, controller);
+        claimable.assets = checked_add(e, claimable.assets, assets);
+        claimable.shares = checked_add(e, claimable.shares, shares);
+        e.storage()
+            .persistent()
+            .set(&AsyncVaultStorageKey::ClaimableDeposit(controller.clone()), &claimable);
+
+        emit_deposit_fulfilled(e, controller, assets, shares);
+
+        shares
+    }
+
+    /// Fulfills `shares` of the pending redeem request of `controller`: the
+    /// shares are converted to assets at the current share price and burned,
+    /// and the assets are reserved until they are claimed. Returns the amount
+    /// of assets.
+    ///
+    /// The exit fee, if any, is charged as by the `redeem` flow of `T`: the
+    /// assets are computed with [`VaultOverrides::preview_redeem`], and the fee
+    /// is transferred to the fee recipient.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `controller` - The address controlling the request.
+    /// * `shares` - The amount of pending shares to fulfill.
+    ///
+    /// # Errors
+    ///
+    /// * [`VaultTokenError::VaultInvalidSharesAmount`] - When `shares <= 0`.
+    /// * [`VaultTokenError::VaultExceededPendingRequest`] - When `shares`
+    ///   exceeds the pending redeem request.
+    /// * [`VaultTokenError::MathOverflow`] - When mathematical operations
+    ///   result in overflow.
+    /// * also refer to [`VaultOverrides::accrue_fees`] and
+    ///   [`VaultOverrides::ensure_idle_assets`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["redeem_fulfilled", controller: Address]`
+    /// * data - `[assets: i128, shares: i128]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// Fulfillment fixes the exchange rate of the request and must be
+    /// restricted to the vault operator, who is also responsible for the
+    /// vault holding enough underlying assets to reserve them.
+    pub fn fulfill_redeem<T: VaultOverrides>(e: &Env, controller: &Address, shares: i128) -> i128 {
+        if shares <= 0 {
+            panic_with_error!(e, VaultTokenError::VaultInvalidSharesAmount);
+        }
+        let pending = Self::pending_redeem_request(e, controller);
+        if shares > pending {
+            panic_with_error!(e, VaultTokenError::VaultExceededPendingRequest);
+        }
+
+        let total_assets = T::total_assets(e);
+        T::accrue_fees(e, total_assets);
+        let assets = T::preview_redeem_with_total_assets(e, shares, total_assets);
+        Base::update(e, Some(&e.current_contract_address()), None, shares);
+
+        if let Some(config) = T::fee_config(e) {
+            let fee = fee_on_raw(e, assets, config.exit_fee);
+            if fee > 0 {
+                T::ensure_idle_assets(e, fee);
+            }
+            pay_fee(e, &T::query_asset(e), &config.recipient, fee);
+        }
+        Self::set_reserved_assets(e, checked_add(e, Self::reserved_assets(e), assets));
+
+        let key = AsyncVaultStorageKey::PendingRedeem(controller.clone());
+        e.storage().persistent().set(&key, &(pending - shares));
+        let mut claimable = Self::claimable_redeem_request(e, controller);
+        claimable.assets = checked_add(e, claimable.assets, assets);
+        claimable.shares = checked_add(e, claimable.shares, shares);
+        e.storage()
+            .persistent()
+            .set(&AsyncVaultStorageKey::ClaimableRedeem(controller.clone()), &claimable);
+
+        emit_redeem_fulfilled(e, controller, assets, shares);
+
+        assets
+    }
+
+    /// Claims `assets` of the claimable deposit request of `controller`,
+    /// transferring the corresponding shares from the vault to `receiver`.
+    /// The shares are computed at the average exchange rate of the claimable
+    /// request (rounded down). Returns the amount of shares.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `assets` - The amount of claimable assets to claim.
+    /// * `receiver` - The address receiving the vault shares.
+    /// * `controller` - The address controlling the request.
+    /// * `operator` - The address claiming the request.
+    ///
+    /// # Errors
+    ///
+    /// * [`VaultTokenError::VaultInvalidOperator`] - When `operator` is neither
+    ///   `controller` nor one of its operators.
+    /// * [`VaultTokenError::VaultInvalidAssetsAmount`] - When `assets < 0`.
+    /// * [`VaultTokenError::VaultExceededClaimableRequest`] - When `assets`
+    ///   exceeds the claimable deposit request.
+    /// * [`VaultTokenError::VaultExceededMaxDeposit`] - When `receiver` is
+    ///   rejected by [`VaultOverrides::can_deposit`].
+    ///
+    /// # Events
+    ///
+    /// * topics - `["deposit", operator: Address, from: Address, receiver:
+    ///   Address]`
+    /// * data - `[assets: i128, shares: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `operator` is required.
+    pub fn claim_deposit<T: VaultOverrides>(
+        e: &Env,
+        assets: i128,
+        receiver: &Address,
+        controller: &Address,
+        operator: &Address,
+    ) -> i128 {
+        Self::require_controller_or_operator(e, controller, operator);
+
+        if assets < 0 {
+            panic_with_error!(e, VaultTokenError::VaultInvalidAssetsAmount);
+        }
+        let mut claimable = Self::claimable_deposit_request(e, controller);
+        if assets > claimable.assets {
+            panic_with_error!(e, VaultTokenError::VaultExceededClaimableRequest);
+        }
+        if !T::can_deposit(e, receiver) {
+            panic_with_error!(e, VaultTokenError::VaultExceededMaxDeposit);
+        }
+
+        let shares = if assets == claimable.assets {
+            claimable.shares
+        } else {
+            mul_div_i128(e, claimable.shares, assets, claimable.assets, Rounding::Floor)
+        };
+        claimable.assets -= assets;
+        claimable.shares -= shares;
+        e.storage()
+            .persistent()
+            .set(&AsyncVaultStorageKey::ClaimableDeposit(controller.clone()), &claimable);
+
+        Base::update(e, Some(&e.current_contract_address()), Some(receiver), shares);
+        emit_deposit(e, operator, controller, receiver, assets, shares);
+
+        shares
+    }
+
+    /// Claims `shares` of the claimable redeem request of `controller`,
+    /// transferring the corresponding assets from the vault to `receiver`.
+    /// The assets are computed at the average exchange rate of the claimable
+    /// request (rounded down). Returns the amount of assets.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `shares` - The amount of claimable shares to claim.
+    /// * `receiver` - The address receiving the underlying assets.
+    /// * `controller` - The address controlling the request.
+    /// * `operator` - The address claiming the request.
+    ///
+    /// # Errors
+    ///
+    /// * [`VaultTokenError::VaultInvalidOperator`] - When `operator` is neither
+    ///   `controller` nor one of its operators.
+    /// * [`VaultTokenError::VaultInvalidSharesAmount`] - When `shares < 0`.
+    /// * [`VaultTokenError::VaultExceededClaimableRequest`] - When `shares`
+    ///   exceeds the claimable redeem request.
+    /// * also refer to [`VaultOverrides::ensure_idle_assets`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["withdraw", operator: Address, receiver: Address, owner:
+    ///   Address]`
+    /// * data - `[assets: i128, shares: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `operator` is required.
+    pub fn claim_redeem<T: VaultOverrides>(
+        e: &Env,
+        shares: i128,
+        receiver: &Address,
+        controller: &Address,
+        operator: &Address,
+    ) -> i128 {
+        Self::require_controller_or_operator(e, controller, operator);
+
+        if shares < 0 {
+            panic_with_error!(e, VaultTokenError::VaultInvalidSharesAmount);
+        }
+        let mut claimable = Self::claimable_redeem_request(e, controller);
+        if shares > claimable.shares {
+            panic_with_error!(e, VaultTokenError::VaultExceededClaimableRequest);
+        }
+
+        let assets = if shares == claimable.shares {
+            claimable.assets
+        } else {
+            mul_div_i128(e, claimable.assets, shares, claimable.shares, Rounding::Floor)
+        };
+        claimable.assets -= assets;
+        claimable.shares -= shares;
+        e.storage()
+            .persistent()
+            .set(&AsyncVaultStorageKey::ClaimableRedeem(controller.clone()), &claimable);
+
+        Self::set_reserved_assets(e, Self::reserved_assets(e) - assets);
+        T::ensure_idle_assets(e, assets);
+        let token_client = token::Client::new(e, &T::query_asset(e));
+        token_client.transfer(&e.current_contract_address(), receiver, &assets);
+        emit_withdraw(e, operator, receiver, controller, assets, shares);
+
+        assets
+    }
+
+    /// Grants or revokes the permission of `operator` to manage the requests
+    /// of `controller`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `controller` - The address controlling the requests.
+    /// * `operator` - The address to grant or revoke.
+    /// * `approved` - Whether the permission is granted.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["operator_set", controller: Address, operator: Address]`
+    /// * data - `[approved: bool]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `controller` is required.
+    pub fn set_operator(e: &Env, controller: &Address, operator: &Address, approved: bool) {
+        controller.require_auth();
+
+        let key = AsyncVaultStorageKey::Operator(controller.clone(), operator.clone());
+        if approved {
+            e.storage().persistent().set(&key, &true);
+        } else {
+            e.storage().persistent().remove(&key);
+        }
+
+        emit_operator_set(e, controller, operator, approved);
+    }
+
+    // ################## LOW-LEVEL HELPERS ##################
+
+    /// Sets the amount of underlying assets held by the vault that don't back
+    /// any shares.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `amount` - The amount of reserved assets.
+    ///
+    /// # Errors
+    ///
+    /// * [`VaultTokenError::VaultInvalidAssetsAmount`] - When `amount < 0`.
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// Reserving assets changes the conversion rate between shares and
+    /// assets of [`AsyncVault`]. It is meant to be used by the request flows,
+    /// and should not be exposed directly.
+    pub fn set_reserved_assets(e: &Env, amount: i128) {
+        if amount < 0 {
+            panic_with_error!(e, VaultTokenError::VaultInvalidAssetsAmount);
+        }
+        e.storage().instance().set(&AsyncVaultStorageKey::ReservedAssets, &amount);
+    }
+
+    /// Requires authorization for `operator`, and panics if `operator` is
+    /// neither `controller` nor one of its operators.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `controller` - The address controlling the requests.
+    /// * `operator` - The address managing the requests.
+    ///
+    /// # Errors
+    ///
+    /// * [`VaultTokenError::VaultInvalidOperator`] - When `operator` is neither
+    ///   `controller` nor one of its operators.
+    pub fn require_controller_or_operator(e: &Env, controller: &Address, operator: &Address) {
+        operator.require_auth();
+
+        if operator != controller && !Self::is_operator(e, controller, operator) {
+            panic_with_error!(e, VaultTokenError::VaultInvalidOperator);
+        }
+    }
+}
+
+/// Reads `key` from the persistent storage, extending its TTL, or returns the
+/// default value if it doesn't exist.
+fn read_or_default<V>(e: &Env, key: &AsyncVaultStorageKey) -> V
+where
+    V: Default + IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    if let Some(value) = e.storage().persistent().get::<_, V>(key) {
+        e.storage().persistent().extend_ttl(key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+        value
+    } else {
+        V::default()
+    }
+}
+
+fn checked_add(e: &Env, a: i128, b: i128) -> i128 {
+    a.checked_add(b).unwrap_or_else(|| panic_with_error!(e, VaultTokenError::MathOverflow))
+}
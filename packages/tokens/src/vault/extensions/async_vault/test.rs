@@ -0,0 +1,397 @@
+extern crate std;
+
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    Address, Env,
+};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{
+    fungible::Base,
+    vault::{
+        async_vault::{AsyncVault, ClaimableRequest},
+        fees::{FeeVault, VaultFeeConfig},
+        limits::VaultDepositVerifier,
+        Vault, VaultOverrides,
+    },
+};
+
+#[contract]
+struct MockVaultContract;
+
+/// Deposit verifier rejecting every receiver.
+#[contract]
+struct MockVerifier;
+
+#[contractimpl]
+impl VaultDepositVerifier for MockVerifier {
+    fn can_deposit(_e: &Env, _vault: Address, _receiver: Address) -> bool {
+        false
+    }
+}
+
+/// Contract type combining the async vault and fees extensions.
+struct AsyncFeeVault;
+
+impl VaultOverrides for AsyncFeeVault {
+    fn total_assets(e: &Env) -> i128 {
+        AsyncVault::total_assets(e)
+    }
+
+    fn fee_config(e: &Env) -> Option<VaultFeeConfig> {
+        FeeVault::fee_config(e)
+    }
+
+    fn accrued_fee_shares(e: &Env, total_assets: i128) -> i128 {
+        FeeVault::accrued_fee_shares(e, total_assets)
+    }
+
+    fn accrue_fees(e: &Env, total_assets: i128) -> i128 {
+        FeeVault::accrue_fees(e, total_assets)
+    }
+}
+
+/// Contract type combining the async vault and limits extensions.
+struct AsyncLimitedVault;
+
+impl VaultOverrides for AsyncLimitedVault {
+    fn total_assets(e: &Env) -> i128 {
+        AsyncVault::total_assets(e)
+    }
+
+    fn can_deposit(e: &Env, receiver: &Address) -> bool {
+        Vault::can_deposit(e, receiver)
+    }
+
+    fn max_deposit_with_total_assets(e: &Env, receiver: &Address, total_assets: i128) -> i128 {
+        Vault::deposit_limit_with_total_assets::<Self>(e, receiver, total_assets)
+    }
+
+    fn max_deposit(e: &Env, receiver: Address) -> i128 {
+        Vault::deposit_limit::<Self>(e, &receiver)
+    }
+}
+
+fn setup(e: &Env) -> (Address, Address, Address) {
+    let vault = e.register(MockVaultContract, ());
+    let asset = e.register_stellar_asset_contract_v2(Address::generate(e)).address();
+    let alice = Address::generate(e);
+    StellarAssetClient::new(e, &asset).mint(&alice, &1000);
+    e.as_contract(&vault, || Vault::set_asset(e, asset.clone()));
+    (vault, asset, alice)
+}
+
+#[test]
+fn deposit_request_lifecycle_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, asset, alice) = setup(&e);
+
+    e.as_contract(&vault, || {
+        Vault::request_deposit::<AsyncVault>(&e, 500, &alice, &alice, &alice);
+
+        assert_eq!(Vault::pending_deposit_request(&e, &alice), 500);
+        assert_eq!(Vault::reserved_assets(&e), 500);
+        assert_eq!(AsyncVault::total_assets(&e), 0);
+    });
+
+    e.as_contract(&vault, || {
+        let shares = Vault::fulfill_deposit::<AsyncVault>(&e, &alice, 500);
+        assert_eq!(shares, 500);
+        assert_eq!(Vault::pending_deposit_request(&e, &alice), 0);
+        assert_eq!(
+            Vault::claimable_deposit_request(&e, &alice),
+            ClaimableRequest { assets: 500, shares: 500 }
+        );
+        assert_eq!(AsyncVault::total_assets(&e), 500);
+        assert_eq!(Base::balance(&e, &vault), 500);
+    });
+
+    e.as_contract(&vault, || {
+        let shares = Vault::claim_deposit::<AsyncVault>(&e, 500, &alice, &alice, &alice);
+        assert_eq!(shares, 500);
+        assert_eq!(Base::balance(&e, &alice), 500);
+        assert_eq!(Base::balance(&e, &vault), 0);
+        assert_eq!(Vault::claimable_deposit_request(&e, &alice), ClaimableRequest::default());
+    });
+
+    let event_assert = EventAssertion::new(&e, vault.clone());
+    // only the deposit event is emitted on claim
+    event_assert.assert_event_count(1);
+    assert_eq!(TokenClient::new(&e, &asset).balance(&vault), 500);
+}
+
+#[test]
+fn pending_deposit_does_not_affect_share_price() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, asset, alice) = setup(&e);
+    let bob = Address::generate(&e);
+    StellarAssetClient::new(&e, &asset).mint(&bob, &100);
+
+    e.as_contract(&vault, || Vault::request_deposit::<AsyncVault>(&e, 500, &alice, &alice, &alice));
+
+    e.as_contract(&vault, || {
+        let shares = AsyncVault::deposit(&e, 100, bob.clone(), bob.clone(), bob.clone());
+        assert_eq!(shares, 100);
+        assert_eq!(AsyncVault::total_assets(&e), 100);
+    });
+}
+
+#[test]
+fn redeem_request_lifecycle_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, asset, alice) = setup(&e);
+    let receiver = Address::generate(&e);
+
+    e.as_contract(&vault, || {
+        AsyncVault::deposit(&e, 1000, alice.clone(), alice.clone(), alice.clone());
+    });
+
+    e.as_contract(&vault, || {
+        Vault::request_redeem(&e, 400, &alice, &alice, &alice);
+        assert_eq!(Vault::pending_redeem_request(&e, &alice), 400);
+        assert_eq!(Base::balance(&e, &alice), 600);
+        assert_eq!(Base::balance(&e, &vault), 400);
+    });
+
+    e.as_contract(&vault, || {
+        let assets = Vault::fulfill_redeem::<AsyncVault>(&e, &alice, 400);
+        assert_eq!(assets, 400);
+        assert_eq!(Base::total_supply(&e), 600);
+        assert_eq!(Vault::reserved_assets(&e), 400);
+        assert_eq!(AsyncVault::total_assets(&e), 600);
+    });
+
+    e.as_contract(&vault, || {
+        // partial claim
+        assert_eq!(Vault::claim_redeem::<AsyncVault>(&e, 100, &receiver, &alice, &alice), 100);
+    });
+    e.as_contract(&vault, || {
+        assert_eq!(Vault::claim_redeem::<AsyncVault>(&e, 300, &receiver, &alice, &alice), 300);
+        assert_eq!(Vault::reserved_assets(&e), 0);
+        assert_eq!(Vault::claimable_redeem_request(&e, &alice), ClaimableRequest::default());
+    });
+
+    assert_eq!(TokenClient::new(&e, &asset).balance(&receiver), 400);
+}
+
+#[test]
+fn operator_can_claim_for_controller() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, _, alice) = setup(&e);
+    let operator = Address::generate(&e);
+
+    e.as_contract(&vault, || {
+        Vault::request_deposit::<AsyncVault>(&e, 500, &alice, &alice, &alice);
+        Vault::fulfill_deposit::<AsyncVault>(&e, &alice, 500);
+    });
+
+    e.as_contract(&vault, || {
+        Vault::set_operator(&e, &alice, &operator, true);
+        assert!(Vault::is_operator(&e, &alice, &operator));
+    });
+
+    e.as_contract(&vault, || {
+        Vault::claim_deposit::<AsyncVault>(&e, 500, &alice, &alice, &operator);
+        assert_eq!(Base::balance(&e, &alice), 500);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #413)")]
+fn claim_by_non_operator_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, _, alice) = setup(&e);
+
+    e.as_contract(&vault, || {
+        Vault::request_deposit::<AsyncVault>(&e, 500, &alice, &alice, &alice);
+        Vault::fulfill_deposit::<AsyncVault>(&e, &alice, 500);
+        let stranger = Address::generate(&e);
+        Vault::claim_deposit::<AsyncVault>(&e, 500, &stranger, &alice, &stranger);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #411)")]
+fn fulfill_more_than_pending_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, _, alice) = setup(&e);
+
+    e.as_contract(&vault, || {
+        Vault::request_deposit::<AsyncVault>(&e, 500, &alice, &alice, &alice);
+        Vault::fulfill_deposit::<AsyncVault>(&e, &alice, 501);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #412)")]
+fn claim_more_than_claimable_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, _, alice) = setup(&e);
+
+    e.as_contract(&vault, || {
+        Vault::request_deposit::<AsyncVault>(&e, 500, &alice, &alice, &alice);
+        Vault::fulfill_deposit::<AsyncVault>(&e, &alice, 300);
+    });
+    e.as_contract(&vault, || {
+        Vault::claim_deposit::<AsyncVault>(&e, 400, &alice, &alice, &alice);
+    });
+}
+
+#[test]
+fn pending_deposits_count_towards_deposit_cap() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, _, alice) = setup(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&vault, || {
+        Vault::set_deposit_cap(&e, Some(800));
+        Vault::request_deposit::<AsyncLimitedVault>(&e, 500, &alice, &alice, &alice);
+    });
+
+    e.as_contract(&vault, || {
+        assert_eq!(Vault::total_pending_deposits(&e), 500);
+        assert_eq!(AsyncLimitedVault::max_deposit(&e, bob.clone()), 300);
+
+        Vault::fulfill_deposit::<AsyncLimitedVault>(&e, &alice, 500);
+        assert_eq!(Vault::total_pending_deposits(&e), 0);
+        assert_eq!(AsyncLimitedVault::max_deposit(&e, bob.clone()), 300);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #405)")]
+fn deposit_request_above_deposit_cap_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, _, alice) = setup(&e);
+
+    e.as_contract(&vault, || {
+        Vault::set_deposit_cap(&e, Some(800));
+        Vault::request_deposit::<AsyncLimitedVault>(&e, 500, &alice, &alice, &alice);
+    });
+    e.as_contract(&vault, || {
+        Vault::request_deposit::<AsyncLimitedVault>(&e, 301, &alice, &alice, &alice);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #405)")]
+fn deposit_request_above_account_cap_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, _, alice) = setup(&e);
+
+    e.as_contract(&vault, || {
+        Vault::set_default_account_deposit_cap(&e, Some(600));
+        Vault::request_deposit::<AsyncLimitedVault>(&e, 400, &alice, &alice, &alice);
+        Vault::fulfill_deposit::<AsyncLimitedVault>(&e, &alice, 200);
+    });
+    // 200 claimable and 200 pending count towards the cap
+    e.as_contract(&vault, || {
+        Vault::request_deposit::<AsyncLimitedVault>(&e, 201, &alice, &alice, &alice);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #405)")]
+fn claim_deposit_for_rejected_receiver_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, _, alice) = setup(&e);
+    let verifier = e.register(MockVerifier, ());
+
+    e.as_contract(&vault, || {
+        Vault::request_deposit::<AsyncLimitedVault>(&e, 500, &alice, &alice, &alice);
+        Vault::fulfill_deposit::<AsyncLimitedVault>(&e, &alice, 500);
+        Vault::set_deposit_verifier(&e, Some(verifier.clone()));
+    });
+    e.as_contract(&vault, || {
+        Vault::claim_deposit::<AsyncLimitedVault>(&e, 500, &alice, &alice, &alice);
+    });
+}
+
+#[test]
+fn fulfill_deposit_charges_entry_fee() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, asset, alice) = setup(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&vault, || {
+        Vault::set_fee_config::<AsyncFeeVault>(
+            &e,
+            &VaultFeeConfig {
+                recipient: recipient.clone(),
+                entry_fee: 100,
+                exit_fee: 0,
+                management_fee: 0,
+                performance_fee: 0,
+            },
+        );
+        Vault::request_deposit::<AsyncFeeVault>(&e, 505, &alice, &alice, &alice);
+    });
+
+    e.as_contract(&vault, || {
+        // 1% of the deposited amount, fees excluded
+        assert_eq!(Vault::fulfill_deposit::<AsyncFeeVault>(&e, &alice, 505), 500);
+        assert_eq!(AsyncFeeVault::total_assets(&e), 500);
+        assert_eq!(
+            Vault::claimable_deposit_request(&e, &alice),
+            ClaimableRequest { assets: 505, shares: 500 }
+        );
+    });
+
+    e.as_contract(&vault, || {
+        assert_eq!(Vault::claim_deposit::<AsyncVault>(&e, 505, &alice, &alice, &alice), 500);
+    });
+
+    assert_eq!(TokenClient::new(&e, &asset).balance(&recipient), 5);
+}
+
+#[test]
+fn fulfill_redeem_charges_exit_fee() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, asset, alice) = setup(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&vault, || {
+        AsyncFeeVault::deposit(&e, 1000, alice.clone(), alice.clone(), alice.clone());
+        Vault::set_fee_config::<AsyncFeeVault>(
+            &e,
+            &VaultFeeConfig {
+                recipient: recipient.clone(),
+                entry_fee: 0,
+                exit_fee: 100,
+                management_fee: 0,
+                performance_fee: 0,
+            },
+        );
+    });
+
+    e.as_contract(&vault, || Vault::request_redeem(&e, 500, &alice, &alice, &alice));
+
+    e.as_contract(&vault, || {
+        assert_eq!(Vault::fulfill_redeem::<AsyncFeeVault>(&e, &alice, 500), 495);
+        assert_eq!(Vault::reserved_assets(&e), 495);
+        assert_eq!(AsyncFeeVault::total_assets(&e), 500);
+    });
+
+    e.as_contract(&vault, || {
+        assert_eq!(Vault::claim_redeem::<AsyncVault>(&e, 500, &alice, &alice, &alice), 495);
+    });
+
+    let token = TokenClient::new(&e, &asset);
+    assert_eq!(token.balance(&recipient), 5);
+    assert_eq!(token.balance(&alice), 495);
+}
@@ -0,0 +1,191 @@
+mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contractevent, contracttrait, Address, Env};
+pub(crate) use storage::{fee_on_raw, fee_on_total, pay_fee};
+pub use storage::{
+    FeeVault, VaultFeeConfig, VaultFeesStorageKey, BASIS_POINTS_DENOMINATOR,
+    MAX_ENTRY_EXIT_FEE_BASIS_POINTS, SECONDS_PER_YEAR, SHARE_PRICE_SCALE,
+};
+
+use crate::vault::{FungibleVault, Vault};
+
+/// Vault Fees Trait for Fungible Token
+///
+/// The `FungibleVaultFees` trait extends the `FungibleVault` trait with
+/// configurable fees, paid to a single fee recipient:
+///
+/// - **Entry fee**: charged on the assets of `deposit` and `mint`, as well as
+///   on fulfilled deposit requests of the async vault extension, and
+///   transferred to the recipient.
+/// - **Exit fee**: charged on the assets of `withdraw` and `redeem`, as well as
+///   on fulfilled redeem requests of the async vault extension, and transferred
+///   to the recipient.
+/// - **Management fee**: a yearly fee on the total assets, accrued continuously
+///   and paid by minting shares to the recipient.
+/// - **Performance fee**: a fee on the increase of the share price above its
+///   high-water mark, paid by minting shares to the recipient.
+///
+/// Fees are applied by the [`FeeVault`] contract type, which the contract must
+/// use as its `ContractType` (or a custom type implementing the fee hooks of
+/// [`crate::vault::VaultOverrides`] likewise when combining extensions). Plain
+/// vaults using [`Vault`] don't read nor accrue any fees. Once configured with
+/// [`Vault::set_fee_config`]:
+///
+/// - `preview_deposit`, `preview_mint`, `preview_withdraw` and `preview_redeem`
+///   include the entry and exit fees, as required by ERC-4626, while
+///   `convert_to_shares` and `convert_to_assets` don't.
+/// - All conversions account for the management and performance fees accrued
+///   since the last accrual, as if the corresponding shares were already
+///   minted. `deposit`, `mint`, `withdraw`, `redeem` and the fulfillment of
+///   asynchronous requests accrue them before executing.
+///
+/// The entry and exit fees are capped at [`MAX_ENTRY_EXIT_FEE_BASIS_POINTS`]
+/// (10%), the management and performance fees at [`BASIS_POINTS_DENOMINATOR`]
+/// (100%).
+///
+/// # Notes
+///
+/// `set_fee_config` has no default implementation, as a reminder for the
+/// implementor to provide the authorization logic.
+#[contracttrait]
+pub trait FungibleVaultFees: FungibleVault {
+    /// Returns the fee configuration of the vault, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn fee_config(e: &Env) -> Option<VaultFeeConfig> {
+        Vault::fee_config(e)
+    }
+
+    /// Returns the share price, scaled by [`SHARE_PRICE_SCALE`], on which
+    /// performance fees were last charged.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn high_water_mark(e: &Env) -> i128 {
+        Vault::high_water_mark(e)
+    }
+
+    /// Returns the amount of shares that would be minted to the fee
+    /// recipient if management and performance fees were accrued now.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::accrued_fee_shares`] errors.
+    fn accrued_fee_shares(e: &Env) -> i128 {
+        Vault::accrued_fee_shares::<Self::ContractType>(e)
+    }
+
+    /// Accrues the management and performance fees by minting the
+    /// corresponding shares to the fee recipient. Returns the amount of
+    /// shares minted.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::accrue_fees`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["fees_accrued", recipient: Address]`
+    /// * data - `[shares: i128]`
+    fn accrue_fees(e: &Env) -> i128 {
+        Vault::accrue_fees::<Self::ContractType>(e)
+    }
+
+    /// Sets the fee configuration of the vault, after accruing the fees due
+    /// under the previous configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `config` - The new fee configuration.
+    /// * `operator` - The address authorizing the change.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::set_fee_config`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["fee_config_set", recipient: Address]`
+    /// * data - `[entry_fee: u32, exit_fee: u32, management_fee: u32,
+    ///   performance_fee: u32]`
+    ///
+    /// # Notes
+    ///
+    /// We recommend using [`Vault::set_fee_config`] with `Self::ContractType`
+    /// when implementing this function.
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// The base implementation doesn't check who can change the fees. The
+    /// implementor must restrict this function, e.g. to the owner or to an
+    /// account holding a fee manager role.
+    fn set_fee_config(e: &Env, config: VaultFeeConfig, operator: Address);
+}
+
+// ################## EVENTS ##################
+
+/// Event emitted when the fee configuration is set.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfigSet {
+    #[topic]
+    pub recipient: Address,
+    pub entry_fee: u32,
+    pub exit_fee: u32,
+    pub management_fee: u32,
+    pub performance_fee: u32,
+}
+
+/// Emits an event when the fee configuration is set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `config` - The new fee configuration.
+pub fn emit_fee_config_set(e: &Env, config: &VaultFeeConfig) {
+    FeeConfigSet {
+        recipient: config.recipient.clone(),
+        entry_fee: config.entry_fee,
+        exit_fee: config.exit_fee,
+        management_fee: config.management_fee,
+        performance_fee: config.performance_fee,
+    }
+    .publish(e);
+}
+
+/// Event emitted when management and performance fees are accrued.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeesAccrued {
+    #[topic]
+    pub recipient: Address,
+    pub shares: i128,
+}
+
+/// Emits an event when management and performance fees are accrued.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `recipient` - The address receiving the fee shares.
+/// * `shares` - The amount of shares minted to the recipient.
+pub fn emit_fees_accrued(e: &Env, recipient: &Address, shares: i128) {
+    FeesAccrued { recipient: recipient.clone(), shares }.publish(e);
+}
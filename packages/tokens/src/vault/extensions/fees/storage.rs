@@ -0,0 +1,399 @@
+use soroban_sdk::{contracttype, panic_with_error, token, Address, Env};
+use stellar_contract_utils::math::{mul_div_i128, Rounding};
+
+use crate::{
+    fungible::{Base, ContractOverrides},
+    vault::{
+        fees::{emit_fee_config_set, emit_fees_accrued},
+        Vault, VaultOverrides, VaultTokenError,
+    },
+};
+
+/// Denominator of the fees, expressed in basis points (10_000 = 100%).
+pub const BASIS_POINTS_DENOMINATOR: u32 = 10_000;
+
+/// Upper bound for the entry and exit fees, so that they can never exceed
+/// 10% of the deposited or withdrawn assets.
+pub const MAX_ENTRY_EXIT_FEE_BASIS_POINTS: u32 = 1_000;
+
+/// Period over which the management fee is charged.
+pub const SECONDS_PER_YEAR: u64 = 31_536_000;
+
+/// Scale of the share price used for the high-water mark.
+pub const SHARE_PRICE_SCALE: i128 = 1_000_000_000_000_000_000;
+
+/// Fees charged by the vault, in basis points (100 = 1%, 10_000 = 100%).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultFeeConfig {
+    /// The address receiving all fees.
+    pub recipient: Address,
+    /// Fee charged on the assets deposited (`deposit` and `mint`).
+    pub entry_fee: u32,
+    /// Fee charged on the assets withdrawn (`withdraw` and `redeem`).
+    pub exit_fee: u32,
+    /// Yearly fee charged on the total assets, accrued continuously.
+    pub management_fee: u32,
+    /// Fee charged on the share price increase above the high-water mark.
+    pub performance_fee: u32,
+}
+
+/// Contract type of the vault fees extension, applying the fees configured
+/// with [`Vault::set_fee_config`] to the flows of the vault.
+pub struct FeeVault;
+
+impl ContractOverrides for FeeVault {
+    fn decimals(e: &Env) -> u32 {
+        Vault::decimals(e)
+    }
+}
+
+impl VaultOverrides for FeeVault {
+    fn fee_config(e: &Env) -> Option<VaultFeeConfig> {
+        Vault::fee_config(e)
+    }
+
+    fn accrued_fee_shares(e: &Env, total_assets: i128) -> i128 {
+        Vault::accrued_fee_shares_with_total_assets(e, total_assets)
+    }
+
+    fn accrue_fees(e: &Env, total_assets: i128) -> i128 {
+        Vault::accrue_fees_with_total_assets(e, total_assets)
+    }
+}
+
+/// Storage keys for the data associated with the vault fees extension
+#[contracttype]
+pub enum VaultFeesStorageKey {
+    /// Stores the fee configuration
+    Config,
+    /// Stores the timestamp of the last fee accrual
+    LastAccrual,
+    /// Stores the highest share price on which performance fees were charged
+    HighWaterMark,
+}
+
+impl Vault {
+    // ################## QUERY STATE ##################
+
+    /// Returns the fee configuration of the vault, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn fee_config(e: &Env) -> Option<VaultFeeConfig> {
+        e.storage().instance().get(&VaultFeesStorageKey::Config)
+    }
+
+    /// Returns the share price, scaled by [`SHARE_PRICE_SCALE`], on which
+    /// performance fees were last charged. Defaults to 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn high_water_mark(e: &Env) -> i128 {
+        e.storage().instance().get(&VaultFeesStorageKey::HighWaterMark).unwrap_or(0)
+    }
+
+    /// Returns the timestamp of the last fee accrual. Defaults to 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn last_fee_accrual(e: &Env) -> u64 {
+        e.storage().instance().get(&VaultFeesStorageKey::LastAccrual).unwrap_or(0)
+    }
+
+    /// Returns the amount of shares that would be minted to the fee
+    /// recipient if management and performance fees were accrued now.
+    ///
+    /// The management fee is charged on the total assets, pro rata of the
+    /// time elapsed since the last accrual. The performance fee is charged on
+    /// the increase of the share price above the high-water mark. The fees
+    /// are paid by minting shares, so that they dilute the share price
+    /// instead of removing assets from the vault.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`VaultTokenError::MathOverflow`] - When mathematical operations
+    ///   result in overflow.
+    /// * also refer to [`VaultOverrides::total_assets()`] errors.
+    pub fn accrued_fee_shares<T: VaultOverrides>(e: &Env) -> i128 {
+        match Self::fee_config(e) {
+            Some(config) if config.management_fee > 0 || config.performance_fee > 0 =>
+                Self::accrued_fee_shares_with_total_assets(e, T::total_assets(e)),
+            _ => 0,
+        }
+    }
+
+    /// Same as [`Vault::accrued_fee_shares()`], with the total assets of the
+    /// vault given by the caller.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `total_assets` - The total assets of the vault, as returned by
+    ///   [`VaultOverrides::total_assets()`].
+    ///
+    /// # Errors
+    ///
+    /// * [`VaultTokenError::MathOverflow`] - When mathematical operations
+    ///   result in overflow.
+    pub fn accrued_fee_shares_with_total_assets(e: &Env, total_assets: i128) -> i128 {
+        let Some(config) = Self::fee_config(e) else {
+            return 0;
+        };
+        if config.management_fee == 0 && config.performance_fee == 0 {
+            return 0;
+        }
+
+        let total_supply = effective_supply(e, Self::total_supply(e));
+
+        let elapsed = e.ledger().timestamp().saturating_sub(Self::last_fee_accrual(e));
+        let management_fee_assets = mul_div_i128(
+            e,
+            total_assets,
+            config.management_fee as i128 * elapsed as i128,
+            BASIS_POINTS_DENOMINATOR as i128 * SECONDS_PER_YEAR as i128,
+            Rounding::Floor,
+        );
+
+        let price = share_price(e, total_assets, total_supply);
+        let high_water_mark = Self::high_water_mark(e);
+        let performance_fee_assets = if price > high_water_mark {
+            let profit = mul_div_i128(
+                e,
+                price - high_water_mark,
+                total_supply,
+                SHARE_PRICE_SCALE,
+                Rounding::Floor,
+            );
+            mul_div_i128(
+                e,
+                profit,
+                config.performance_fee as i128,
+                BASIS_POINTS_DENOMINATOR as i128,
+                Rounding::Floor,
+            )
+        } else {
+            0
+        };
+
+        let fee_assets = management_fee_assets
+            .checked_add(performance_fee_assets)
+            .unwrap_or_else(|| panic_with_error!(e, VaultTokenError::MathOverflow))
+            .min(total_assets);
+        if fee_assets == 0 {
+            return 0;
+        }
+
+        // Shares worth `fee_assets` once they are minted:
+        // fee_shares = fee_assets × totalSupply / (totalAssets - fee_assets + 1)
+        mul_div_i128(e, fee_assets, total_supply, total_assets - fee_assets + 1, Rounding::Floor)
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Accrues the management and performance fees by minting the
+    /// corresponding shares to the fee recipient, and updates the
+    /// high-water mark. Returns the amount of shares minted.
+    ///
+    /// This function is called by the `deposit`, `mint`, `withdraw` and
+    /// `redeem` flows of [`FeeVault`] before executing, and by
+    /// [`Vault::set_fee_config()`] before changing the fees.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::accrued_fee_shares()`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["fees_accrued", recipient: Address]`
+    /// * data - `[shares: i128]`
+    ///
+    /// # Notes
+    ///
+    /// Accruing fees doesn't change the share price as reported by the
+    /// conversion functions, which already account for the accrued fees.
+    /// Hence, this function can safely be exposed without authorization.
+    pub fn accrue_fees<T: VaultOverrides>(e: &Env) -> i128 {
+        if Self::fee_config(e).is_none() {
+            return 0;
+        }
+        Self::accrue_fees_with_total_assets(e, T::total_assets(e))
+    }
+
+    /// Same as [`Vault::accrue_fees()`], with the total assets of the vault
+    /// given by the caller. Accruing fees doesn't change the total assets, so
+    /// they can be reused afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `total_assets` - The total assets of the vault, as returned by
+    ///   [`VaultOverrides::total_assets()`].
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::accrued_fee_shares_with_total_assets()`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["fees_accrued", recipient: Address]`
+    /// * data - `[shares: i128]`
+    pub fn accrue_fees_with_total_assets(e: &Env, total_assets: i128) -> i128 {
+        let Some(config) = Self::fee_config(e) else {
+            return 0;
+        };
+
+        let shares = Self::accrued_fee_shares_with_total_assets(e, total_assets);
+        if shares > 0 {
+            Base::update(e, None, Some(&config.recipient), shares);
+            emit_fees_accrued(e, &config.recipient, shares);
+        }
+
+        e.storage().instance().set(&VaultFeesStorageKey::LastAccrual, &e.ledger().timestamp());
+        if config.performance_fee > 0 {
+            let total_supply = effective_supply(e, Self::total_supply(e));
+            let price = share_price(e, total_assets, total_supply);
+            if price > Self::high_water_mark(e) {
+                e.storage().instance().set(&VaultFeesStorageKey::HighWaterMark, &price);
+            }
+        }
+
+        shares
+    }
+
+    /// Sets the fee configuration of the vault, after accruing the fees due
+    /// under the previous configuration.
+    ///
+    /// When performance fees are enabled for the first time, the high-water
+    /// mark is initialized to the current share price, so that no fees are
+    /// charged on the gains made before.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `config` - The new fee configuration.
+    ///
+    /// # Errors
+    ///
+    /// * [`VaultTokenError::VaultInvalidFee`] - When the entry or exit fee is
+    ///   higher than [`MAX_ENTRY_EXIT_FEE_BASIS_POINTS`], or when the
+    ///   management or performance fee is higher than
+    ///   [`BASIS_POINTS_DENOMINATOR`].
+    /// * also refer to [`Vault::accrue_fees()`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["fee_config_set", recipient: Address]`
+    /// * data - `[entry_fee: u32, exit_fee: u32, management_fee: u32,
+    ///   performance_fee: u32]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can set the
+    /// fees, typically by combining with the Ownable or Access Control
+    /// pattern.
+    pub fn set_fee_config<T: VaultOverrides>(e: &Env, config: &VaultFeeConfig) {
+        if config.entry_fee > MAX_ENTRY_EXIT_FEE_BASIS_POINTS
+            || config.exit_fee > MAX_ENTRY_EXIT_FEE_BASIS_POINTS
+            || config.management_fee > BASIS_POINTS_DENOMINATOR
+            || config.performance_fee > BASIS_POINTS_DENOMINATOR
+        {
+            panic_with_error!(e, VaultTokenError::VaultInvalidFee);
+        }
+
+        Self::accrue_fees::<T>(e);
+        e.storage().instance().set(&VaultFeesStorageKey::Config, config);
+        e.storage().instance().set(&VaultFeesStorageKey::LastAccrual, &e.ledger().timestamp());
+        if config.performance_fee > 0
+            && !e.storage().instance().has(&VaultFeesStorageKey::HighWaterMark)
+        {
+            let total_supply = effective_supply(e, Self::total_supply(e));
+            let price = share_price(e, T::total_assets(e), total_supply);
+            e.storage().instance().set(&VaultFeesStorageKey::HighWaterMark, &price);
+        }
+
+        emit_fee_config_set(e, config);
+    }
+}
+
+/// Returns the fee to add to an amount of assets that doesn't include fees
+/// (rounded up).
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `assets` - The amount of assets, excluding fees.
+/// * `fee` - The fee in basis points.
+pub(crate) fn fee_on_raw(e: &Env, assets: i128, fee: u32) -> i128 {
+    if fee == 0 {
+        return 0;
+    }
+    mul_div_i128(e, assets, fee as i128, BASIS_POINTS_DENOMINATOR as i128, Rounding::Ceil)
+}
+
+/// Returns the fee part of an amount of assets that already includes fees
+/// (rounded up).
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `assets` - The amount of assets, including fees.
+/// * `fee` - The fee in basis points.
+pub(crate) fn fee_on_total(e: &Env, assets: i128, fee: u32) -> i128 {
+    if fee == 0 {
+        return 0;
+    }
+    mul_div_i128(
+        e,
+        assets,
+        fee as i128,
+        fee as i128 + BASIS_POINTS_DENOMINATOR as i128,
+        Rounding::Ceil,
+    )
+}
+
+/// Transfers `fee` of the underlying `asset` from the vault to `recipient`,
+/// if it is not zero.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `asset` - The address of the underlying asset.
+/// * `recipient` - The address receiving the fee.
+/// * `fee` - The amount of assets of the fee.
+pub(crate) fn pay_fee(e: &Env, asset: &Address, recipient: &Address, fee: i128) {
+    if fee > 0 {
+        token::Client::new(e, asset).transfer(&e.current_contract_address(), recipient, &fee);
+    }
+}
+
+/// Returns the total supply including the virtual shares.
+fn effective_supply(e: &Env, total_supply: i128) -> i128 {
+    10_i128
+        .checked_pow(Vault::get_decimals_offset(e))
+        .and_then(|pow| total_supply.checked_add(pow))
+        .unwrap_or_else(|| panic_with_error!(e, VaultTokenError::MathOverflow))
+}
+
+/// Returns the price of a share, scaled by [`SHARE_PRICE_SCALE`], including
+/// the virtual assets and shares.
+fn share_price(e: &Env, total_assets: i128, effective_supply: i128) -> i128 {
+    let total_assets = total_assets
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, VaultTokenError::MathOverflow));
+    mul_div_i128(e, total_assets, SHARE_PRICE_SCALE, effective_supply, Rounding::Floor)
+}
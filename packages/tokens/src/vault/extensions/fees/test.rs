@@ -0,0 +1,237 @@
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env,
+};
+
+use crate::{
+    fungible::Base,
+    vault::{
+        fees::{
+            FeeVault, VaultFeeConfig, VaultFeesStorageKey, SECONDS_PER_YEAR, SHARE_PRICE_SCALE,
+        },
+        Vault, VaultOverrides,
+    },
+};
+
+#[contract]
+struct MockVaultContract;
+
+fn setup(e: &Env) -> (Address, Address, Address) {
+    let vault = e.register(MockVaultContract, ());
+    let asset = e.register_stellar_asset_contract_v2(Address::generate(e)).address();
+    let alice = Address::generate(e);
+    StellarAssetClient::new(e, &asset).mint(&alice, &10_000);
+    e.as_contract(&vault, || Vault::set_asset(e, asset.clone()));
+    (vault, asset, alice)
+}
+
+fn fee_config(recipient: &Address) -> VaultFeeConfig {
+    VaultFeeConfig {
+        recipient: recipient.clone(),
+        entry_fee: 0,
+        exit_fee: 0,
+        management_fee: 0,
+        performance_fee: 0,
+    }
+}
+
+#[test]
+fn entry_fee_on_deposit_and_mint() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, asset, alice) = setup(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&vault, || {
+        Vault::set_fee_config::<FeeVault>(
+            &e,
+            &VaultFeeConfig { entry_fee: 100, ..fee_config(&recipient) },
+        );
+
+        // 1% of the deposited amount, fees excluded
+        assert_eq!(FeeVault::preview_deposit(&e, 1010), 1000);
+        assert_eq!(FeeVault::preview_mint(&e, 1000), 1010);
+        // conversions remain fee-neutral
+        assert_eq!(FeeVault::convert_to_shares(&e, 1010), 1010);
+    });
+
+    e.as_contract(&vault, || {
+        let shares = FeeVault::deposit(&e, 1010, alice.clone(), alice.clone(), alice.clone());
+        assert_eq!(shares, 1000);
+    });
+
+    e.as_contract(&vault, || {
+        let assets = FeeVault::mint(&e, 1000, alice.clone(), alice.clone(), alice.clone());
+        assert_eq!(assets, 1010);
+        assert_eq!(Base::balance(&e, &alice), 2000);
+        assert_eq!(FeeVault::total_assets(&e), 2000);
+    });
+
+    assert_eq!(TokenClient::new(&e, &asset).balance(&recipient), 20);
+}
+
+#[test]
+fn exit_fee_on_withdraw_and_redeem() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, asset, alice) = setup(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&vault, || {
+        FeeVault::deposit(&e, 2000, alice.clone(), alice.clone(), alice.clone());
+        Vault::set_fee_config::<FeeVault>(
+            &e,
+            &VaultFeeConfig { exit_fee: 100, ..fee_config(&recipient) },
+        );
+
+        assert_eq!(FeeVault::preview_redeem(&e, 1000), 990);
+        assert_eq!(FeeVault::preview_withdraw(&e, 990), 1000);
+        assert_eq!(FeeVault::max_withdraw(&e, alice.clone()), 1980);
+    });
+
+    e.as_contract(&vault, || {
+        let assets = FeeVault::redeem(&e, 1000, alice.clone(), alice.clone(), alice.clone());
+        assert_eq!(assets, 990);
+    });
+
+    e.as_contract(&vault, || {
+        let shares = FeeVault::withdraw(&e, 990, alice.clone(), alice.clone(), alice.clone());
+        assert_eq!(shares, 1000);
+        assert_eq!(Base::balance(&e, &alice), 0);
+    });
+
+    let token = TokenClient::new(&e, &asset);
+    assert_eq!(token.balance(&alice), 10_000 - 20);
+    assert_eq!(token.balance(&recipient), 20);
+}
+
+#[test]
+fn management_fee_accrues_over_time() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, _, alice) = setup(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&vault, || {
+        FeeVault::deposit(&e, 1000, alice.clone(), alice.clone(), alice.clone());
+        Vault::set_fee_config::<FeeVault>(
+            &e,
+            &VaultFeeConfig { management_fee: 1000, ..fee_config(&recipient) },
+        );
+        assert_eq!(Vault::accrued_fee_shares::<FeeVault>(&e), 0);
+    });
+
+    e.ledger().with_mut(|li| li.timestamp += SECONDS_PER_YEAR);
+
+    e.as_contract(&vault, || {
+        // shares worth 10% of the total assets once minted
+        assert_eq!(Vault::accrued_fee_shares::<FeeVault>(&e), 111);
+        let price = FeeVault::convert_to_assets(&e, 1000);
+        assert_eq!(price, 900);
+
+        assert_eq!(Vault::accrue_fees::<FeeVault>(&e), 111);
+        assert_eq!(Base::balance(&e, &recipient), 111);
+        assert_eq!(Vault::accrued_fee_shares::<FeeVault>(&e), 0);
+        assert_eq!(FeeVault::convert_to_assets(&e, 1000), price);
+        assert_eq!(Vault::last_fee_accrual(&e), e.ledger().timestamp());
+    });
+}
+
+#[test]
+fn performance_fee_above_high_water_mark() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, asset, alice) = setup(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&vault, || {
+        FeeVault::deposit(&e, 1000, alice.clone(), alice.clone(), alice.clone());
+        Vault::set_fee_config::<FeeVault>(
+            &e,
+            &VaultFeeConfig { performance_fee: 2000, ..fee_config(&recipient) },
+        );
+        assert_eq!(Vault::high_water_mark(&e), SHARE_PRICE_SCALE);
+    });
+
+    // the vault doubles its assets
+    StellarAssetClient::new(&e, &asset).mint(&vault, &1000);
+
+    e.as_contract(&vault, || {
+        // 20% of a profit of 999 assets
+        assert_eq!(Vault::accrued_fee_shares::<FeeVault>(&e), 110);
+        assert_eq!(Vault::accrue_fees::<FeeVault>(&e), 110);
+        assert_eq!(Base::balance(&e, &recipient), 110);
+        assert_eq!(Vault::high_water_mark(&e), 1_801_080_108_010_801_080);
+
+        // no fees are charged until the price exceeds the high-water mark again
+        assert_eq!(Vault::accrued_fee_shares::<FeeVault>(&e), 0);
+    });
+}
+
+#[test]
+fn no_fees_without_config() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, _, alice) = setup(&e);
+
+    e.as_contract(&vault, || {
+        FeeVault::deposit(&e, 1000, alice.clone(), alice.clone(), alice.clone());
+        assert_eq!(Vault::fee_config(&e), None);
+        assert_eq!(Vault::accrue_fees::<FeeVault>(&e), 0);
+        assert_eq!(FeeVault::preview_redeem(&e, 1000), 1000);
+    });
+}
+
+#[test]
+fn plain_vault_ignores_fees() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, _, alice) = setup(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&vault, || {
+        Vault::set_fee_config::<Vault>(
+            &e,
+            &VaultFeeConfig { entry_fee: 100, management_fee: 200, ..fee_config(&recipient) },
+        );
+        e.storage().instance().remove(&VaultFeesStorageKey::LastAccrual);
+
+        assert_eq!(Vault::deposit(&e, 1000, alice.clone(), alice.clone(), alice.clone()), 1000);
+        assert_eq!(Base::balance(&e, &recipient), 0);
+        assert!(!e.storage().instance().has(&VaultFeesStorageKey::LastAccrual));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #414)")]
+fn set_fee_config_above_max_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, _, _) = setup(&e);
+
+    e.as_contract(&vault, || {
+        Vault::set_fee_config::<FeeVault>(
+            &e,
+            &VaultFeeConfig { exit_fee: 10_001, ..fee_config(&Address::generate(&e)) },
+        );
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #414)")]
+fn set_entry_fee_above_max_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, _, _) = setup(&e);
+
+    e.as_contract(&vault, || {
+        Vault::set_fee_config::<FeeVault>(
+            &e,
+            &VaultFeeConfig { entry_fee: 1_001, ..fee_config(&Address::generate(&e)) },
+        );
+    });
+}
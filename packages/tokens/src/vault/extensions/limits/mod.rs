@@ -0,0 +1,274 @@
+mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contractclient, contractevent, contracttrait, Address, Env};
+pub use storage::{LimitedVault, VaultLimitsStorageKey};
+
+use crate::vault::{FungibleVault, Vault};
+
+/// Vault Limits Trait for Fungible Token
+///
+/// The `FungibleVaultLimits` trait extends the `FungibleVault` trait with
+/// configurable deposit limits, for capped or permissioned vaults:
+///
+/// - **Deposit cap**: the maximum total assets of the vault.
+/// - **Account deposit caps**: the maximum assets an account can hold in the
+///   vault after a deposit, its shares being valued at the current share price.
+///   A default cap applies to every account, and can be overridden per account.
+/// - **Deposit verifier**: an optional contract implementing
+///   [`VaultDepositVerifier`], checking whether an account can receive shares
+///   from deposits (e.g. an allowlist or an identity registry).
+///
+/// The contract must use the [`LimitedVault`] contract type, which applies the
+/// limits to `max_deposit` and `max_mint`, so that they are enforced by
+/// `deposit` and `mint`, as well as by the deposit requests of the async vault
+/// extension, as soon as they are configured. When combining extensions, a
+/// custom type must implement [`crate::vault::VaultOverrides::max_deposit`],
+/// [`crate::vault::VaultOverrides::max_deposit_with_total_assets`] and
+/// [`crate::vault::VaultOverrides::can_deposit`] likewise. Plain vaults using
+/// [`Vault`] never read the limits. In
+/// line with ERC-4626, `preview_deposit` and `preview_mint` don't account for
+/// them, and simulate the deposit as if it were accepted.
+///
+/// # Notes
+///
+/// * The limits only apply to deposits: shares can still be transferred to any
+///   account, and beyond its cap. Vaults that must restrict holders should also
+///   restrict transfers, for instance with the allowlist extension of the
+///   fungible token.
+/// * The account caps are not holding limits: they only check the balance of
+///   the receiver at deposit time, so they can be bypassed with share
+///   transfers, or by depositing for several addresses.
+/// * The functions changing state have no default implementation, as a reminder
+///   for the implementor to provide the authorization logic.
+#[contracttrait]
+pub trait FungibleVaultLimits: FungibleVault {
+    /// Returns the maximum amount of total assets of the vault, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn deposit_cap(e: &Env) -> Option<i128> {
+        Vault::deposit_cap(e)
+    }
+
+    /// Returns the maximum amount of assets that any account without a
+    /// specific cap can hold, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn default_account_deposit_cap(e: &Env) -> Option<i128> {
+        Vault::default_account_deposit_cap(e)
+    }
+
+    /// Returns the maximum amount of assets that `account` can hold, if any.
+    ///
+    /// The cap is only checked when `account` receives the shares of a
+    /// deposit. It can be exceeded with share transfers, or bypassed by
+    /// depositing for other addresses, and is therefore not a holding limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address of the account.
+    fn account_deposit_cap(e: &Env, account: Address) -> Option<i128> {
+        Vault::account_deposit_cap(e, &account)
+    }
+
+    /// Returns the address of the contract checking the receivers of
+    /// deposits, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn deposit_verifier(e: &Env) -> Option<Address> {
+        Vault::deposit_verifier(e)
+    }
+
+    /// Sets the maximum amount of total assets of the vault, or removes it.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `cap` - The maximum amount of total assets, `None` for no limit.
+    /// * `operator` - The address authorizing the change.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::set_deposit_cap`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["deposit_cap_set"]`
+    /// * data - `[cap: Option<i128>]`
+    ///
+    /// # Notes
+    ///
+    /// We recommend using [`Vault::set_deposit_cap`] when implementing this
+    /// function.
+    fn set_deposit_cap(e: &Env, cap: Option<i128>, operator: Address);
+
+    /// Sets the maximum amount of assets that any account without a specific
+    /// cap can hold, or removes it.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `cap` - The maximum amount of assets, `None` for no limit.
+    /// * `operator` - The address authorizing the change.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::set_default_account_deposit_cap`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["default_account_deposit_cap_set"]`
+    /// * data - `[cap: Option<i128>]`
+    ///
+    /// # Notes
+    ///
+    /// We recommend using [`Vault::set_default_account_deposit_cap`] when
+    /// implementing this function.
+    fn set_default_account_deposit_cap(e: &Env, cap: Option<i128>, operator: Address);
+
+    /// Sets the maximum amount of assets that `account` can hold, or removes
+    /// it.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address of the account.
+    /// * `cap` - The maximum amount of assets, `None` to fall back to the
+    ///   default account cap.
+    /// * `operator` - The address authorizing the change.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::set_account_deposit_cap`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["account_deposit_cap_set", account: Address]`
+    /// * data - `[cap: Option<i128>]`
+    ///
+    /// # Notes
+    ///
+    /// We recommend using [`Vault::set_account_deposit_cap`] when
+    /// implementing this function.
+    fn set_account_deposit_cap(e: &Env, account: Address, cap: Option<i128>, operator: Address);
+
+    /// Sets the contract checking the receivers of deposits, or removes it.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `verifier` - The address of the deposit verifier, `None` to allow any
+    ///   receiver.
+    /// * `operator` - The address authorizing the change.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["deposit_verifier_set"]`
+    /// * data - `[verifier: Option<Address>]`
+    ///
+    /// # Notes
+    ///
+    /// We recommend using [`Vault::set_deposit_verifier`] when implementing
+    /// this function.
+    fn set_deposit_verifier(e: &Env, verifier: Option<Address>, operator: Address);
+}
+
+/// Deposit Verifier Trait for Vaults
+///
+/// The `VaultDepositVerifier` trait has to be implemented by the contracts
+/// set as deposit verifier of a vault (see [`FungibleVaultLimits`]), for
+/// instance to check an allowlist or the identity of the receivers.
+#[contractclient(name = "VaultDepositVerifierClient")]
+pub trait VaultDepositVerifier {
+    /// Returns whether `receiver` can receive shares of `vault` from
+    /// deposits.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `vault` - The address of the vault.
+    /// * `receiver` - The address that would receive the vault shares.
+    fn can_deposit(e: &Env, vault: Address, receiver: Address) -> bool;
+}
+
+// ################## EVENTS ##################
+
+/// Event emitted when the deposit cap of the vault is set.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositCapSet {
+    pub cap: Option<i128>,
+}
+
+/// Emits an event when the deposit cap of the vault is set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `cap` - The maximum amount of total assets, if any.
+pub fn emit_deposit_cap_set(e: &Env, cap: Option<i128>) {
+    DepositCapSet { cap }.publish(e);
+}
+
+/// Event emitted when the default account deposit cap is set.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DefaultAccountDepositCapSet {
+    pub cap: Option<i128>,
+}
+
+/// Emits an event when the default account deposit cap is set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `cap` - The maximum amount of assets per account, if any.
+pub fn emit_default_account_deposit_cap_set(e: &Env, cap: Option<i128>) {
+    DefaultAccountDepositCapSet { cap }.publish(e);
+}
+
+/// Event emitted when the deposit cap of an account is set.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountDepositCapSet {
+    #[topic]
+    pub account: Address,
+    pub cap: Option<i128>,
+}
+
+/// Emits an event when the deposit cap of an account is set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The address of the account.
+/// * `cap` - The maximum amount of assets of the account, if any.
+pub fn emit_account_deposit_cap_set(e: &Env, account: &Address, cap: Option<i128>) {
+    AccountDepositCapSet { account: account.clone(), cap }.publish(e);
+}
+
+/// Event emitted when the deposit verifier is set.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositVerifierSet {
+    pub verifier: Option<Address>,
+}
+
+/// Emits an event when the deposit verifier is set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `verifier` - The address of the deposit verifier, if any.
+pub fn emit_deposit_verifier_set(e: &Env, verifier: Option<Address>) {
+    DepositVerifierSet { verifier }.publish(e);
+}
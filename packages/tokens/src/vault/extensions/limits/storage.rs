@@ -0,0 +1,353 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+use stellar_contract_utils::math::Rounding;
+
+use crate::{
+    fungible::{ContractOverrides, BALANCE_EXTEND_AMOUNT, BALANCE_TTL_THRESHOLD},
+    vault::{
+        limits::{
+            emit_account_deposit_cap_set, emit_default_account_deposit_cap_set,
+            emit_deposit_cap_set, emit_deposit_verifier_set, VaultDepositVerifierClient,
+        },
+        Vault, VaultOverrides, VaultTokenError,
+    },
+};
+
+/// Storage keys for the data associated with the vault limits extension
+#[contracttype]
+pub enum VaultLimitsStorageKey {
+    /// Stores the maximum total assets of the vault
+    DepositCap,
+    /// Stores the maximum assets held by any account without a specific cap
+    DefaultAccountDepositCap,
+    /// Stores the maximum assets held by a specific account
+    AccountDepositCap(Address),
+    /// Stores the address of the contract checking deposit receivers
+    DepositVerifier,
+}
+
+/// Contract type of the vault limits extension, applying the configured
+/// limits to [`VaultOverrides::max_deposit`] and
+/// [`VaultOverrides::max_mint`], and therefore to the deposits.
+pub struct LimitedVault;
+
+impl ContractOverrides for LimitedVault {
+    fn decimals(e: &Env) -> u32 {
+        Vault::decimals(e)
+    }
+}
+
+impl VaultOverrides for LimitedVault {
+    fn can_deposit(e: &Env, receiver: &Address) -> bool {
+        Vault::can_deposit(e, receiver)
+    }
+
+    fn max_deposit_with_total_assets(e: &Env, receiver: &Address, total_assets: i128) -> i128 {
+        Vault::deposit_limit_with_total_assets::<Self>(e, receiver, total_assets)
+    }
+
+    fn max_deposit(e: &Env, receiver: Address) -> i128 {
+        Vault::deposit_limit::<Self>(e, &receiver)
+    }
+}
+
+impl Vault {
+    // ################## QUERY STATE ##################
+
+    /// Returns the maximum amount of total assets of the vault, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn deposit_cap(e: &Env) -> Option<i128> {
+        e.storage().instance().get(&VaultLimitsStorageKey::DepositCap)
+    }
+
+    /// Returns the maximum amount of assets, valued at the current share
+    /// price, that any account without a specific cap can hold, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn default_account_deposit_cap(e: &Env) -> Option<i128> {
+        e.storage().instance().get(&VaultLimitsStorageKey::DefaultAccountDepositCap)
+    }
+
+    /// Returns the maximum amount of assets, valued at the current share
+    /// price, that `account` can hold, if any. Falls back to
+    /// [`Vault::default_account_deposit_cap`] when `account` has no specific
+    /// cap.
+    ///
+    /// The cap is only checked against the holdings of `account` when it
+    /// receives the shares of a deposit (see [`Vault::deposit_limit`]). It is
+    /// not a holding limit: `account` can exceed it by receiving share
+    /// transfers, and a depositor can bypass it by depositing for other
+    /// addresses it controls.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address of the account.
+    pub fn account_deposit_cap(e: &Env, account: &Address) -> Option<i128> {
+        let key = VaultLimitsStorageKey::AccountDepositCap(account.clone());
+        match e.storage().persistent().get::<_, i128>(&key) {
+            Some(cap) => {
+                e.storage().persistent().extend_ttl(
+                    &key,
+                    BALANCE_TTL_THRESHOLD,
+                    BALANCE_EXTEND_AMOUNT,
+                );
+                Some(cap)
+            }
+            None => Self::default_account_deposit_cap(e),
+        }
+    }
+
+    /// Returns the address of the contract checking the receivers of
+    /// deposits, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn deposit_verifier(e: &Env) -> Option<Address> {
+        e.storage().instance().get(&VaultLimitsStorageKey::DepositVerifier)
+    }
+
+    /// Returns whether `receiver` is allowed to receive vault shares from
+    /// deposits, according to the deposit verifier. Returns `true` if no
+    /// deposit verifier is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `receiver` - The address that would receive the vault shares.
+    pub fn can_deposit(e: &Env, receiver: &Address) -> bool {
+        match Self::deposit_verifier(e) {
+            Some(verifier) => VaultDepositVerifierClient::new(e, &verifier)
+                .can_deposit(&e.current_contract_address(), receiver),
+            None => true,
+        }
+    }
+
+    /// Returns the maximum amount of underlying assets that can be deposited
+    /// for `receiver` under the configured limits:
+    ///
+    /// - 0 if `receiver` is rejected by the deposit verifier.
+    /// - the room left below the deposit cap of the vault, the assets of
+    ///   pending deposit requests (see [`Vault::total_pending_deposits`])
+    ///   counting towards it.
+    /// - the room left below the deposit cap of `receiver`, its vault shares
+    ///   and the shares of its claimable deposit request being valued at the
+    ///   current share price (rounded up), and the assets of its pending
+    ///   deposit request counting towards it.
+    ///
+    /// Returns `i128::MAX` if no limit is configured.
+    ///
+    /// The account cap only accounts for what `receiver` holds at the time of
+    /// the deposit. Shares transferred to `receiver` afterwards, or deposits
+    /// made for other addresses, are not constrained by it. Therefore, it
+    /// must not be relied upon as a limit on the holdings of an account or
+    /// of its owner.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `receiver` - The address that would receive the vault shares.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`VaultOverrides::total_assets`] and
+    ///   [`Vault::deposit_limit_with_total_assets`] errors.
+    pub fn deposit_limit<T: VaultOverrides>(e: &Env, receiver: &Address) -> i128 {
+        // The total assets are only needed, and computed, when a cap is set.
+        if Self::deposit_cap(e).is_none() && Self::account_deposit_cap(e, receiver).is_none() {
+            return if Self::can_deposit(e, receiver) { i128::MAX } else { 0 };
+        }
+        Self::deposit_limit_with_total_assets::<T>(e, receiver, T::total_assets(e))
+    }
+
+    /// Same as [`Vault::deposit_limit`], with the total assets of the vault
+    /// given by the caller.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `receiver` - The address that would receive the vault shares.
+    /// * `total_assets` - The total assets of the vault, as returned by
+    ///   [`VaultOverrides::total_assets`].
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`VaultOverrides::convert_to_assets_with_total_assets`]
+    ///   errors.
+    pub fn deposit_limit_with_total_assets<T: VaultOverrides>(
+        e: &Env,
+        receiver: &Address,
+        total_assets: i128,
+    ) -> i128 {
+        if !Self::can_deposit(e, receiver) {
+            return 0;
+        }
+
+        let mut limit = i128::MAX;
+        if let Some(cap) = Self::deposit_cap(e) {
+            // Pending deposits are excluded from the total assets, but will back
+            // shares once fulfilled. Claimable ones are already included.
+            let assets = total_assets.saturating_add(Self::total_pending_deposits(e));
+            limit = limit.min(cap.saturating_sub(assets));
+        }
+        if let Some(cap) = Self::account_deposit_cap(e, receiver) {
+            let shares = Self::balance(e, receiver)
+                .saturating_add(Self::claimable_deposit_request(e, receiver).shares);
+            let held =
+                T::convert_to_assets_with_total_assets(e, shares, total_assets, Rounding::Ceil)
+                    .saturating_add(Self::pending_deposit_request(e, receiver));
+            limit = limit.min(cap.saturating_sub(held));
+        }
+        limit.max(0)
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Sets the maximum amount of total assets of the vault, or removes it.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `cap` - The maximum amount of total assets, `None` for no limit.
+    ///
+    /// # Errors
+    ///
+    /// * [`VaultTokenError::VaultInvalidAssetsAmount`] - When `cap < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["deposit_cap_set"]`
+    /// * data - `[cap: Option<i128>]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can set the
+    /// deposit limits.
+    pub fn set_deposit_cap(e: &Env, cap: Option<i128>) {
+        check_cap(e, cap);
+        match cap {
+            Some(cap) => e.storage().instance().set(&VaultLimitsStorageKey::DepositCap, &cap),
+            None => e.storage().instance().remove(&VaultLimitsStorageKey::DepositCap),
+        }
+
+        emit_deposit_cap_set(e, cap);
+    }
+
+    /// Sets the maximum amount of assets that any account without a specific
+    /// cap can hold, or removes it.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `cap` - The maximum amount of assets, `None` for no limit.
+    ///
+    /// # Errors
+    ///
+    /// * [`VaultTokenError::VaultInvalidAssetsAmount`] - When `cap < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["default_account_deposit_cap_set"]`
+    /// * data - `[cap: Option<i128>]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can set the
+    /// deposit limits.
+    pub fn set_default_account_deposit_cap(e: &Env, cap: Option<i128>) {
+        check_cap(e, cap);
+        let key = VaultLimitsStorageKey::DefaultAccountDepositCap;
+        match cap {
+            Some(cap) => e.storage().instance().set(&key, &cap),
+            None => e.storage().instance().remove(&key),
+        }
+
+        emit_default_account_deposit_cap_set(e, cap);
+    }
+
+    /// Sets the maximum amount of assets that `account` can hold, or removes
+    /// it, in which case the default account cap applies.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address of the account.
+    /// * `cap` - The maximum amount of assets, `None` to fall back to the
+    ///   default account cap.
+    ///
+    /// # Errors
+    ///
+    /// * [`VaultTokenError::VaultInvalidAssetsAmount`] - When `cap < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["account_deposit_cap_set", account: Address]`
+    /// * data - `[cap: Option<i128>]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can set the
+    /// deposit limits.
+    pub fn set_account_deposit_cap(e: &Env, account: &Address, cap: Option<i128>) {
+        check_cap(e, cap);
+        let key = VaultLimitsStorageKey::AccountDepositCap(account.clone());
+        match cap {
+            Some(cap) => e.storage().persistent().set(&key, &cap),
+            None => e.storage().persistent().remove(&key),
+        }
+
+        emit_account_deposit_cap_set(e, account, cap);
+    }
+
+    /// Sets the contract checking the receivers of deposits, or removes it.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `verifier` - The address of a contract implementing
+    ///   [`crate::vault::limits::VaultDepositVerifier`], `None` to allow any
+    ///   receiver.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["deposit_verifier_set"]`
+    /// * data - `[verifier: Option<Address>]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can set the
+    /// deposit verifier.
+    pub fn set_deposit_verifier(e: &Env, verifier: Option<Address>) {
+        match &verifier {
+            Some(verifier) =>
+                e.storage().instance().set(&VaultLimitsStorageKey::DepositVerifier, verifier),
+            None => e.storage().instance().remove(&VaultLimitsStorageKey::DepositVerifier),
+        }
+
+        emit_deposit_verifier_set(e, verifier);
+    }
+}
+
+/// Panics if `cap` is negative.
+fn check_cap(e: &Env, cap: Option<i128>) {
+    if cap.is_some_and(|cap| cap < 0) {
+        panic_with_error!(e, VaultTokenError::VaultInvalidAssetsAmount);
+    }
+}
@@ -0,0 +1,181 @@
+extern crate std;
+
+use soroban_sdk::{
+    contract, contractimpl, contracttype, testutils::Address as _, token::StellarAssetClient,
+    Address, Env,
+};
+
+use crate::vault::{
+    limits::{LimitedVault, VaultDepositVerifier},
+    Vault, VaultOverrides,
+};
+
+#[contract]
+struct MockVaultContract;
+
+#[contracttype]
+enum MockVerifierStorageKey {
+    Allowed(Address),
+}
+
+#[contract]
+struct MockVerifier;
+
+#[contractimpl]
+impl MockVerifier {
+    pub fn allow(e: &Env, account: Address) {
+        e.storage().instance().set(&MockVerifierStorageKey::Allowed(account), &true);
+    }
+}
+
+#[contractimpl]
+impl VaultDepositVerifier for MockVerifier {
+    fn can_deposit(e: &Env, _vault: Address, receiver: Address) -> bool {
+        e.storage().instance().has(&MockVerifierStorageKey::Allowed(receiver))
+    }
+}
+
+fn setup(e: &Env) -> (Address, Address) {
+    let vault = e.register(MockVaultContract, ());
+    let asset = e.register_stellar_asset_contract_v2(Address::generate(e)).address();
+    let alice = Address::generate(e);
+    StellarAssetClient::new(e, &asset).mint(&alice, &10_000);
+    e.as_contract(&vault, || Vault::set_asset(e, asset.clone()));
+    (vault, alice)
+}
+
+#[test]
+fn no_limits_by_default() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, alice) = setup(&e);
+
+    e.as_contract(&vault, || {
+        assert_eq!(Vault::deposit_cap(&e), None);
+        assert_eq!(Vault::account_deposit_cap(&e, &alice), None);
+        assert_eq!(Vault::deposit_verifier(&e), None);
+        assert_eq!(LimitedVault::max_deposit(&e, alice.clone()), i128::MAX);
+        assert_eq!(LimitedVault::max_mint(&e, alice.clone()), i128::MAX);
+    });
+}
+
+#[test]
+fn deposit_cap_limits_total_assets() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, alice) = setup(&e);
+
+    e.as_contract(&vault, || {
+        Vault::set_deposit_cap(&e, Some(1500));
+        assert_eq!(Vault::deposit_cap(&e), Some(1500));
+        LimitedVault::deposit(&e, 1000, alice.clone(), alice.clone(), alice.clone());
+    });
+
+    e.as_contract(&vault, || {
+        assert_eq!(LimitedVault::max_deposit(&e, alice.clone()), 500);
+        assert_eq!(LimitedVault::max_mint(&e, alice.clone()), 500);
+
+        Vault::set_deposit_cap(&e, None);
+        assert_eq!(LimitedVault::max_deposit(&e, alice.clone()), i128::MAX);
+    });
+}
+
+#[test]
+fn account_deposit_caps() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, alice) = setup(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&vault, || {
+        Vault::set_default_account_deposit_cap(&e, Some(300));
+        Vault::set_account_deposit_cap(&e, &bob, Some(800));
+        assert_eq!(Vault::account_deposit_cap(&e, &alice), Some(300));
+        assert_eq!(Vault::account_deposit_cap(&e, &bob), Some(800));
+        assert_eq!(LimitedVault::max_deposit(&e, bob.clone()), 800);
+
+        LimitedVault::deposit(&e, 200, alice.clone(), alice.clone(), alice.clone());
+    });
+
+    e.as_contract(&vault, || {
+        assert_eq!(LimitedVault::max_deposit(&e, alice.clone()), 100);
+
+        // removing the specific cap falls back to the default one
+        Vault::set_account_deposit_cap(&e, &bob, None);
+        assert_eq!(LimitedVault::max_deposit(&e, bob.clone()), 300);
+    });
+}
+
+#[test]
+fn deposit_verifier_restricts_receivers() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, alice) = setup(&e);
+    let verifier = e.register(MockVerifier, ());
+    MockVerifierClient::new(&e, &verifier).allow(&alice);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&vault, || {
+        Vault::set_deposit_verifier(&e, Some(verifier.clone()));
+        assert_eq!(Vault::deposit_verifier(&e), Some(verifier.clone()));
+        assert!(Vault::can_deposit(&e, &alice));
+        assert!(!Vault::can_deposit(&e, &bob));
+        assert_eq!(LimitedVault::max_deposit(&e, alice.clone()), i128::MAX);
+        assert_eq!(LimitedVault::max_deposit(&e, bob.clone()), 0);
+        assert_eq!(LimitedVault::max_mint(&e, bob.clone()), 0);
+    });
+}
+
+#[test]
+fn plain_vault_ignores_limits() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, alice) = setup(&e);
+
+    e.as_contract(&vault, || {
+        Vault::set_deposit_cap(&e, Some(500));
+        assert_eq!(Vault::max_deposit(&e, alice.clone()), i128::MAX);
+        assert_eq!(Vault::max_mint(&e, alice.clone()), i128::MAX);
+        Vault::deposit(&e, 1000, alice.clone(), alice.clone(), alice.clone());
+        assert_eq!(LimitedVault::max_deposit(&e, alice.clone()), 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #405)")]
+fn deposit_for_rejected_receiver_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, alice) = setup(&e);
+    let verifier = e.register(MockVerifier, ());
+
+    e.as_contract(&vault, || {
+        Vault::set_deposit_verifier(&e, Some(verifier.clone()));
+        LimitedVault::deposit(&e, 100, alice.clone(), alice.clone(), alice.clone());
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #406)")]
+fn mint_above_deposit_cap_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, alice) = setup(&e);
+
+    e.as_contract(&vault, || {
+        Vault::set_deposit_cap(&e, Some(1000));
+        LimitedVault::mint(&e, 1001, alice.clone(), alice.clone(), alice.clone());
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #403)")]
+fn set_negative_cap_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, _) = setup(&e);
+
+    e.as_contract(&vault, || {
+        Vault::set_default_account_deposit_cap(&e, Some(-1));
+    });
+}
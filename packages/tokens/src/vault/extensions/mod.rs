@@ -0,0 +1,4 @@
+pub mod async_vault;
+pub mod fees;
+pub mod limits;
+pub mod strategies;
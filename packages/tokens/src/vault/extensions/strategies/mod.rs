@@ -0,0 +1,408 @@
+mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contractclient, contractevent, contracttrait, Address, Env, Vec};
+pub use storage::{StrategyVault, VaultStrategiesStorageKey, MAX_STRATEGIES};
+
+use crate::vault::{FungibleVault, Vault};
+
+/// Multi-Strategy Vault Trait for Fungible Token
+///
+/// The `FungibleVaultStrategies` trait extends the `FungibleVault` trait to
+/// deploy the underlying assets of the vault into a list of strategy
+/// contracts implementing [`VaultStrategy`], for instance lending markets or
+/// liquidity pools.
+///
+/// The contract must use the [`StrategyVault`] contract type, whose total
+/// assets aggregate the idle assets held by the vault and the value reported
+/// by each strategy, so that the yield generated by the strategies is
+/// reflected in the share price. When combining extensions, a custom type must
+/// implement [`crate::vault::VaultOverrides::total_assets`] and
+/// [`crate::vault::VaultOverrides::ensure_idle_assets`] likewise. Plain vaults
+/// using [`Vault`] never query any strategy.
+///
+/// - **Allocation**: an allocator moves idle assets into a strategy with
+///   [`FungibleVaultStrategies::allocate`], and back with
+///   [`FungibleVaultStrategies::deallocate`]. Each strategy has an allocation
+///   cap, which can't be exceeded by `allocate`.
+/// - **Withdrawal queue**: when a withdrawal requires more assets than the
+///   vault holds, the missing assets are deallocated from the strategies, in
+///   the order of the withdrawal queue. The queue contains every strategy, in
+///   the order they were added, until reordered with
+///   [`FungibleVaultStrategies::set_withdrawal_queue`].
+///
+/// # Notes
+///
+/// The functions changing state have no default implementation, as a
+/// reminder for the implementor to provide the authorization logic. A
+/// typical setup restricts `allocate` and `deallocate` to an `"allocator"`
+/// role, and the management of the strategies to a more privileged role,
+/// e.g. with `#[only_role(operator, "allocator")]` from `stellar-macros`.
+///
+/// # Security Considerations
+///
+/// Strategies are fully trusted: a strategy over-reporting its value inflates
+/// the share price, and a strategy failing to return assets blocks the
+/// withdrawals relying on it. The number of strategies is bounded by
+/// [`MAX_STRATEGIES`], as all of them are queried by every conversion.
+#[contracttrait]
+pub trait FungibleVaultStrategies: FungibleVault {
+    /// Returns the strategies of the vault, in the order they were added.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn strategies(e: &Env) -> Vec<Address> {
+        Vault::strategies(e)
+    }
+
+    /// Returns the order in which strategies are drawn from on withdrawals.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn withdrawal_queue(e: &Env) -> Vec<Address> {
+        Vault::withdrawal_queue(e)
+    }
+
+    /// Returns the maximum amount of assets that can be allocated to
+    /// `strategy`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `strategy` - The address of the strategy.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::strategy_cap`] errors.
+    fn strategy_cap(e: &Env, strategy: Address) -> i128 {
+        Vault::strategy_cap(e, &strategy)
+    }
+
+    /// Returns the value, in underlying assets, of the position of the vault
+    /// in `strategy`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `strategy` - The address of the strategy.
+    fn strategy_assets(e: &Env, strategy: Address) -> i128 {
+        Vault::strategy_assets(e, &strategy)
+    }
+
+    /// Returns the amount of underlying assets held by the vault itself and
+    /// available to be allocated or withdrawn right away.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::idle_assets`] errors.
+    fn idle_assets(e: &Env) -> i128 {
+        Vault::idle_assets(e)
+    }
+
+    /// Adds `strategy` to the vault, at the end of the withdrawal queue.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `strategy` - The address of the strategy.
+    /// * `cap` - The maximum amount of assets allocated to the strategy.
+    /// * `operator` - The address authorizing the change.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::add_strategy`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["strategy_added", strategy: Address]`
+    /// * data - `[cap: i128]`
+    ///
+    /// # Notes
+    ///
+    /// We recommend using [`Vault::add_strategy`] when implementing this
+    /// function.
+    fn add_strategy(e: &Env, strategy: Address, cap: i128, operator: Address);
+
+    /// Removes `strategy`, which must not hold any assets of the vault.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `strategy` - The address of the strategy.
+    /// * `operator` - The address authorizing the change.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::remove_strategy`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["strategy_removed", strategy: Address]`
+    /// * data - `[]`
+    ///
+    /// # Notes
+    ///
+    /// We recommend using [`Vault::remove_strategy`] when implementing this
+    /// function.
+    fn remove_strategy(e: &Env, strategy: Address, operator: Address);
+
+    /// Sets the maximum amount of assets that can be allocated to `strategy`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `strategy` - The address of the strategy.
+    /// * `cap` - The maximum amount of assets.
+    /// * `operator` - The address authorizing the change.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::set_strategy_cap`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["strategy_cap_set", strategy: Address]`
+    /// * data - `[cap: i128]`
+    ///
+    /// # Notes
+    ///
+    /// We recommend using [`Vault::set_strategy_cap`] when implementing this
+    /// function.
+    fn set_strategy_cap(e: &Env, strategy: Address, cap: i128, operator: Address);
+
+    /// Sets the order in which strategies are drawn from on withdrawals.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `queue` - The strategies of the vault, in withdrawal order.
+    /// * `operator` - The address authorizing the change.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::set_withdrawal_queue`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["withdrawal_queue_set"]`
+    /// * data - `[queue: Vec<Address>]`
+    ///
+    /// # Notes
+    ///
+    /// We recommend using [`Vault::set_withdrawal_queue`] when implementing
+    /// this function.
+    fn set_withdrawal_queue(e: &Env, queue: Vec<Address>, operator: Address);
+
+    /// Allocates `assets` of the idle assets of the vault to `strategy`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `strategy` - The address of the strategy.
+    /// * `assets` - The amount of underlying assets to allocate.
+    /// * `operator` - The address authorizing the allocation.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::allocate`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["allocated", strategy: Address]`
+    /// * data - `[assets: i128]`
+    ///
+    /// # Notes
+    ///
+    /// We recommend using [`Vault::allocate`] when implementing this
+    /// function.
+    fn allocate(e: &Env, strategy: Address, assets: i128, operator: Address);
+
+    /// Deallocates `assets` from `strategy` back to the vault.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `strategy` - The address of the strategy.
+    /// * `assets` - The amount of underlying assets to deallocate.
+    /// * `operator` - The address authorizing the deallocation.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::deallocate`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["deallocated", strategy: Address]`
+    /// * data - `[assets: i128]`
+    ///
+    /// # Notes
+    ///
+    /// We recommend using [`Vault::deallocate`] when implementing this
+    /// function.
+    fn deallocate(e: &Env, strategy: Address, assets: i128, operator: Address);
+}
+
+/// Strategy Trait for Vaults
+///
+/// The `VaultStrategy` trait has to be implemented by the contracts in which
+/// a vault deploys its underlying assets (see [`FungibleVaultStrategies`]).
+/// All amounts are expressed in the underlying asset of the vault.
+///
+/// A strategy can serve a single vault or several of them, the calling vault
+/// being passed to every function. Implementations should check that
+/// `vault.require_auth()` passes in `deploy` and `withdraw`.
+#[contractclient(name = "VaultStrategyClient")]
+pub trait VaultStrategy {
+    /// Deploys `assets`, which the vault transferred to the strategy right
+    /// before the call.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `vault` - The address of the vault.
+    /// * `assets` - The amount of underlying assets transferred.
+    fn deploy(e: &Env, vault: Address, assets: i128);
+
+    /// Transfers `assets` of the position of `vault` back to `vault`. Must
+    /// panic if it can't transfer the full amount.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `vault` - The address of the vault.
+    /// * `assets` - The amount of underlying assets to transfer.
+    fn withdraw(e: &Env, vault: Address, assets: i128);
+
+    /// Returns the value of the position of `vault` in underlying assets.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `vault` - The address of the vault.
+    fn total_value(e: &Env, vault: Address) -> i128;
+}
+
+// ################## EVENTS ##################
+
+/// Event emitted when a strategy is added.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StrategyAdded {
+    #[topic]
+    pub strategy: Address,
+    pub cap: i128,
+}
+
+/// Emits an event when a strategy is added.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `strategy` - The address of the strategy.
+/// * `cap` - The maximum amount of assets allocated to the strategy.
+pub fn emit_strategy_added(e: &Env, strategy: &Address, cap: i128) {
+    StrategyAdded { strategy: strategy.clone(), cap }.publish(e);
+}
+
+/// Event emitted when a strategy is removed.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StrategyRemoved {
+    #[topic]
+    pub strategy: Address,
+}
+
+/// Emits an event when a strategy is removed.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `strategy` - The address of the strategy.
+pub fn emit_strategy_removed(e: &Env, strategy: &Address) {
+    StrategyRemoved { strategy: strategy.clone() }.publish(e);
+}
+
+/// Event emitted when the allocation cap of a strategy is set.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StrategyCapSet {
+    #[topic]
+    pub strategy: Address,
+    pub cap: i128,
+}
+
+/// Emits an event when the allocation cap of a strategy is set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `strategy` - The address of the strategy.
+/// * `cap` - The maximum amount of assets allocated to the strategy.
+pub fn emit_strategy_cap_set(e: &Env, strategy: &Address, cap: i128) {
+    StrategyCapSet { strategy: strategy.clone(), cap }.publish(e);
+}
+
+/// Event emitted when the withdrawal queue is set.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalQueueSet {
+    pub queue: Vec<Address>,
+}
+
+/// Emits an event when the withdrawal queue is set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `queue` - The strategies of the vault, in withdrawal order.
+pub fn emit_withdrawal_queue_set(e: &Env, queue: &Vec<Address>) {
+    WithdrawalQueueSet { queue: queue.clone() }.publish(e);
+}
+
+/// Event emitted when assets are allocated to a strategy.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Allocated {
+    #[topic]
+    pub strategy: Address,
+    pub assets: i128,
+}
+
+/// Emits an event when assets are allocated to a strategy.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `strategy` - The address of the strategy.
+/// * `assets` - The amount of underlying assets allocated.
+pub fn emit_allocated(e: &Env, strategy: &Address, assets: i128) {
+    Allocated { strategy: strategy.clone(), assets }.publish(e);
+}
+
+/// Event emitted when assets are deallocated from a strategy.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Deallocated {
+    #[topic]
+    pub strategy: Address,
+    pub assets: i128,
+}
+
+/// Emits an event when assets are deallocated from a strategy.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `strategy` - The address of the strategy.
+/// * `assets` - The amount of underlying assets deallocated.
+pub fn emit_deallocated(e: &Env, strategy: &Address, assets: i128) {
+    Deallocated { strategy: strategy.clone(), assets }.publish(e);
+}
@@ -0,0 +1,477 @@
+use soroban_sdk::{contracttype, panic_with_error, token, Address, Env, Vec};
+
+use crate::{
+    fungible::ContractOverrides,
+    vault::{
+        strategies::{
+            emit_allocated, emit_deallocated, emit_strategy_added, emit_strategy_cap_set,
+            emit_strategy_removed, emit_withdrawal_queue_set, VaultStrategyClient,
+        },
+        Vault, VaultOverrides, VaultTokenError,
+    },
+};
+
+/// Maximum number of strategies of a vault, bounding the cost of the total
+/// assets of [`StrategyVault`], which queries each of them.
+pub const MAX_STRATEGIES: u32 = 20;
+
+/// Contract type of the vault strategies extension, adding the assets
+/// deployed into strategies (see [`Vault::deployed_assets`]) to the total
+/// assets of the vault, and deallocating them when needed by withdrawals.
+pub struct StrategyVault;
+
+impl ContractOverrides for StrategyVault {
+    fn decimals(e: &Env) -> u32 {
+        Vault::decimals(e)
+    }
+}
+
+impl VaultOverrides for StrategyVault {
+    fn total_assets(e: &Env) -> i128 {
+        Vault::total_assets(e)
+            .checked_add(Vault::deployed_assets(e))
+            .unwrap_or_else(|| panic_with_error!(e, VaultTokenError::MathOverflow))
+    }
+
+    fn ensure_idle_assets(e: &Env, assets: i128) {
+        Vault::ensure_idle_assets(e, assets);
+    }
+}
+
+/// Storage keys for the data associated with the vault strategies extension
+#[contracttype]
+pub enum VaultStrategiesStorageKey {
+    /// Stores the list of strategies
+    Strategies,
+    /// Stores the order in which strategies are drawn from on withdrawals
+    WithdrawalQueue,
+    /// Stores the maximum amount of assets allocated to a strategy
+    Cap(Address),
+}
+
+impl Vault {
+    // ################## QUERY STATE ##################
+
+    /// Returns the strategies of the vault, in the order they were added.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn strategies(e: &Env) -> Vec<Address> {
+        e.storage()
+            .instance()
+            .get(&VaultStrategiesStorageKey::Strategies)
+            .unwrap_or_else(|| Vec::new(e))
+    }
+
+    /// Returns the order in which strategies are drawn from when the vault
+    /// doesn't hold enough idle assets to process a withdrawal.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn withdrawal_queue(e: &Env) -> Vec<Address> {
+        e.storage()
+            .instance()
+            .get(&VaultStrategiesStorageKey::WithdrawalQueue)
+            .unwrap_or_else(|| Vec::new(e))
+    }
+
+    /// Returns the maximum amount of assets that can be allocated to
+    /// `strategy`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `strategy` - The address of the strategy.
+    ///
+    /// # Errors
+    ///
+    /// * [`VaultTokenError::VaultStrategyNotFound`] - When `strategy` is not a
+    ///   strategy of the vault.
+    pub fn strategy_cap(e: &Env, strategy: &Address) -> i128 {
+        e.storage()
+            .instance()
+            .get(&VaultStrategiesStorageKey::Cap(strategy.clone()))
+            .unwrap_or_else(|| panic_with_error!(e, VaultTokenError::VaultStrategyNotFound))
+    }
+
+    /// Returns the value, in underlying assets, of the position of the vault
+    /// in `strategy`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `strategy` - The address of the strategy.
+    pub fn strategy_assets(e: &Env, strategy: &Address) -> i128 {
+        VaultStrategyClient::new(e, strategy).total_value(&e.current_contract_address())
+    }
+
+    /// Returns the value, in underlying assets, of the positions of the vault
+    /// in all its strategies.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`VaultTokenError::MathOverflow`] - When the sum overflows.
+    pub fn deployed_assets(e: &Env) -> i128 {
+        Self::strategies(e).iter().fold(0_i128, |total, strategy| {
+            total
+                .checked_add(Self::strategy_assets(e, &strategy))
+                .unwrap_or_else(|| panic_with_error!(e, VaultTokenError::MathOverflow))
+        })
+    }
+
+    /// Returns the amount of underlying assets held by the vault itself,
+    /// excluding the reserved assets (see [`Vault::reserved_assets`]), and
+    /// available to be allocated or withdrawn right away.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Vault::query_asset`] errors.
+    pub fn idle_assets(e: &Env) -> i128 {
+        let token_client = token::Client::new(e, &Self::query_asset(e));
+        let balance = token_client.balance(&e.current_contract_address());
+        balance.saturating_sub(Self::reserved_assets(e)).max(0)
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Adds `strategy` to the vault, at the end of the withdrawal queue.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `strategy` - The address of the strategy.
+    /// * `cap` - The maximum amount of assets that can be allocated to the
+    ///   strategy.
+    ///
+    /// # Errors
+    ///
+    /// * [`VaultTokenError::VaultStrategyAlreadyExists`] - When `strategy` is
+    ///   already a strategy of the vault.
+    /// * [`VaultTokenError::VaultMaxStrategiesExceeded`] - When the vault
+    ///   already has [`MAX_STRATEGIES`] strategies.
+    /// * [`VaultTokenError::VaultInvalidAssetsAmount`] - When `cap < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["strategy_added", strategy: Address]`
+    /// * data - `[cap: i128]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// Strategies are trusted to report their value and to return the assets
+    /// of the vault. It is the responsibility of the implementer to establish
+    /// appropriate access controls to ensure that only authorized accounts can
+    /// add strategies.
+    pub fn add_strategy(e: &Env, strategy: &Address, cap: i128) {
+        let mut strategies = Self::strategies(e);
+        if strategies.contains(strategy) {
+            panic_with_error!(e, VaultTokenError::VaultStrategyAlreadyExists);
+        }
+        if strategies.len() >= MAX_STRATEGIES {
+            panic_with_error!(e, VaultTokenError::VaultMaxStrategiesExceeded);
+        }
+        if cap < 0 {
+            panic_with_error!(e, VaultTokenError::VaultInvalidAssetsAmount);
+        }
+
+        strategies.push_back(strategy.clone());
+        e.storage().instance().set(&VaultStrategiesStorageKey::Strategies, &strategies);
+
+        let mut queue = Self::withdrawal_queue(e);
+        queue.push_back(strategy.clone());
+        e.storage().instance().set(&VaultStrategiesStorageKey::WithdrawalQueue, &queue);
+
+        e.storage().instance().set(&VaultStrategiesStorageKey::Cap(strategy.clone()), &cap);
+
+        emit_strategy_added(e, strategy, cap);
+    }
+
+    /// Removes `strategy` from the vault and from the withdrawal queue.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `strategy` - The address of the strategy.
+    ///
+    /// # Errors
+    ///
+    /// * [`VaultTokenError::VaultStrategyNotFound`] - When `strategy` is not a
+    ///   strategy of the vault.
+    /// * [`VaultTokenError::VaultStrategyNotEmpty`] - When the vault still has
+    ///   assets allocated to `strategy`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["strategy_removed", strategy: Address]`
+    /// * data - `[]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can remove
+    /// strategies.
+    pub fn remove_strategy(e: &Env, strategy: &Address) {
+        let mut strategies = Self::strategies(e);
+        let Some(index) = strategies.first_index_of(strategy) else {
+            panic_with_error!(e, VaultTokenError::VaultStrategyNotFound);
+        };
+        if Self::strategy_assets(e, strategy) > 0 {
+            panic_with_error!(e, VaultTokenError::VaultStrategyNotEmpty);
+        }
+
+        strategies.remove(index);
+        e.storage().instance().set(&VaultStrategiesStorageKey::Strategies, &strategies);
+
+        let mut queue = Self::withdrawal_queue(e);
+        if let Some(index) = queue.first_index_of(strategy) {
+            queue.remove(index);
+            e.storage().instance().set(&VaultStrategiesStorageKey::WithdrawalQueue, &queue);
+        }
+
+        e.storage().instance().remove(&VaultStrategiesStorageKey::Cap(strategy.clone()));
+
+        emit_strategy_removed(e, strategy);
+    }
+
+    /// Sets the maximum amount of assets that can be allocated to `strategy`.
+    /// Lowering the cap below the current allocation doesn't deallocate any
+    /// assets, but prevents further allocations.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `strategy` - The address of the strategy.
+    /// * `cap` - The maximum amount of assets.
+    ///
+    /// # Errors
+    ///
+    /// * [`VaultTokenError::VaultStrategyNotFound`] - When `strategy` is not a
+    ///   strategy of the vault.
+    /// * [`VaultTokenError::VaultInvalidAssetsAmount`] - When `cap < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["strategy_cap_set", strategy: Address]`
+    /// * data - `[cap: i128]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can set the
+    /// allocation caps.
+    pub fn set_strategy_cap(e: &Env, strategy: &Address, cap: i128) {
+        // ensures the strategy exists
+        Self::strategy_cap(e, strategy);
+        if cap < 0 {
+            panic_with_error!(e, VaultTokenError::VaultInvalidAssetsAmount);
+        }
+        e.storage().instance().set(&VaultStrategiesStorageKey::Cap(strategy.clone()), &cap);
+
+        emit_strategy_cap_set(e, strategy, cap);
+    }
+
+    /// Sets the order in which strategies are drawn from on withdrawals.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `queue` - The strategies of the vault, in withdrawal order.
+    ///
+    /// # Errors
+    ///
+    /// * [`VaultTokenError::VaultInvalidWithdrawalQueue`] - When `queue` is not
+    ///   a permutation of the strategies of the vault.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["withdrawal_queue_set"]`
+    /// * data - `[queue: Vec<Address>]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can set the
+    /// withdrawal queue.
+    pub fn set_withdrawal_queue(e: &Env, queue: &Vec<Address>) {
+        let strategies = Self::strategies(e);
+        if queue.len() != strategies.len() {
+            panic_with_error!(e, VaultTokenError::VaultInvalidWithdrawalQueue);
+        }
+        for (i, strategy) in queue.iter().enumerate() {
+            // rejects unknown strategies and duplicates
+            if !strategies.contains(&strategy) || queue.first_index_of(&strategy) != Some(i as u32)
+            {
+                panic_with_error!(e, VaultTokenError::VaultInvalidWithdrawalQueue);
+            }
+        }
+        e.storage().instance().set(&VaultStrategiesStorageKey::WithdrawalQueue, queue);
+
+        emit_withdrawal_queue_set(e, queue);
+    }
+
+    /// Allocates `assets` of the idle assets of the vault to `strategy`,
+    /// by transferring them to the strategy and calling
+    /// [`crate::vault::strategies::VaultStrategy::deploy`].
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `strategy` - The address of the strategy.
+    /// * `assets` - The amount of underlying assets to allocate.
+    ///
+    /// # Errors
+    ///
+    /// * [`VaultTokenError::VaultStrategyNotFound`] - When `strategy` is not a
+    ///   strategy of the vault.
+    /// * [`VaultTokenError::VaultInvalidAssetsAmount`] - When `assets < 0`.
+    /// * [`VaultTokenError::VaultStrategyCapExceeded`] - When the allocation of
+    ///   the strategy would exceed its cap.
+    /// * [`VaultTokenError::VaultInsufficientLiquidity`] - When the vault
+    ///   doesn't hold `assets` idle assets.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["allocated", strategy: Address]`
+    /// * data - `[assets: i128]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts, typically
+    /// holding an allocator role, can allocate assets.
+    pub fn allocate(e: &Env, strategy: &Address, assets: i128) {
+        let cap = Self::strategy_cap(e, strategy);
+        if assets < 0 {
+            panic_with_error!(e, VaultTokenError::VaultInvalidAssetsAmount);
+        }
+        let allocation = Self::strategy_assets(e, strategy)
+            .checked_add(assets)
+            .unwrap_or_else(|| panic_with_error!(e, VaultTokenError::MathOverflow));
+        if allocation > cap {
+            panic_with_error!(e, VaultTokenError::VaultStrategyCapExceeded);
+        }
+        if assets > Self::idle_assets(e) {
+            panic_with_error!(e, VaultTokenError::VaultInsufficientLiquidity);
+        }
+
+        let vault = e.current_contract_address();
+        let token_client = token::Client::new(e, &Self::query_asset(e));
+        token_client.transfer(&vault, strategy, &assets);
+        VaultStrategyClient::new(e, strategy).deploy(&vault, &assets);
+
+        emit_allocated(e, strategy, assets);
+    }
+
+    /// Deallocates `assets` from `strategy` back to the vault, by calling
+    /// [`crate::vault::strategies::VaultStrategy::withdraw`].
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `strategy` - The address of the strategy.
+    /// * `assets` - The amount of underlying assets to deallocate.
+    ///
+    /// # Errors
+    ///
+    /// * [`VaultTokenError::VaultStrategyNotFound`] - When `strategy` is not a
+    ///   strategy of the vault.
+    /// * [`VaultTokenError::VaultInvalidAssetsAmount`] - When `assets < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["deallocated", strategy: Address]`
+    /// * data - `[assets: i128]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts, typically
+    /// holding an allocator role, can deallocate assets.
+    pub fn deallocate(e: &Env, strategy: &Address, assets: i128) {
+        // ensures the strategy exists
+        Self::strategy_cap(e, strategy);
+        if assets < 0 {
+            panic_with_error!(e, VaultTokenError::VaultInvalidAssetsAmount);
+        }
+
+        VaultStrategyClient::new(e, strategy).withdraw(&e.current_contract_address(), &assets);
+
+        emit_deallocated(e, strategy, assets);
+    }
+
+    /// Ensures the vault holds at least `assets` idle assets, by
+    /// deallocating the missing assets from the strategies, in the order of
+    /// the withdrawal queue.
+    ///
+    /// This function is called by the withdrawal flows of [`StrategyVault`]
+    /// before transferring assets out of the vault. It is a no-op when the
+    /// vault has no strategies.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `assets` - The amount of idle assets required.
+    ///
+    /// # Errors
+    ///
+    /// * [`VaultTokenError::VaultInsufficientLiquidity`] - When the strategies
+    ///   of the withdrawal queue can't cover the missing assets.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["deallocated", strategy: Address]`
+    /// * data - `[assets: i128]`
+    ///
+    /// # Security Warning
+    ///
+    /// This function has NO AUTHORIZATION CONTROLS. It doesn't change the
+    /// total assets of the vault, but should only be used as part of a
+    /// withdrawal flow.
+    pub fn ensure_idle_assets(e: &Env, assets: i128) {
+        let queue = Self::withdrawal_queue(e);
+        if queue.is_empty() {
+            return;
+        }
+        let idle = Self::idle_assets(e);
+        if assets <= idle {
+            return;
+        }
+
+        let mut missing = assets - idle;
+        for strategy in queue.iter() {
+            let available = Self::strategy_assets(e, &strategy);
+            let amount = missing.min(available);
+            if amount > 0 {
+                Self::deallocate(e, &strategy, amount);
+                missing -= amount;
+            }
+            if missing == 0 {
+                return;
+            }
+        }
+
+        panic_with_error!(e, VaultTokenError::VaultInsufficientLiquidity);
+    }
+}
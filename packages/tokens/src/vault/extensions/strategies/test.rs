@@ -0,0 +1,314 @@
+extern crate std;
+
+use soroban_sdk::{
+    contract, contractimpl, contracttype,
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env,
+};
+
+use crate::vault::{
+    fees::{FeeVault, VaultFeeConfig},
+    strategies::{StrategyVault, VaultStrategy, MAX_STRATEGIES},
+    Vault, VaultOverrides,
+};
+
+#[contract]
+struct MockVaultContract;
+
+#[contracttype]
+enum MockStrategyStorageKey {
+    Asset,
+}
+
+#[contract]
+struct MockStrategy;
+
+#[contractimpl]
+impl MockStrategy {
+    pub fn __constructor(e: &Env, asset: Address) {
+        e.storage().instance().set(&MockStrategyStorageKey::Asset, &asset);
+    }
+}
+
+fn asset_client(e: &Env) -> TokenClient<'_> {
+    let asset: Address = e.storage().instance().get(&MockStrategyStorageKey::Asset).unwrap();
+    TokenClient::new(e, &asset)
+}
+
+#[contractimpl]
+impl VaultStrategy for MockStrategy {
+    fn deploy(_e: &Env, vault: Address, _assets: i128) {
+        vault.require_auth();
+    }
+
+    fn withdraw(e: &Env, vault: Address, assets: i128) {
+        vault.require_auth();
+        asset_client(e).transfer(&e.current_contract_address(), &vault, &assets);
+    }
+
+    fn total_value(e: &Env, _vault: Address) -> i128 {
+        asset_client(e).balance(&e.current_contract_address())
+    }
+}
+
+/// Contract type combining the strategies, fees and limits extensions.
+struct StrategyFeeVault;
+
+impl VaultOverrides for StrategyFeeVault {
+    fn total_assets(e: &Env) -> i128 {
+        StrategyVault::total_assets(e)
+    }
+
+    fn fee_config(e: &Env) -> Option<VaultFeeConfig> {
+        FeeVault::fee_config(e)
+    }
+
+    fn accrued_fee_shares(e: &Env, total_assets: i128) -> i128 {
+        FeeVault::accrued_fee_shares(e, total_assets)
+    }
+
+    fn accrue_fees(e: &Env, total_assets: i128) -> i128 {
+        FeeVault::accrue_fees(e, total_assets)
+    }
+
+    fn ensure_idle_assets(e: &Env, assets: i128) {
+        StrategyVault::ensure_idle_assets(e, assets);
+    }
+
+    fn can_deposit(e: &Env, receiver: &Address) -> bool {
+        Vault::can_deposit(e, receiver)
+    }
+
+    fn max_deposit_with_total_assets(e: &Env, receiver: &Address, total_assets: i128) -> i128 {
+        Vault::deposit_limit_with_total_assets::<Self>(e, receiver, total_assets)
+    }
+
+    fn max_deposit(e: &Env, receiver: Address) -> i128 {
+        Vault::deposit_limit::<Self>(e, &receiver)
+    }
+}
+
+fn setup(e: &Env) -> (Address, Address, Address) {
+    let vault = e.register(MockVaultContract, ());
+    let asset = e.register_stellar_asset_contract_v2(Address::generate(e)).address();
+    let alice = Address::generate(e);
+    StellarAssetClient::new(e, &asset).mint(&alice, &10_000);
+    e.as_contract(&vault, || {
+        Vault::set_asset(e, asset.clone());
+        Vault::deposit(e, 1000, alice.clone(), alice.clone(), alice.clone());
+    });
+    (vault, asset, alice)
+}
+
+#[test]
+fn total_assets_includes_strategies() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, asset, _) = setup(&e);
+    let strategy = e.register(MockStrategy, (asset.clone(),));
+
+    e.as_contract(&vault, || {
+        Vault::add_strategy(&e, &strategy, 800);
+        assert_eq!(Vault::strategies(&e), vec![&e, strategy.clone()]);
+        assert_eq!(Vault::withdrawal_queue(&e), vec![&e, strategy.clone()]);
+        assert_eq!(Vault::strategy_cap(&e, &strategy), 800);
+
+        Vault::allocate(&e, &strategy, 600);
+        assert_eq!(Vault::idle_assets(&e), 400);
+        assert_eq!(Vault::strategy_assets(&e, &strategy), 600);
+        assert_eq!(StrategyVault::total_assets(&e), 1000);
+    });
+
+    // the strategy generates yield
+    StellarAssetClient::new(&e, &asset).mint(&strategy, &1000);
+
+    e.as_contract(&vault, || {
+        assert_eq!(Vault::deployed_assets(&e), 1600);
+        assert_eq!(StrategyVault::total_assets(&e), 2000);
+        assert_eq!(StrategyVault::convert_to_assets(&e, 500), 999);
+    });
+}
+
+#[test]
+fn plain_vault_ignores_strategies() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, asset, _) = setup(&e);
+    let strategy = e.register(MockStrategy, (asset.clone(),));
+
+    e.as_contract(&vault, || {
+        Vault::add_strategy(&e, &strategy, 800);
+        Vault::allocate(&e, &strategy, 600);
+        assert_eq!(Vault::total_assets(&e), 400);
+        assert_eq!(StrategyVault::total_assets(&e), 1000);
+    });
+}
+
+#[test]
+fn withdraw_draws_from_withdrawal_queue() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, asset, alice) = setup(&e);
+    let first = e.register(MockStrategy, (asset.clone(),));
+    let second = e.register(MockStrategy, (asset.clone(),));
+
+    e.as_contract(&vault, || {
+        Vault::add_strategy(&e, &first, 1000);
+        Vault::add_strategy(&e, &second, 1000);
+        Vault::allocate(&e, &first, 400);
+        Vault::allocate(&e, &second, 400);
+        Vault::set_withdrawal_queue(&e, &vec![&e, second.clone(), first.clone()]);
+    });
+
+    e.as_contract(&vault, || {
+        // 200 idle, then 400 from `second`, then 100 from `first`
+        StrategyVault::withdraw(&e, 700, alice.clone(), alice.clone(), alice.clone());
+        assert_eq!(Vault::idle_assets(&e), 0);
+        assert_eq!(Vault::strategy_assets(&e, &second), 0);
+        assert_eq!(Vault::strategy_assets(&e, &first), 300);
+        assert_eq!(StrategyVault::total_assets(&e), 300);
+    });
+
+    assert_eq!(TokenClient::new(&e, &asset).balance(&alice), 9700);
+}
+
+#[test]
+fn remove_strategy_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, asset, _) = setup(&e);
+    let first = e.register(MockStrategy, (asset.clone(),));
+    let second = e.register(MockStrategy, (asset.clone(),));
+
+    e.as_contract(&vault, || {
+        Vault::add_strategy(&e, &first, 1000);
+        Vault::add_strategy(&e, &second, 1000);
+        Vault::allocate(&e, &first, 500);
+        Vault::deallocate(&e, &first, 500);
+        assert_eq!(Vault::idle_assets(&e), 1000);
+
+        Vault::remove_strategy(&e, &first);
+        assert_eq!(Vault::strategies(&e), vec![&e, second.clone()]);
+        assert_eq!(Vault::withdrawal_queue(&e), vec![&e, second.clone()]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #418)")]
+fn remove_non_empty_strategy_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, asset, _) = setup(&e);
+    let strategy = e.register(MockStrategy, (asset.clone(),));
+
+    e.as_contract(&vault, || {
+        Vault::add_strategy(&e, &strategy, 1000);
+        Vault::allocate(&e, &strategy, 1);
+        Vault::remove_strategy(&e, &strategy);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #417)")]
+fn allocate_above_cap_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, asset, _) = setup(&e);
+    let strategy = e.register(MockStrategy, (asset.clone(),));
+
+    e.as_contract(&vault, || {
+        Vault::add_strategy(&e, &strategy, 500);
+        Vault::allocate(&e, &strategy, 400);
+        Vault::allocate(&e, &strategy, 101);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #419)")]
+fn allocate_above_idle_assets_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, asset, _) = setup(&e);
+    let strategy = e.register(MockStrategy, (asset.clone(),));
+
+    e.as_contract(&vault, || {
+        Vault::add_strategy(&e, &strategy, 5000);
+        Vault::allocate(&e, &strategy, 1001);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #416)")]
+fn add_existing_strategy_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, asset, _) = setup(&e);
+    let strategy = e.register(MockStrategy, (asset.clone(),));
+
+    e.as_contract(&vault, || {
+        Vault::add_strategy(&e, &strategy, 500);
+        Vault::add_strategy(&e, &strategy, 500);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #420)")]
+fn set_invalid_withdrawal_queue_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, asset, _) = setup(&e);
+    let first = e.register(MockStrategy, (asset.clone(),));
+    let second = e.register(MockStrategy, (asset.clone(),));
+
+    e.as_contract(&vault, || {
+        Vault::add_strategy(&e, &first, 500);
+        Vault::add_strategy(&e, &second, 500);
+        Vault::set_withdrawal_queue(&e, &vec![&e, first.clone(), first.clone()]);
+    });
+}
+
+#[test]
+fn max_strategies_fit_in_budget() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (vault, asset, alice) = setup(&e);
+    let recipient = Address::generate(&e);
+    let strategies: std::vec::Vec<Address> =
+        (0..MAX_STRATEGIES).map(|_| e.register(MockStrategy, (asset.clone(),))).collect();
+    e.cost_estimate().budget().reset_unlimited();
+
+    e.as_contract(&vault, || {
+        for strategy in strategies.iter() {
+            Vault::add_strategy(&e, strategy, 1000);
+            Vault::allocate(&e, strategy, 10);
+        }
+        // every limit and fee reading the total assets
+        Vault::set_deposit_cap(&e, Some(1_000_000));
+        Vault::set_default_account_deposit_cap(&e, Some(1_000_000));
+        Vault::set_fee_config::<StrategyFeeVault>(
+            &e,
+            &VaultFeeConfig {
+                recipient: recipient.clone(),
+                entry_fee: 100,
+                exit_fee: 100,
+                management_fee: 200,
+                performance_fee: 2000,
+            },
+        );
+    });
+
+    // the total assets, querying every strategy, are computed once per call,
+    // well below the limit of 100M instructions per transaction
+    e.cost_estimate().budget().reset_unlimited();
+    e.as_contract(&vault, || {
+        StrategyFeeVault::deposit(&e, 1000, alice.clone(), alice.clone(), alice.clone());
+    });
+    assert!(e.cost_estimate().budget().cpu_instruction_cost() < 10_000_000);
+
+    e.cost_estimate().budget().reset_unlimited();
+    e.as_contract(&vault, || {
+        StrategyFeeVault::redeem(&e, 1000, alice.clone(), alice.clone(), alice.clone());
+    });
+    assert!(e.cost_estimate().budget().cpu_instruction_cost() < 10_000_000);
+}
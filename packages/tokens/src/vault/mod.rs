@@ -1,8 +1,12 @@
+mod extensions;
+mod overrides;
 pub mod storage;
 
 #[cfg(test)]
 mod test;
 
+pub use extensions::{async_vault, fees, limits, strategies};
+pub use overrides::VaultOverrides;
 use soroban_sdk::{contracterror, contractevent, contracttrait, Address, Env};
 pub use storage::Vault;
 
@@ -34,13 +38,32 @@ use crate::fungible::FungibleToken;
 /// add appropriate access controls, typically by combining with Ownable or
 /// Access Control patterns.
 ///
+/// # Extensions
+///
+/// Extensions hooking into the flows of the vault provide their own contract
+/// type, implementing [`VaultOverrides`], to be used as `ContractType` instead
+/// of [`Vault`]. Plain vaults therefore don't pay for the extensions they
+/// don't use.
+///
+/// - *Async Vault* adds ERC-7540 style asynchronous deposit and redeem
+///   requests, fulfilled by the vault operator. It requires the
+///   [`async_vault::AsyncVault`] contract type.
+/// - *Fees* adds entry, exit, management and performance fees, reflected in the
+///   `preview_*` functions. It requires the [`fees::FeeVault`] contract type.
+/// - *Strategies* deploys the underlying assets into strategy contracts, with
+///   allocation caps and a withdrawal queue. It requires the
+///   [`strategies::StrategyVault`] contract type.
+/// - *Limits* adds a deposit cap, per-account caps and an optional deposit
+///   verifier, reflected in `max_deposit` and `max_mint`. It requires the
+///   [`limits::LimitedVault`] contract type.
+///
 /// # Compatibility
 ///
 /// This implementation follows the ERC-4626 standard for tokenized vaults,
 /// providing familiar interfaces for Ethereum developers while leveraging
 /// Stellar's unique capabilities.
 #[contracttrait]
-pub trait FungibleVault: FungibleToken<ContractType = Vault> {
+pub trait FungibleVault: FungibleToken<ContractType: VaultOverrides> {
     /// Returns the address of the underlying asset that the vault manages.
     ///
     /// # Arguments
@@ -55,10 +78,12 @@ pub trait FungibleVault: FungibleToken<ContractType = Vault> {
         Self::ContractType::query_asset(e)
     }
 
-    /// Returns the total amount of underlying assets held by the vault.
+    /// Returns the total amount of underlying assets managed by the vault.
     ///
-    /// This represents the vault's balance of the underlying asset, which
-    /// determines the conversion rate between shares and assets.
+    /// This represents the vault's balance of the underlying asset, plus the
+    /// assets deployed into strategies when using the
+    /// [`strategies::StrategyVault`] contract type, which determines the
+    /// conversion rate between shares and assets.
     ///
     /// # Arguments
     ///
@@ -109,7 +134,8 @@ pub trait FungibleVault: FungibleToken<ContractType = Vault> {
     }
 
     /// Returns the maximum amount of underlying assets that can be deposited
-    /// for the given receiver address (currently `i128::MAX`).
+    /// for the given receiver address, under the deposit limits of the
+    /// [`limits::LimitedVault`] contract type (`i128::MAX` if there are none).
     ///
     /// # Arguments
     ///
@@ -174,7 +200,8 @@ pub trait FungibleVault: FungibleToken<ContractType = Vault> {
     }
 
     /// Returns the maximum amount of vault shares that can be minted
-    /// for the given receiver address (currently `i128::MAX`).
+    /// for the given receiver address, under the deposit limits of the
+    /// [`limits::LimitedVault`] contract type (`i128::MAX` if there are none).
     ///
     /// # Arguments
     ///
@@ -408,6 +435,30 @@ pub enum VaultTokenError {
     VaultMaxDecimalsOffsetExceeded = 409,
     /// Indicates overflow due to mathematical operations
     MathOverflow = 410,
+    /// Attempted to fulfill more than the pending request.
+    VaultExceededPendingRequest = 411,
+    /// Attempted to claim more than the claimable request.
+    VaultExceededClaimableRequest = 412,
+    /// Indicates an address that is neither the controller of a request nor
+    /// one of its operators.
+    VaultInvalidOperator = 413,
+    /// Indicates a fee higher than 100%.
+    VaultInvalidFee = 414,
+    /// Indicates an address that is not a strategy of the vault.
+    VaultStrategyNotFound = 415,
+    /// Indicates a strategy that was already added to the vault.
+    VaultStrategyAlreadyExists = 416,
+    /// Attempted to allocate more assets to a strategy than its cap.
+    VaultStrategyCapExceeded = 417,
+    /// Attempted to remove a strategy that still holds assets of the vault.
+    VaultStrategyNotEmpty = 418,
+    /// Indicates that the vault can't provide the requested assets.
+    VaultInsufficientLiquidity = 419,
+    /// Indicates a withdrawal queue that isn't a permutation of the
+    /// strategies.
+    VaultInvalidWithdrawalQueue = 420,
+    /// Maximum number of strategies exceeded.
+    VaultMaxStrategiesExceeded = 421,
 }
 
 // ################## CONSTANTS ##################
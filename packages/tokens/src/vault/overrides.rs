@@ -0,0 +1,293 @@
+use soroban_sdk::{panic_with_error, Address, Env};
+use stellar_contract_utils::math::Rounding;
+
+use crate::{
+    fungible::ContractOverrides,
+    vault::{
+        emit_deposit, emit_withdraw,
+        fees::{fee_on_raw, fee_on_total, pay_fee, VaultFeeConfig},
+        Vault, VaultTokenError,
+    },
+};
+
+/// Based on the extension, some default behavior of
+/// [`crate::vault::FungibleVault`] might have to be overridden. This is a
+/// helper trait, similar to [`crate::fungible::ContractOverrides`], that
+/// allows the extensions of the vault to hook into its flows.
+///
+/// The flows (conversions, previews, limits, deposits and withdrawals) are
+/// provided as default implementations, built on top of a few hooks, such as
+/// [`VaultOverrides::total_assets`]. An extension provides its own contract
+/// type, which overrides the hooks it needs, so that plain vaults (using the
+/// [`Vault`] contract type) don't pay for the extensions they don't use.
+///
+/// A vault combining several extensions can define its own contract type,
+/// and implement the hooks by delegating to the contract types of the
+/// extensions.
+pub trait VaultOverrides {
+    // ################## HOOKS ##################
+
+    fn query_asset(e: &Env) -> Address {
+        Vault::query_asset(e)
+    }
+
+    fn total_assets(e: &Env) -> i128 {
+        Vault::total_assets(e)
+    }
+
+    fn fee_config(_e: &Env) -> Option<VaultFeeConfig> {
+        None
+    }
+
+    fn accrued_fee_shares(_e: &Env, _total_assets: i128) -> i128 {
+        0
+    }
+
+    fn accrue_fees(_e: &Env, _total_assets: i128) -> i128 {
+        0
+    }
+
+    /// Makes sure that the vault holds at least `assets` idle assets, before
+    /// they are transferred out of the vault.
+    fn ensure_idle_assets(_e: &Env, _assets: i128) {}
+
+    /// Returns whether `receiver` is allowed to receive vault shares from
+    /// deposits.
+    fn can_deposit(_e: &Env, _receiver: &Address) -> bool {
+        true
+    }
+
+    /// Same as [`VaultOverrides::max_deposit`], with the total assets of the
+    /// vault given by the caller.
+    fn max_deposit_with_total_assets(_e: &Env, _receiver: &Address, _total_assets: i128) -> i128 {
+        i128::MAX
+    }
+
+    // ################## FLOWS ##################
+
+    fn convert_to_shares(e: &Env, assets: i128) -> i128 {
+        Self::convert_to_shares_with_total_assets(e, assets, Self::total_assets(e), Rounding::Floor)
+    }
+
+    fn convert_to_assets(e: &Env, shares: i128) -> i128 {
+        Self::convert_to_assets_with_total_assets(e, shares, Self::total_assets(e), Rounding::Floor)
+    }
+
+    fn max_deposit(_e: &Env, _receiver: Address) -> i128 {
+        i128::MAX
+    }
+
+    fn preview_deposit(e: &Env, assets: i128) -> i128 {
+        Self::preview_deposit_with_total_assets(e, assets, Self::total_assets(e))
+    }
+
+    fn max_mint(e: &Env, receiver: Address) -> i128 {
+        let max_assets = Self::max_deposit(e, receiver);
+        if max_assets == i128::MAX {
+            return i128::MAX;
+        }
+        Self::preview_deposit(e, max_assets)
+    }
+
+    fn preview_mint(e: &Env, shares: i128) -> i128 {
+        Self::preview_mint_with_total_assets(e, shares, Self::total_assets(e))
+    }
+
+    fn max_withdraw(e: &Env, owner: Address) -> i128 {
+        Self::preview_redeem(e, Vault::balance(e, &owner))
+    }
+
+    fn preview_withdraw(e: &Env, assets: i128) -> i128 {
+        Self::preview_withdraw_with_total_assets(e, assets, Self::total_assets(e))
+    }
+
+    fn max_redeem(e: &Env, owner: Address) -> i128 {
+        Vault::balance(e, &owner)
+    }
+
+    fn preview_redeem(e: &Env, shares: i128) -> i128 {
+        Self::preview_redeem_with_total_assets(e, shares, Self::total_assets(e))
+    }
+
+    fn deposit(e: &Env, assets: i128, receiver: Address, from: Address, operator: Address) -> i128 {
+        operator.require_auth();
+        // Accruing fees mints shares, but doesn't change the total assets.
+        let total_assets = Self::total_assets(e);
+        Self::accrue_fees(e, total_assets);
+
+        let max_assets = Self::max_deposit_with_total_assets(e, &receiver, total_assets);
+        if assets > max_assets {
+            panic_with_error!(e, VaultTokenError::VaultExceededMaxDeposit);
+        }
+        let shares: i128 = Self::preview_deposit_with_total_assets(e, assets, total_assets);
+        Self::deposit_internal(e, &receiver, assets, shares, &from, &operator);
+        emit_deposit(e, &operator, &from, &receiver, assets, shares);
+
+        shares
+    }
+
+    fn mint(e: &Env, shares: i128, receiver: Address, from: Address, operator: Address) -> i128 {
+        operator.require_auth();
+        let total_assets = Self::total_assets(e);
+        Self::accrue_fees(e, total_assets);
+
+        let max_shares = Self::max_mint_with_total_assets(e, &receiver, total_assets);
+        if shares > max_shares {
+            panic_with_error!(e, VaultTokenError::VaultExceededMaxMint);
+        }
+        let assets: i128 = Self::preview_mint_with_total_assets(e, shares, total_assets);
+        Self::deposit_internal(e, &receiver, assets, shares, &from, &operator);
+        emit_deposit(e, &operator, &from, &receiver, assets, shares);
+
+        assets
+    }
+
+    fn withdraw(
+        e: &Env,
+        assets: i128,
+        receiver: Address,
+        owner: Address,
+        operator: Address,
+    ) -> i128 {
+        operator.require_auth();
+        let total_assets = Self::total_assets(e);
+        Self::accrue_fees(e, total_assets);
+
+        // Same as `max_withdraw`
+        let max_assets =
+            Self::preview_redeem_with_total_assets(e, Vault::balance(e, &owner), total_assets);
+        if assets > max_assets {
+            panic_with_error!(e, VaultTokenError::VaultExceededMaxWithdraw);
+        }
+        let shares: i128 = Self::preview_withdraw_with_total_assets(e, assets, total_assets);
+        Self::withdraw_internal(e, &receiver, &owner, assets, shares, &operator);
+        emit_withdraw(e, &operator, &receiver, &owner, assets, shares);
+
+        shares
+    }
+
+    fn redeem(e: &Env, shares: i128, receiver: Address, owner: Address, operator: Address) -> i128 {
+        operator.require_auth();
+        let total_assets = Self::total_assets(e);
+        Self::accrue_fees(e, total_assets);
+
+        let max_shares = Self::max_redeem(e, owner.clone());
+        if shares > max_shares {
+            panic_with_error!(e, VaultTokenError::VaultExceededMaxRedeem);
+        }
+        let assets = Self::preview_redeem_with_total_assets(e, shares, total_assets);
+        Self::withdraw_internal(e, &receiver, &owner, assets, shares, &operator);
+        emit_withdraw(e, &operator, &receiver, &owner, assets, shares);
+
+        assets
+    }
+
+    // ################## LOW-LEVEL HELPERS ##################
+
+    fn convert_to_shares_with_total_assets(
+        e: &Env,
+        assets: i128,
+        total_assets: i128,
+        rounding: Rounding,
+    ) -> i128 {
+        let total_supply = Self::total_supply_with_fees(e, total_assets);
+        Vault::convert_to_shares_with_totals(e, assets, total_assets, total_supply, rounding)
+    }
+
+    fn convert_to_assets_with_total_assets(
+        e: &Env,
+        shares: i128,
+        total_assets: i128,
+        rounding: Rounding,
+    ) -> i128 {
+        let total_supply = Self::total_supply_with_fees(e, total_assets);
+        Vault::convert_to_assets_with_totals(e, shares, total_assets, total_supply, rounding)
+    }
+
+    /// Returns the total supply of shares, including the fee shares accrued
+    /// since the last accrual, as if they were already minted.
+    fn total_supply_with_fees(e: &Env, total_assets: i128) -> i128 {
+        Vault::total_supply(e)
+            .checked_add(Self::accrued_fee_shares(e, total_assets))
+            .unwrap_or_else(|| panic_with_error!(e, VaultTokenError::MathOverflow))
+    }
+
+    fn preview_deposit_with_total_assets(e: &Env, assets: i128, total_assets: i128) -> i128 {
+        let fee = Self::fee_config(e).map_or(0, |config| fee_on_total(e, assets, config.entry_fee));
+        Self::convert_to_shares_with_total_assets(e, assets - fee, total_assets, Rounding::Floor)
+    }
+
+    fn max_mint_with_total_assets(e: &Env, receiver: &Address, total_assets: i128) -> i128 {
+        let max_assets = Self::max_deposit_with_total_assets(e, receiver, total_assets);
+        if max_assets == i128::MAX {
+            return i128::MAX;
+        }
+        Self::preview_deposit_with_total_assets(e, max_assets, total_assets)
+    }
+
+    fn preview_mint_with_total_assets(e: &Env, shares: i128, total_assets: i128) -> i128 {
+        let assets =
+            Self::convert_to_assets_with_total_assets(e, shares, total_assets, Rounding::Ceil);
+        let fee = Self::fee_config(e).map_or(0, |config| fee_on_raw(e, assets, config.entry_fee));
+        assets
+            .checked_add(fee)
+            .unwrap_or_else(|| panic_with_error!(e, VaultTokenError::MathOverflow))
+    }
+
+    fn preview_withdraw_with_total_assets(e: &Env, assets: i128, total_assets: i128) -> i128 {
+        let fee = Self::fee_config(e).map_or(0, |config| fee_on_raw(e, assets, config.exit_fee));
+        let assets = assets
+            .checked_add(fee)
+            .unwrap_or_else(|| panic_with_error!(e, VaultTokenError::MathOverflow));
+        Self::convert_to_shares_with_total_assets(e, assets, total_assets, Rounding::Ceil)
+    }
+
+    fn preview_redeem_with_total_assets(e: &Env, shares: i128, total_assets: i128) -> i128 {
+        let assets =
+            Self::convert_to_assets_with_total_assets(e, shares, total_assets, Rounding::Floor);
+        assets - Self::fee_config(e).map_or(0, |config| fee_on_total(e, assets, config.exit_fee))
+    }
+
+    fn deposit_internal(
+        e: &Env,
+        receiver: &Address,
+        assets: i128,
+        shares: i128,
+        from: &Address,
+        operator: &Address,
+    ) {
+        Vault::deposit_internal(e, receiver, assets, shares, from, operator);
+
+        if let Some(config) = Self::fee_config(e) {
+            let fee = fee_on_total(e, assets, config.entry_fee);
+            pay_fee(e, &Self::query_asset(e), &config.recipient, fee);
+        }
+    }
+
+    fn withdraw_internal(
+        e: &Env,
+        receiver: &Address,
+        owner: &Address,
+        assets: i128,
+        shares: i128,
+        operator: &Address,
+    ) {
+        let fee_config = Self::fee_config(e);
+        let fee = fee_config.as_ref().map_or(0, |config| fee_on_raw(e, assets, config.exit_fee));
+        Self::ensure_idle_assets(
+            e,
+            assets
+                .checked_add(fee)
+                .unwrap_or_else(|| panic_with_error!(e, VaultTokenError::MathOverflow)),
+        );
+
+        Vault::withdraw_internal(e, receiver, owner, assets, shares, operator);
+
+        if let Some(config) = fee_config {
+            pay_fee(e, &Self::query_asset(e), &config.recipient, fee);
+        }
+    }
+}
+
+// No override required for the `Vault` contract type.
+impl VaultOverrides for Vault {}
@@ -3,7 +3,7 @@ use stellar_contract_utils::math::{mul_div_i128, Rounding};
 
 use crate::{
     fungible::{Base, ContractOverrides},
-    vault::{emit_deposit, emit_withdraw, VaultTokenError, MAX_DECIMALS_OFFSET},
+    vault::{VaultOverrides, VaultTokenError, MAX_DECIMALS_OFFSET},
 };
 
 pub struct Vault;
@@ -121,7 +121,7 @@ impl Vault {
             .unwrap_or_else(|| panic_with_error!(e, VaultTokenError::VaultAssetAddressNotSet))
     }
 
-    /// Returns the total amount of underlying assets held by the vault.
+    /// Returns the total amount of underlying assets managed by the vault.
     ///
     /// This represents the vault's balance of the underlying asset, which
     /// determines the conversion rate between shares and assets.
@@ -336,17 +336,7 @@ impl Vault {
         from: Address,
         operator: Address,
     ) -> i128 {
-        operator.require_auth();
-
-        let max_assets = Self::max_deposit(e, receiver.clone());
-        if assets > max_assets {
-            panic_with_error!(e, VaultTokenError::VaultExceededMaxDeposit);
-        }
-        let shares: i128 = Self::preview_deposit(e, assets);
-        Self::deposit_internal(e, &receiver, assets, shares, &from, &operator);
-        emit_deposit(e, &operator, &from, &receiver, assets, shares);
-
-        shares
+        <Self as VaultOverrides>::deposit(e, assets, receiver, from, operator)
     }
 
     /// Mints a specific amount of vault shares to the receiver by depositing
@@ -383,17 +373,7 @@ impl Vault {
         from: Address,
         operator: Address,
     ) -> i128 {
-        operator.require_auth();
-
-        let max_shares = Self::max_mint(e, receiver.clone());
-        if shares > max_shares {
-            panic_with_error!(e, VaultTokenError::VaultExceededMaxMint);
-        }
-        let assets: i128 = Self::preview_mint(e, shares);
-        Self::deposit_internal(e, &receiver, assets, shares, &from, &operator);
-        emit_deposit(e, &operator, &from, &receiver, assets, shares);
-
-        assets
+        <Self as VaultOverrides>::mint(e, shares, receiver, from, operator)
     }
 
     /// Withdraws a specific amount of underlying assets from the vault
@@ -429,17 +409,7 @@ impl Vault {
         owner: Address,
         operator: Address,
     ) -> i128 {
-        operator.require_auth();
-
-        let max_assets = Self::max_withdraw(e, owner.clone());
-        if assets > max_assets {
-            panic_with_error!(e, VaultTokenError::VaultExceededMaxWithdraw);
-        }
-        let shares: i128 = Self::preview_withdraw(e, assets);
-        Self::withdraw_internal(e, &receiver, &owner, assets, shares, &operator);
-        emit_withdraw(e, &operator, &receiver, &owner, assets, shares);
-
-        shares
+        <Self as VaultOverrides>::withdraw(e, assets, receiver, owner, operator)
     }
 
     /// Redeems a specific amount of vault shares for underlying assets,
@@ -475,17 +445,7 @@ impl Vault {
         owner: Address,
         operator: Address,
     ) -> i128 {
-        operator.require_auth();
-
-        let max_shares = Self::max_redeem(e, owner.clone());
-        if shares > max_shares {
-            panic_with_error!(e, VaultTokenError::VaultExceededMaxRedeem);
-        }
-        let assets = Self::preview_redeem(e, shares);
-        Self::withdraw_internal(e, &receiver, &owner, assets, shares, &operator);
-        emit_withdraw(e, &operator, &receiver, &owner, assets, shares);
-
-        assets
+        <Self as VaultOverrides>::redeem(e, shares, receiver, owner, operator)
     }
 
     // ################## OVERRIDDEN FUNCTIONS ##################
@@ -619,6 +579,39 @@ impl Vault {
     /// * [`VaultTokenError::MathOverflow`] - When mathematical operations
     ///   result in overflow.
     pub fn convert_to_shares_with_rounding(e: &Env, assets: i128, rounding: Rounding) -> i128 {
+        Self::convert_to_shares_with_totals(
+            e,
+            assets,
+            Self::total_assets(e),
+            Self::total_supply(e),
+            rounding,
+        )
+    }
+
+    /// Same as [`Self::convert_to_shares_with_rounding()`], with the total
+    /// assets and the total supply of the vault given by the caller, for
+    /// extensions adjusting them (see [`crate::vault::VaultOverrides`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `assets` - The amount of underlying assets to convert.
+    /// * `total_assets` - The total assets of the vault.
+    /// * `total_supply` - The total supply of vault shares.
+    /// * `rounding` - The rounding direction to use for the conversion.
+    ///
+    /// # Errors
+    ///
+    /// * [`VaultTokenError::VaultInvalidAssetsAmount`] - When `assets < 0`.
+    /// * [`VaultTokenError::MathOverflow`] - When mathematical operations
+    ///   result in overflow.
+    pub fn convert_to_shares_with_totals(
+        e: &Env,
+        assets: i128,
+        total_assets: i128,
+        total_supply: i128,
+        rounding: Rounding,
+    ) -> i128 {
         if assets < 0 {
             panic_with_error!(e, VaultTokenError::VaultInvalidAssetsAmount);
         }
@@ -635,12 +628,12 @@ impl Vault {
             .unwrap_or_else(|| panic_with_error!(e, VaultTokenError::MathOverflow));
 
         // Effective total supply = totalSupply + virtual offset
-        let y = Self::total_supply(e)
+        let y = total_supply
             .checked_add(pow)
             .unwrap_or_else(|| panic_with_error!(e, VaultTokenError::MathOverflow));
 
         // Effective total assets = totalAssets + 1 (prevents division by zero)
-        let denominator = Self::total_assets(e)
+        let denominator = total_assets
             .checked_add(1_i128)
             .unwrap_or_else(|| panic_with_error!(e, VaultTokenError::MathOverflow));
 
@@ -667,6 +660,39 @@ impl Vault {
     /// * [`VaultTokenError::MathOverflow`] - When mathematical operations
     ///   result in overflow.
     pub fn convert_to_assets_with_rounding(e: &Env, shares: i128, rounding: Rounding) -> i128 {
+        Self::convert_to_assets_with_totals(
+            e,
+            shares,
+            Self::total_assets(e),
+            Self::total_supply(e),
+            rounding,
+        )
+    }
+
+    /// Same as [`Self::convert_to_assets_with_rounding()`], with the total
+    /// assets and the total supply of the vault given by the caller, for
+    /// extensions adjusting them (see [`crate::vault::VaultOverrides`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `shares` - The amount of vault shares to convert.
+    /// * `total_assets` - The total assets of the vault.
+    /// * `total_supply` - The total supply of vault shares.
+    /// * `rounding` - The rounding direction to use for the conversion.
+    ///
+    /// # Errors
+    ///
+    /// * [`VaultTokenError::VaultInvalidSharesAmount`] - When `shares < 0`.
+    /// * [`VaultTokenError::MathOverflow`] - When mathematical operations
+    ///   result in overflow.
+    pub fn convert_to_assets_with_totals(
+        e: &Env,
+        shares: i128,
+        total_assets: i128,
+        total_supply: i128,
+        rounding: Rounding,
+    ) -> i128 {
         if shares < 0 {
             panic_with_error!(e, VaultTokenError::VaultInvalidSharesAmount);
         }
@@ -678,7 +704,7 @@ impl Vault {
         let x = shares;
 
         // Effective total assets = totalAssets + 1 (prevents division by zero)
-        let y = Self::total_assets(e)
+        let y = total_assets
             .checked_add(1_i128)
             .unwrap_or_else(|| panic_with_error!(e, VaultTokenError::MathOverflow));
 
@@ -688,7 +714,7 @@ impl Vault {
             .unwrap_or_else(|| panic_with_error!(e, VaultTokenError::MathOverflow));
 
         // Effective total supply = totalSupply + virtual offset
-        let denominator = Self::total_supply(e)
+        let denominator = total_supply
             .checked_add(pow)
             .unwrap_or_else(|| panic_with_error!(e, VaultTokenError::MathOverflow));
 
@@ -0,0 +1,294 @@
+//! # Vesting Module
+//!
+//! Locks SEP-41 tokens on behalf of beneficiaries and releases them over time
+//! according to a vesting schedule. It is typically used for team, advisor
+//! and investor allocations of a token launch.
+//!
+//! A contract using this module can manage any number of grants, identified
+//! by an incremental `grant_id`. Each grant is defined by a
+//! [`VestingSchedule`]:
+//!
+//! - Nothing vests before `start + cliff_duration`.
+//! - Tokens vest linearly from `start` until `start + duration`, so the amount
+//!   accrued during the cliff period unlocks at once when the cliff ends.
+//! - The full `total_amount` is vested at `start + duration`.
+//!
+//! Setting `cliff_duration` to `0` results in a purely linear schedule, while
+//! setting `cliff_duration` equal to `duration` results in a single unlock
+//! at the end of the period.
+//!
+//! Vested tokens are transferred to the beneficiary with [`release`], which
+//! requires no authorization since the funds can only go to the beneficiary.
+//!
+//! Grants created as `revocable` can be revoked with [`revoke`]: vesting stops
+//! at the time of revocation, the unvested tokens are returned to a chosen
+//! recipient (e.g. an admin or treasury), and the tokens vested so far remain
+//! releasable by the beneficiary.
+//!
+//! The module doesn't make any assumption on the authorization model of the
+//! contract. [`create_grant`] and [`revoke`] are meant to be called from
+//! functions restricted with the help of the `access` package.
+pub mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contracterror, contractevent, contracttrait, Address, Env};
+pub use storage::{
+    create_grant, get_grant, grant_count, releasable, release, released, revoke, vested_amount,
+    VestingGrant, VestingSchedule, VestingStorageKey,
+};
+
+/// Vesting Trait
+///
+/// The `Vesting` trait exposes the functionalities of the vesting module as
+/// contract endpoints. Query functions and [`Vesting::release`] have default
+/// implementations, while [`Vesting::create_grant`] and [`Vesting::revoke`]
+/// must be implemented, because they move tokens out of the control of the
+/// beneficiaries and the authorization logic is left to the implementor.
+///
+/// ```ignore
+/// #[contractimpl(contracttrait)]
+/// impl Vesting for ExampleContract {
+///     #[only_owner]
+///     fn create_grant(e: &Env, funder: Address, schedule: VestingSchedule) -> u32 {
+///         vesting::create_grant(e, &funder, &schedule)
+///     }
+///
+///     #[only_owner]
+///     fn revoke(e: &Env, grant_id: u32, recipient: Address) -> i128 {
+///         vesting::revoke(e, grant_id, &recipient)
+///     }
+/// }
+/// ```
+#[contracttrait]
+pub trait Vesting {
+    /// Returns the grant identified by `grant_id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `grant_id` - The identifier of the grant.
+    ///
+    /// # Errors
+    ///
+    /// * [`VestingError::GrantNotFound`] - When the grant does not exist.
+    fn get_grant(e: &Env, grant_id: u32) -> VestingGrant {
+        get_grant(e, grant_id)
+    }
+
+    /// Returns the number of grants created so far.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn grant_count(e: &Env) -> u32 {
+        grant_count(e)
+    }
+
+    /// Returns the amount of tokens already released for `grant_id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `grant_id` - The identifier of the grant.
+    ///
+    /// # Errors
+    ///
+    /// * [`VestingError::GrantNotFound`] - When the grant does not exist.
+    fn released(e: &Env, grant_id: u32) -> i128 {
+        released(e, grant_id)
+    }
+
+    /// Returns the amount of tokens that are vested but not yet released for
+    /// `grant_id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `grant_id` - The identifier of the grant.
+    ///
+    /// # Errors
+    ///
+    /// * [`VestingError::GrantNotFound`] - When the grant does not exist.
+    fn releasable(e: &Env, grant_id: u32) -> i128 {
+        releasable(e, grant_id)
+    }
+
+    /// Returns the amount of tokens vested for `grant_id` at `timestamp`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `grant_id` - The identifier of the grant.
+    /// * `timestamp` - The ledger timestamp to compute the vested amount at.
+    ///
+    /// # Errors
+    ///
+    /// * [`VestingError::GrantNotFound`] - When the grant does not exist.
+    fn vested_amount(e: &Env, grant_id: u32, timestamp: u64) -> i128 {
+        vested_amount(e, grant_id, timestamp)
+    }
+
+    /// Transfers the releasable tokens of `grant_id` to its beneficiary and
+    /// returns the amount released.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `grant_id` - The identifier of the grant.
+    ///
+    /// # Errors
+    ///
+    /// * [`VestingError::GrantNotFound`] - When the grant does not exist.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["tokens_released", grant_id: u32, beneficiary: Address]`
+    /// * data - `[amount: i128]`
+    fn release(e: &Env, grant_id: u32) -> i128 {
+        release(e, grant_id)
+    }
+
+    /// Creates a new grant, transferring `schedule.total_amount` of
+    /// `schedule.token` from `funder` to the contract, and returns its id.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `funder` - The address providing the tokens.
+    /// * `schedule` - The vesting schedule of the grant.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`create_grant`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["grant_created", grant_id: u32, beneficiary: Address]`
+    /// * data - `[token: Address, total_amount: i128]`
+    fn create_grant(e: &Env, funder: Address, schedule: VestingSchedule) -> u32;
+
+    /// Revokes `grant_id`, transferring the unvested tokens to `recipient`,
+    /// and returns the amount transferred.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `grant_id` - The identifier of the grant.
+    /// * `recipient` - The address receiving the unvested tokens.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`revoke`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["grant_revoked", grant_id: u32, recipient: Address]`
+    /// * data - `[unvested_amount: i128]`
+    fn revoke(e: &Env, grant_id: u32, recipient: Address) -> i128;
+}
+
+// ################## ERRORS ##################
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VestingError {
+    /// Indicates a non-existent grant.
+    GrantNotFound = 500,
+    /// Indicates the total amount of a grant is not strictly positive.
+    InvalidVestingAmount = 501,
+    /// Indicates a schedule whose cliff exceeds its duration, or whose end
+    /// overflows.
+    InvalidVestingSchedule = 502,
+    /// Indicates an attempt to revoke a grant that is not revocable.
+    GrantNotRevocable = 503,
+    /// Indicates an attempt to revoke a grant that was already revoked.
+    GrantAlreadyRevoked = 504,
+    /// Indicates overflow due to mathematical operations.
+    MathOverflow = 505,
+}
+
+// ################## CONSTANTS ##################
+
+const DAY_IN_LEDGERS: u32 = 17280;
+pub const VESTING_EXTEND_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const VESTING_TTL_THRESHOLD: u32 = VESTING_EXTEND_AMOUNT - DAY_IN_LEDGERS;
+
+// ################## EVENTS ##################
+
+/// Event emitted when a grant is created.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GrantCreated {
+    #[topic]
+    pub grant_id: u32,
+    #[topic]
+    pub beneficiary: Address,
+    pub token: Address,
+    pub total_amount: i128,
+}
+
+/// Emits an event indicating a grant was created.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `grant_id` - The identifier of the grant.
+/// * `schedule` - The vesting schedule of the grant.
+pub fn emit_grant_created(e: &Env, grant_id: u32, schedule: &VestingSchedule) {
+    GrantCreated {
+        grant_id,
+        beneficiary: schedule.beneficiary.clone(),
+        token: schedule.token.clone(),
+        total_amount: schedule.total_amount,
+    }
+    .publish(e);
+}
+
+/// Event emitted when vested tokens are released to the beneficiary.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokensReleased {
+    #[topic]
+    pub grant_id: u32,
+    #[topic]
+    pub beneficiary: Address,
+    pub amount: i128,
+}
+
+/// Emits an event indicating vested tokens were released.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `grant_id` - The identifier of the grant.
+/// * `beneficiary` - The address receiving the tokens.
+/// * `amount` - The amount of tokens released.
+pub fn emit_tokens_released(e: &Env, grant_id: u32, beneficiary: &Address, amount: i128) {
+    TokensReleased { grant_id, beneficiary: beneficiary.clone(), amount }.publish(e);
+}
+
+/// Event emitted when a grant is revoked.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GrantRevoked {
+    #[topic]
+    pub grant_id: u32,
+    #[topic]
+    pub recipient: Address,
+    pub unvested_amount: i128,
+}
+
+/// Emits an event indicating a grant was revoked.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `grant_id` - The identifier of the grant.
+/// * `recipient` - The address receiving the unvested tokens.
+/// * `unvested_amount` - The amount of unvested tokens returned.
+pub fn emit_grant_revoked(e: &Env, grant_id: u32, recipient: &Address, unvested_amount: i128) {
+    GrantRevoked { grant_id, recipient: recipient.clone(), unvested_amount }.publish(e);
+}
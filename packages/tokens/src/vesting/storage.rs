@@ -0,0 +1,328 @@
+use soroban_sdk::{contracttype, panic_with_error, token::TokenClient, Address, Env};
+use stellar_contract_utils::math::{mul_div_i128, Rounding};
+
+use crate::vesting::{
+    emit_grant_created, emit_grant_revoked, emit_tokens_released, VestingError,
+    VESTING_EXTEND_AMOUNT, VESTING_TTL_THRESHOLD,
+};
+
+/// Vesting schedule of a grant.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingSchedule {
+    /// The address receiving the vested tokens.
+    pub beneficiary: Address,
+    /// The address of the vested SEP-41 token.
+    pub token: Address,
+    /// The total amount of tokens vested over the schedule.
+    pub total_amount: i128,
+    /// The ledger timestamp at which vesting starts.
+    pub start: u64,
+    /// The number of seconds after `start` before which nothing vests.
+    pub cliff_duration: u64,
+    /// The number of seconds after `start` at which everything is vested.
+    pub duration: u64,
+    /// Whether the grant can be revoked.
+    pub revocable: bool,
+}
+
+/// A grant along with its vesting state.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingGrant {
+    /// The vesting schedule of the grant.
+    pub schedule: VestingSchedule,
+    /// The amount of tokens already released to the beneficiary.
+    pub released: i128,
+    /// The ledger timestamp at which the grant was revoked, if any.
+    pub revoked_at: Option<u64>,
+}
+
+/// Storage keys for the data associated with the vesting module
+#[contracttype]
+pub enum VestingStorageKey {
+    /// Number of grants created so far, also the id of the next grant
+    GrantCount,
+    /// A grant identified by its id
+    Grant(u32),
+}
+
+// ################## QUERY STATE ##################
+
+/// Returns the grant identified by `grant_id`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `grant_id` - The identifier of the grant.
+///
+/// # Errors
+///
+/// * [`VestingError::GrantNotFound`] - When the grant does not exist.
+pub fn get_grant(e: &Env, grant_id: u32) -> VestingGrant {
+    let key = VestingStorageKey::Grant(grant_id);
+    if let Some(grant) = e.storage().persistent().get::<_, VestingGrant>(&key) {
+        e.storage().persistent().extend_ttl(&key, VESTING_TTL_THRESHOLD, VESTING_EXTEND_AMOUNT);
+        grant
+    } else {
+        panic_with_error!(e, VestingError::GrantNotFound);
+    }
+}
+
+/// Returns the number of grants created so far.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+pub fn grant_count(e: &Env) -> u32 {
+    e.storage().instance().get(&VestingStorageKey::GrantCount).unwrap_or(0)
+}
+
+/// Returns the amount of tokens already released for `grant_id`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `grant_id` - The identifier of the grant.
+///
+/// # Errors
+///
+/// * refer to [`get_grant`] errors.
+pub fn released(e: &Env, grant_id: u32) -> i128 {
+    get_grant(e, grant_id).released
+}
+
+/// Returns the amount of tokens vested for `grant_id` at `timestamp`.
+///
+/// For revoked grants, vesting stops at the time of revocation.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `grant_id` - The identifier of the grant.
+/// * `timestamp` - The ledger timestamp to compute the vested amount at.
+///
+/// # Errors
+///
+/// * refer to [`get_grant`] errors.
+pub fn vested_amount(e: &Env, grant_id: u32, timestamp: u64) -> i128 {
+    grant_vested_amount(e, &get_grant(e, grant_id), timestamp)
+}
+
+/// Returns the amount of tokens that are vested but not yet released for
+/// `grant_id`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `grant_id` - The identifier of the grant.
+///
+/// # Errors
+///
+/// * refer to [`get_grant`] errors.
+pub fn releasable(e: &Env, grant_id: u32) -> i128 {
+    let grant = get_grant(e, grant_id);
+    grant_releasable(e, &grant)
+}
+
+// ################## CHANGE STATE ##################
+
+/// Creates a new grant, transferring `schedule.total_amount` of
+/// `schedule.token` from `funder` to the current contract, and returns its
+/// id.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `funder` - The address providing the tokens.
+/// * `schedule` - The vesting schedule of the grant.
+///
+/// # Errors
+///
+/// * [`VestingError::InvalidVestingAmount`] - When `schedule.total_amount` is
+///   not strictly positive.
+/// * [`VestingError::InvalidVestingSchedule`] - When `schedule.cliff_duration`
+///   exceeds `schedule.duration`, or when the end of the schedule overflows.
+/// * [`VestingError::MathOverflow`] - When the grant id overflows.
+///
+/// # Events
+///
+/// * topics - `["grant_created", grant_id: u32, beneficiary: Address]`
+/// * data - `[token: Address, total_amount: i128]`
+///
+/// # Notes
+///
+/// Authorization for `funder` is required.
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS other than
+/// the one of `funder` ⚠️
+///
+/// Grants can be revocable, so it is the responsibility of the implementer to
+/// establish appropriate access controls to ensure that only authorized
+/// accounts can create grants.
+pub fn create_grant(e: &Env, funder: &Address, schedule: &VestingSchedule) -> u32 {
+    funder.require_auth();
+
+    if schedule.total_amount <= 0 {
+        panic_with_error!(e, VestingError::InvalidVestingAmount);
+    }
+    if schedule.cliff_duration > schedule.duration
+        || schedule.start.checked_add(schedule.duration).is_none()
+    {
+        panic_with_error!(e, VestingError::InvalidVestingSchedule);
+    }
+
+    let grant_id = grant_count(e);
+    let Some(count) = grant_id.checked_add(1) else {
+        panic_with_error!(e, VestingError::MathOverflow);
+    };
+    e.storage().instance().set(&VestingStorageKey::GrantCount, &count);
+
+    let grant = VestingGrant { schedule: schedule.clone(), released: 0, revoked_at: None };
+    e.storage().persistent().set(&VestingStorageKey::Grant(grant_id), &grant);
+
+    TokenClient::new(e, &schedule.token).transfer(
+        funder,
+        e.current_contract_address(),
+        &schedule.total_amount,
+    );
+
+    emit_grant_created(e, grant_id, schedule);
+
+    grant_id
+}
+
+/// Transfers the releasable tokens of `grant_id` to its beneficiary and
+/// returns the amount released.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `grant_id` - The identifier of the grant.
+///
+/// # Errors
+///
+/// * refer to [`get_grant`] errors.
+///
+/// # Events
+///
+/// * topics - `["tokens_released", grant_id: u32, beneficiary: Address]`
+/// * data - `[amount: i128]`
+///
+/// # Notes
+///
+/// No authorization is required, as the tokens can only be transferred to the
+/// beneficiary. No event is emitted when there is nothing to release.
+pub fn release(e: &Env, grant_id: u32) -> i128 {
+    let mut grant = get_grant(e, grant_id);
+    let amount = grant_releasable(e, &grant);
+    if amount == 0 {
+        return 0;
+    }
+
+    // NOTE: can't overflow, because `released + amount <= total_amount`.
+    grant.released += amount;
+    e.storage().persistent().set(&VestingStorageKey::Grant(grant_id), &grant);
+
+    let beneficiary = &grant.schedule.beneficiary;
+    TokenClient::new(e, &grant.schedule.token).transfer(
+        &e.current_contract_address(),
+        beneficiary,
+        &amount,
+    );
+
+    emit_tokens_released(e, grant_id, beneficiary, amount);
+
+    amount
+}
+
+/// Revokes `grant_id`, transferring the unvested tokens to `recipient`, and
+/// returns the amount transferred. The tokens vested until now remain
+/// releasable by the beneficiary.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `grant_id` - The identifier of the grant.
+/// * `recipient` - The address receiving the unvested tokens.
+///
+/// # Errors
+///
+/// * refer to [`get_grant`] errors.
+/// * [`VestingError::GrantNotRevocable`] - When the grant is not revocable.
+/// * [`VestingError::GrantAlreadyRevoked`] - When the grant was already
+///   revoked.
+///
+/// # Events
+///
+/// * topics - `["grant_revoked", grant_id: u32, recipient: Address]`
+/// * data - `[unvested_amount: i128]`
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate
+/// access controls to ensure that only authorized accounts can revoke grants.
+pub fn revoke(e: &Env, grant_id: u32, recipient: &Address) -> i128 {
+    let mut grant = get_grant(e, grant_id);
+    if !grant.schedule.revocable {
+        panic_with_error!(e, VestingError::GrantNotRevocable);
+    }
+    if grant.revoked_at.is_some() {
+        panic_with_error!(e, VestingError::GrantAlreadyRevoked);
+    }
+
+    let now = e.ledger().timestamp();
+    let unvested = grant.schedule.total_amount - grant_vested_amount(e, &grant, now);
+
+    grant.revoked_at = Some(now);
+    e.storage().persistent().set(&VestingStorageKey::Grant(grant_id), &grant);
+
+    if unvested > 0 {
+        TokenClient::new(e, &grant.schedule.token).transfer(
+            &e.current_contract_address(),
+            recipient,
+            &unvested,
+        );
+    }
+
+    emit_grant_revoked(e, grant_id, recipient, unvested);
+
+    unvested
+}
+
+// ################## INTERNAL HELPERS ##################
+
+/// Computes the vested amount of `grant` at `timestamp`.
+fn grant_vested_amount(e: &Env, grant: &VestingGrant, timestamp: u64) -> i128 {
+    let schedule = &grant.schedule;
+    let timestamp = match grant.revoked_at {
+        Some(revoked_at) => timestamp.min(revoked_at),
+        None => timestamp,
+    };
+
+    // NOTE: can't overflow, validated at grant creation.
+    if timestamp < schedule.start + schedule.cliff_duration {
+        0
+    } else if timestamp >= schedule.start + schedule.duration {
+        schedule.total_amount
+    } else {
+        // NOTE: `duration > 0` here, since `start + cliff_duration <= timestamp
+        // < start + duration`.
+        mul_div_i128(
+            e,
+            schedule.total_amount,
+            (timestamp - schedule.start) as i128,
+            schedule.duration as i128,
+            Rounding::Floor,
+        )
+    }
+}
+
+/// Computes the releasable amount of `grant` at the current ledger timestamp.
+fn grant_releasable(e: &Env, grant: &VestingGrant) -> i128 {
+    grant_vested_amount(e, grant, e.ledger().timestamp()) - grant.released
+}
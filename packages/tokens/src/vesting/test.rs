@@ -0,0 +1,250 @@
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger, StellarAssetContract},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env,
+};
+use stellar_event_assertion::EventAssertion;
+
+use crate::vesting::{
+    create_grant, get_grant, grant_count, releasable, release, released, revoke, vested_amount,
+    VestingSchedule,
+};
+
+#[contract]
+struct MockContract;
+
+struct Setup {
+    e: Env,
+    contract_address: Address,
+    token: Address,
+    funder: Address,
+    beneficiary: Address,
+}
+
+fn setup_env() -> Setup {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1000);
+    let contract_address = e.register(MockContract, ());
+    let issuer = Address::generate(&e);
+    let sac: StellarAssetContract = e.register_stellar_asset_contract_v2(issuer);
+    let token = sac.address();
+    let funder = Address::generate(&e);
+    let beneficiary = Address::generate(&e);
+    StellarAssetClient::new(&e, &token).mint(&funder, &10_000);
+    Setup { e, contract_address, token, funder, beneficiary }
+}
+
+fn schedule(s: &Setup, cliff_duration: u64, revocable: bool) -> VestingSchedule {
+    VestingSchedule {
+        beneficiary: s.beneficiary.clone(),
+        token: s.token.clone(),
+        total_amount: 1000,
+        start: 1000,
+        cliff_duration,
+        duration: 100,
+        revocable,
+    }
+}
+
+#[test]
+fn create_grant_locks_tokens() {
+    let s = setup_env();
+    let e = &s.e;
+
+    e.as_contract(&s.contract_address, || {
+        let grant_id = create_grant(e, &s.funder, &schedule(&s, 0, false));
+        assert_eq!(grant_id, 0);
+        assert_eq!(grant_count(e), 1);
+
+        let grant = get_grant(e, grant_id);
+        assert_eq!(grant.schedule, schedule(&s, 0, false));
+        assert_eq!(grant.released, 0);
+        assert_eq!(grant.revoked_at, None);
+    });
+
+    let token = TokenClient::new(e, &s.token);
+    assert_eq!(token.balance(&s.funder), 9000);
+    assert_eq!(token.balance(&s.contract_address), 1000);
+}
+
+#[test]
+fn linear_vesting() {
+    let s = setup_env();
+    let e = &s.e;
+
+    e.as_contract(&s.contract_address, || {
+        let grant_id = create_grant(e, &s.funder, &schedule(&s, 0, false));
+
+        assert_eq!(vested_amount(e, grant_id, 999), 0);
+        assert_eq!(vested_amount(e, grant_id, 1000), 0);
+        assert_eq!(vested_amount(e, grant_id, 1025), 250);
+        assert_eq!(vested_amount(e, grant_id, 1099), 990);
+        assert_eq!(vested_amount(e, grant_id, 1100), 1000);
+        assert_eq!(vested_amount(e, grant_id, 5000), 1000);
+    });
+}
+
+#[test]
+fn cliff_vesting() {
+    let s = setup_env();
+    let e = &s.e;
+
+    e.as_contract(&s.contract_address, || {
+        let grant_id = create_grant(e, &s.funder, &schedule(&s, 40, false));
+
+        assert_eq!(vested_amount(e, grant_id, 1039), 0);
+        // Amount accrued during the cliff unlocks at once.
+        assert_eq!(vested_amount(e, grant_id, 1040), 400);
+        assert_eq!(vested_amount(e, grant_id, 1050), 500);
+        assert_eq!(vested_amount(e, grant_id, 1100), 1000);
+    });
+}
+
+#[test]
+fn release_transfers_vested_tokens() {
+    let s = setup_env();
+    let e = &s.e;
+
+    let grant_id =
+        e.as_contract(&s.contract_address, || create_grant(e, &s.funder, &schedule(&s, 0, false)));
+
+    e.ledger().set_timestamp(1030);
+    e.as_contract(&s.contract_address, || {
+        assert_eq!(releasable(e, grant_id), 300);
+        assert_eq!(release(e, grant_id), 300);
+        assert_eq!(released(e, grant_id), 300);
+        assert_eq!(releasable(e, grant_id), 0);
+
+        // Nothing left to release at the same timestamp.
+        assert_eq!(release(e, grant_id), 0);
+    });
+
+    e.ledger().set_timestamp(1200);
+    e.as_contract(&s.contract_address, || {
+        assert_eq!(release(e, grant_id), 700);
+
+        let event_assert = EventAssertion::new(e, s.contract_address.clone());
+        event_assert.assert_event_count(2);
+    });
+
+    let token = TokenClient::new(e, &s.token);
+    assert_eq!(token.balance(&s.beneficiary), 1000);
+    assert_eq!(token.balance(&s.contract_address), 0);
+}
+
+#[test]
+fn revoke_returns_unvested_tokens() {
+    let s = setup_env();
+    let e = &s.e;
+    let admin = Address::generate(e);
+
+    let grant_id =
+        e.as_contract(&s.contract_address, || create_grant(e, &s.funder, &schedule(&s, 0, true)));
+
+    e.ledger().set_timestamp(1060);
+    e.as_contract(&s.contract_address, || {
+        assert_eq!(revoke(e, grant_id, &admin), 400);
+        assert_eq!(get_grant(e, grant_id).revoked_at, Some(1060));
+    });
+
+    // Vesting stops at revocation.
+    e.ledger().set_timestamp(1200);
+    e.as_contract(&s.contract_address, || {
+        assert_eq!(vested_amount(e, grant_id, 1200), 600);
+        assert_eq!(release(e, grant_id), 600);
+    });
+
+    let token = TokenClient::new(e, &s.token);
+    assert_eq!(token.balance(&admin), 400);
+    assert_eq!(token.balance(&s.beneficiary), 600);
+    assert_eq!(token.balance(&s.contract_address), 0);
+}
+
+#[test]
+fn multiple_grants_are_independent() {
+    let s = setup_env();
+    let e = &s.e;
+    let other = Address::generate(e);
+
+    let first =
+        e.as_contract(&s.contract_address, || create_grant(e, &s.funder, &schedule(&s, 0, false)));
+    let second = e.as_contract(&s.contract_address, || {
+        let mut other_schedule = schedule(&s, 0, false);
+        other_schedule.beneficiary = other.clone();
+        other_schedule.total_amount = 500;
+        other_schedule.duration = 50;
+        create_grant(e, &s.funder, &other_schedule)
+    });
+    assert_eq!((first, second), (0, 1));
+
+    e.ledger().set_timestamp(1050);
+    e.as_contract(&s.contract_address, || {
+        assert_eq!(releasable(e, first), 500);
+        assert_eq!(releasable(e, second), 500);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #500)")]
+fn get_missing_grant_panics() {
+    let s = setup_env();
+
+    s.e.as_contract(&s.contract_address, || {
+        get_grant(&s.e, 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #501)")]
+fn create_grant_with_zero_amount_panics() {
+    let s = setup_env();
+    let e = &s.e;
+
+    e.as_contract(&s.contract_address, || {
+        let mut schedule = schedule(&s, 0, false);
+        schedule.total_amount = 0;
+        create_grant(e, &s.funder, &schedule);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #502)")]
+fn create_grant_with_cliff_after_end_panics() {
+    let s = setup_env();
+    let e = &s.e;
+
+    e.as_contract(&s.contract_address, || {
+        create_grant(e, &s.funder, &schedule(&s, 101, false));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #503)")]
+fn revoke_non_revocable_grant_panics() {
+    let s = setup_env();
+    let e = &s.e;
+
+    let grant_id =
+        e.as_contract(&s.contract_address, || create_grant(e, &s.funder, &schedule(&s, 0, false)));
+    e.as_contract(&s.contract_address, || {
+        revoke(e, grant_id, &s.funder);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #504)")]
+fn revoke_twice_panics() {
+    let s = setup_env();
+    let e = &s.e;
+
+    let grant_id =
+        e.as_contract(&s.contract_address, || create_grant(e, &s.funder, &schedule(&s, 0, true)));
+    e.as_contract(&s.contract_address, || {
+        revoke(e, grant_id, &s.funder);
+        revoke(e, grant_id, &s.funder);
+    });
+}